@@ -187,13 +187,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Resolve and use services
     println!("🔍 Resolving services...");
     
-    let db = provider.get_required::<Arc<DatabaseConnection>>();
+    let db = provider.get_required::<DatabaseConnection>();
     println!("📊 Database connection age: {:?}", db.connection_age());
     
-    let http = provider.get_required::<Arc<HttpClient>>();
+    let http = provider.get_required::<HttpClient>();
     println!("🌐 HTTP client ready: {}", http.base_url);
     
-    let data_service = provider.get_required::<Arc<DataService>>();
+    let data_service = provider.get_required::<DataService>();
     println!("🔄 Processing data...");
     
     let result = data_service.process_data("sample_data");
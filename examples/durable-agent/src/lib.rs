@@ -6,15 +6,15 @@
 //! - Scope-local context for workflow execution
 //! - Extension methods for service registration
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use ferrous_di::*;
 use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
 
 // ============================ Configuration ============================
 
@@ -27,6 +27,25 @@ pub struct EngineOptions {
     pub enable_tracing: bool,
 }
 
+/// Storage backend for checkpoints, chosen by configuration
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CheckpointBackend {
+    /// Process-local storage, lost on exit
+    InMemory,
+    /// One file per checkpoint under `path`
+    File { path: std::path::PathBuf },
+}
+
+/// Checkpoint storage configuration consumed by `CheckpointService::from_config`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointConfig {
+    pub backend: CheckpointBackend,
+    /// Gzip checkpoints; requires the `compression` feature
+    #[serde(default)]
+    pub compress: bool,
+}
+
 // ============================ Core Workflow Context ============================
 
 /// Per-run context stored in ScopeLocal
@@ -83,6 +102,8 @@ pub trait StateStore: Send + Sync {
 pub trait CheckpointService: Send + Sync {
     async fn save(&self, run_id: &str, step: u32, checkpoint: Checkpoint) -> Result<()>;
     async fn load_latest(&self, run_id: &str) -> Result<Option<Checkpoint>>;
+    /// Loads the checkpoint saved under `step`, if any
+    async fn load_at(&self, run_id: &str, step: u32) -> Result<Option<Checkpoint>>;
     async fn list_checkpoints(&self, run_id: &str) -> Result<Vec<CheckpointMetadata>>;
 }
 
@@ -236,6 +257,125 @@ impl StateStore for FileStateStore {
     }
 }
 
+/// Read-through LRU cache in front of a slower `StateStore`
+///
+/// `get` is served from memory while an entry is fresh, `put` writes through
+/// to the inner store, and `delete` drops the cached copy. `list_keys` always
+/// asks the inner store, since the cache only holds part of it.
+///
+/// A read that misses only fills the cache if no write or delete happened
+/// while it was reading the inner store, so a slow read can't put back a
+/// value that was deleted or overwritten in the meantime.
+pub struct CachingStateStore<S> {
+    inner: S,
+    capacity: usize,
+    ttl: Duration,
+    cache: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Value, insertion time and last-access tick per key
+    entries: HashMap<String, (Vec<u8>, Instant, u64)>,
+    tick: u64,
+    /// Bumped by every write and delete, to spot reads that raced with one
+    generation: u64,
+}
+
+impl<S: StateStore> CachingStateStore<S> {
+    /// Caches up to `capacity` entries, each for at most `ttl`
+    pub fn new(inner: S, capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner,
+            capacity,
+            ttl,
+            cache: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Access to the wrapped store
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Drops the cached copy of `key` and invalidates reads in flight
+    fn invalidate(&self, key: &str) {
+        let mut cache = self.cache.lock();
+        cache.entries.remove(key);
+        cache.generation += 1;
+    }
+
+    fn remember(&self, cache: &mut CacheState, key: &str, val: Vec<u8>) {
+        if self.capacity == 0 {
+            return;
+        }
+        cache.tick += 1;
+        let tick = cache.tick;
+        cache.entries.insert(key.to_string(), (val, Instant::now(), tick));
+
+        if cache.entries.len() > self.capacity {
+            let oldest = cache.entries.iter()
+                .min_by_key(|(_, (_, _, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                cache.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<S: StateStore> StateStore for CachingStateStore<S> {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let generation = {
+            let mut cache = self.cache.lock();
+            cache.tick += 1;
+            let tick = cache.tick;
+            match cache.entries.get_mut(key) {
+                Some((val, inserted, last_used)) if inserted.elapsed() < self.ttl => {
+                    *last_used = tick;
+                    return Ok(Some(val.clone()));
+                }
+                Some(_) => {
+                    cache.entries.remove(key);
+                }
+                None => {}
+            }
+            cache.generation
+        };
+
+        let val = self.inner.get(key).await?;
+        if let Some(val) = &val {
+            let mut cache = self.cache.lock();
+            if cache.generation == generation {
+                self.remember(&mut cache, key, val.clone());
+            }
+        }
+        Ok(val)
+    }
+
+    async fn put(&self, key: &str, val: Vec<u8>) -> Result<()> {
+        self.invalidate(key);
+        self.inner.put(key, val.clone()).await?;
+        let mut cache = self.cache.lock();
+        cache.generation += 1;
+        self.remember(&mut cache, key, val);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.invalidate(key);
+        let result = self.inner.delete(key).await;
+        // Reads that started while the delete was in flight may have seen the old value
+        self.invalidate(key);
+        result
+    }
+
+    async fn list_keys(&self, prefix: &str) -> Result<Vec<String>> {
+        self.inner.list_keys(prefix).await
+    }
+}
+
 /// Simple checkpoint service backed by StateStore
 pub struct SimpleCheckpointService {
     store: Arc<dyn StateStore>,
@@ -302,6 +442,14 @@ impl CheckpointService for SimpleCheckpointService {
         }
     }
 
+    async fn load_at(&self, run_id: &str, step: u32) -> Result<Option<Checkpoint>> {
+        let key = format!("checkpoint:{}:{:04}", run_id, step);
+        match self.store.get(&key).await? {
+            Some(bytes) => Ok(Some(self.serializer.deserialize_checkpoint(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
     async fn list_checkpoints(&self, run_id: &str) -> Result<Vec<CheckpointMetadata>> {
         let keys = self.store.list_keys(&format!("checkpoint:{}:", run_id)).await?;
         let mut metas = Vec::new();
@@ -323,6 +471,28 @@ impl CheckpointService for SimpleCheckpointService {
     }
 }
 
+impl dyn CheckpointService {
+    /// Builds the state store and serializer `config` asks for and wraps them
+    /// in a `SimpleCheckpointService`
+    pub fn from_config(config: &CheckpointConfig) -> Result<Arc<dyn CheckpointService>> {
+        let store: Arc<dyn StateStore> = match &config.backend {
+            CheckpointBackend::InMemory => Arc::new(InMemoryStateStore::default()),
+            CheckpointBackend::File { path } => Arc::new(FileStateStore::new(path.clone())),
+        };
+
+        let serializer: Arc<dyn SnapshotSerializer> = if config.compress {
+            #[cfg(feature = "compression")]
+            { Arc::new(CompressingSerializer::new(JsonSerializer)) }
+            #[cfg(not(feature = "compression"))]
+            { return Err(anyhow!("Checkpoint compression requires the `compression` feature")); }
+        } else {
+            Arc::new(JsonSerializer)
+        };
+
+        Ok(Arc::new(SimpleCheckpointService::new(store, serializer)))
+    }
+}
+
 /// JSON serializer implementation
 pub struct JsonSerializer;
 
@@ -344,6 +514,73 @@ impl SnapshotSerializer for JsonSerializer {
     }
 }
 
+/// Serializer wrapper that gzip-compresses the inner serializer's output.
+///
+/// Compressed blobs start with a 4-byte header (`FDZ` plus a format version).
+/// Blobs without the header are handed to the inner serializer unchanged, so
+/// checkpoints written before compression was enabled still load.
+#[cfg(feature = "compression")]
+pub struct CompressingSerializer<S> {
+    inner: S,
+    level: flate2::Compression,
+}
+
+#[cfg(feature = "compression")]
+impl<S: SnapshotSerializer> CompressingSerializer<S> {
+    const MAGIC: [u8; 4] = *b"FDZ\x01";
+
+    pub fn new(inner: S) -> Self {
+        Self { inner, level: flate2::Compression::default() }
+    }
+
+    /// Sets the gzip level (0-9).
+    pub fn with_level(mut self, level: u32) -> Self {
+        self.level = flate2::Compression::new(level);
+        self
+    }
+
+    fn compress(&self, bytes: Vec<u8>) -> Result<Vec<u8>> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Self::MAGIC.to_vec(), self.level);
+        encoder.write_all(&bytes)?;
+        Ok(encoder.finish()?)
+    }
+
+    fn decompress<'a>(&self, bytes: &'a [u8]) -> Result<std::borrow::Cow<'a, [u8]>> {
+        use std::io::Read;
+
+        match bytes.strip_prefix(&Self::MAGIC[..]) {
+            Some(body) => {
+                let mut out = Vec::new();
+                flate2::read::GzDecoder::new(body).read_to_end(&mut out)?;
+                Ok(out.into())
+            }
+            // Legacy uncompressed blob
+            None => Ok(bytes.into()),
+        }
+    }
+}
+
+#[cfg(feature = "compression")]
+impl<S: SnapshotSerializer> SnapshotSerializer for CompressingSerializer<S> {
+    fn serialize_value(&self, value: &Value) -> Result<Vec<u8>> {
+        self.compress(self.inner.serialize_value(value)?)
+    }
+
+    fn deserialize_value(&self, bytes: &[u8]) -> Result<Value> {
+        self.inner.deserialize_value(&self.decompress(bytes)?)
+    }
+
+    fn serialize_checkpoint(&self, checkpoint: &Checkpoint) -> Result<Vec<u8>> {
+        self.compress(self.inner.serialize_checkpoint(checkpoint)?)
+    }
+
+    fn deserialize_checkpoint(&self, bytes: &[u8]) -> Result<Checkpoint> {
+        self.inner.deserialize_checkpoint(&self.decompress(bytes)?)
+    }
+}
+
 // ============================ Tool System ============================
 
 /// Base trait for workflow tools
@@ -360,6 +597,7 @@ pub trait Tool: Send + Sync {
 pub struct ToolContext<'a> {
     pub run_context: Arc<RunContext>,
     pub cancellation: Option<Arc<CancellationToken>>,
+    pub pause: Option<Arc<PauseToken>>,
     _phantom: std::marker::PhantomData<&'a ()>,
 }
 
@@ -370,6 +608,7 @@ impl<'a> ToolContext<'a> {
         Self {
             run_context,
             cancellation: Self::try_get_cancellation_token(resolver),
+            pause: Self::try_get_pause_token(resolver),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -378,6 +617,7 @@ impl<'a> ToolContext<'a> {
         Self {
             run_context,
             cancellation: Self::try_get_cancellation_token(resolver),
+            pause: Self::try_get_pause_token(resolver),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -386,10 +626,22 @@ impl<'a> ToolContext<'a> {
         Self {
             run_context,
             cancellation: Self::try_get_cancellation_token_from_scoped(resolver),
+            pause: resolver.get::<PauseToken>().ok(),
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Fails with [`Cancelled`] once the run's cancellation token has fired.
+    ///
+    /// Called by [`WorkflowEngine::execute_step`] around every tool and
+    /// decorator, so tools don't need to check the token themselves.
+    pub fn check_cancelled(&self) -> Result<()> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(Cancelled.into()),
+            _ => Ok(()),
+        }
+    }
+
     fn try_get_cancellation_token(resolver: &dyn ResolverCore) -> Option<Arc<CancellationToken>> {
         use std::any::TypeId;
         let key = Key::Type(TypeId::of::<CancellationToken>(), "CancellationToken");
@@ -397,6 +649,13 @@ impl<'a> ToolContext<'a> {
             .and_then(|any| any.downcast::<CancellationToken>().ok())
     }
 
+    fn try_get_pause_token(resolver: &dyn ResolverCore) -> Option<Arc<PauseToken>> {
+        use std::any::TypeId;
+        let key = Key::Type(TypeId::of::<PauseToken>(), "PauseToken");
+        resolver.resolve_any(&key).ok()
+            .and_then(|any| any.downcast::<PauseToken>().ok())
+    }
+
     fn try_get_cancellation_token_from_scoped(resolver: &ScopedResolver) -> Option<Arc<CancellationToken>> {
         resolver.get::<CancellationToken>().ok()
     }
@@ -418,6 +677,13 @@ impl LoggingDecorator {
     pub fn new(enabled: bool) -> Self {
         Self { enabled }
     }
+
+    /// Prints a workflow event as a trace line, if enabled
+    pub fn log(&self, event: &WorkflowEvent) {
+        if self.enabled {
+            println!("[TRACE] {:?}", event);
+        }
+    }
 }
 
 /// Checkpoint decorator for durability
@@ -484,6 +750,95 @@ impl CheckpointDecorator {
     }
 }
 
+impl CheckpointDecorator {
+    /// Records the step a cancelled run stopped at, so resuming starts there.
+    pub async fn checkpoint_cancelled(
+        &self,
+        tool_name: &str,
+        input: &Value,
+        context: &ToolContext<'_>,
+        resolver: &ScopedResolver,
+    ) -> Result<()> {
+        println!("[CHECKPOINT] Cancelled at {}: step {}", tool_name, context.run_context.step);
+
+        let checkpoint = Checkpoint {
+            run_id: context.run_context.run_id.clone(),
+            step: context.run_context.step,
+            timestamp: chrono::Utc::now(),
+            tool_name: tool_name.to_string(),
+            input: input.clone(),
+            output: None,
+            error: Some(Cancelled.to_string()),
+            metadata: HashMap::from([("status".to_string(), "cancelled".to_string())]),
+        };
+
+        if let Ok(checkpoint_service) = resolver.get_trait::<dyn CheckpointService>() {
+            checkpoint_service.save(&context.run_context.run_id, context.run_context.step, checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Records the step a paused run will continue from.
+    pub async fn checkpoint_paused(
+        &self,
+        tool_name: &str,
+        input: &Value,
+        context: &ToolContext<'_>,
+        resolver: &ScopedResolver,
+    ) -> Result<()> {
+        println!("[CHECKPOINT] Paused before {}: step {}", tool_name, context.run_context.step);
+
+        let checkpoint = Checkpoint {
+            run_id: context.run_context.run_id.clone(),
+            step: context.run_context.step,
+            timestamp: chrono::Utc::now(),
+            tool_name: tool_name.to_string(),
+            input: input.clone(),
+            output: None,
+            error: None,
+            metadata: HashMap::from([("status".to_string(), "paused".to_string())]),
+        };
+
+        if let Ok(checkpoint_service) = resolver.get_trait::<dyn CheckpointService>() {
+            checkpoint_service.save(&context.run_context.run_id, context.run_context.step, checkpoint).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Records a step whose input was rejected before the tool ran, so
+    /// resuming retries it.
+    #[cfg(feature = "schema-validation")]
+    pub async fn checkpoint_rejected(
+        &self,
+        tool_name: &str,
+        input: &Value,
+        error: &InvalidInput,
+        context: &ToolContext<'_>,
+        resolver: &ScopedResolver,
+    ) -> Result<()> {
+        println!("[CHECKPOINT] Rejected input for {}: step {}", tool_name, context.run_context.step);
+
+        let checkpoint = Checkpoint {
+            run_id: context.run_context.run_id.clone(),
+            step: context.run_context.step,
+            timestamp: chrono::Utc::now(),
+            tool_name: tool_name.to_string(),
+            input: input.clone(),
+            output: None,
+            error: Some(error.to_string()),
+            metadata: HashMap::from([("status".to_string(), "rejected".to_string())]),
+        };
+
+        if let Ok(checkpoint_service) = resolver.get_trait::<dyn CheckpointService>() {
+            checkpoint_service.save(&context.run_context.run_id, context.run_context.step, checkpoint).await?;
+        }
+
+        Ok(())
+    }
+}
+
 // ============================ Concrete Tools ============================
 
 /// File reading tool
@@ -498,13 +853,6 @@ impl Tool for ReadFileTool {
     }
 
     async fn invoke(&self, input: Value, context: &ToolContext<'_>) -> Result<Value> {
-        // Check cancellation
-        if let Some(token) = &context.cancellation {
-            if token.is_cancelled() {
-                return Err(anyhow!("Operation cancelled"));
-            }
-        }
-
         let path = input["path"]
             .as_str()
             .ok_or_else(|| anyhow!("Missing 'path' parameter"))?;
@@ -590,6 +938,92 @@ impl Default for CancellationToken {
     }
 }
 
+/// Pause token for workflow execution
+///
+/// Unlike cancellation, pausing never interrupts a step: the engine finishes
+/// the current step, checkpoints the next one, and reports a `paused` status
+/// that a later run can resume from.
+#[derive(Clone)]
+pub struct PauseToken {
+    inner: Arc<Mutex<bool>>,
+}
+
+impl PauseToken {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub fn pause(&self) {
+        *self.inner.lock() = true;
+    }
+
+    pub fn resume(&self) {
+        *self.inner.lock() = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.inner.lock()
+    }
+}
+
+impl Default for PauseToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned when a step is short-circuited by cancellation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Operation cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Error returned when a step's input doesn't match its tool's schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidInput {
+    pub tool_name: String,
+    pub errors: Vec<String>,
+}
+
+impl std::fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid input for {}: {}", self.tool_name, self.errors.join("; "))
+    }
+}
+
+impl std::error::Error for InvalidInput {}
+
+/// A tool resolved through the container can fail its build with this
+impl From<InvalidInput> for DiError {
+    fn from(error: InvalidInput) -> Self {
+        DiError::FactoryFailed {
+            key: Key::Trait(std::any::type_name::<dyn Tool>()),
+            source: Arc::new(error),
+        }
+    }
+}
+
+/// Checks `input` against the tool's JSON schema
+#[cfg(feature = "schema-validation")]
+fn validate_input(tool: &dyn Tool, input: &Value) -> std::result::Result<(), InvalidInput> {
+    let rejected = |errors| InvalidInput { tool_name: tool.name().to_string(), errors };
+    let schema: Value = serde_json::from_str(tool.schema())
+        .map_err(|e| rejected(vec![format!("schema is not valid JSON: {e}")]))?;
+    let validator = jsonschema::validator_for(&schema)
+        .map_err(|e| rejected(vec![format!("schema is not a valid JSON schema: {e}")]))?;
+
+    let errors: Vec<String> = validator.iter_errors(input).map(|e| e.to_string()).collect();
+    if errors.is_empty() { Ok(()) } else { Err(rejected(errors)) }
+}
+
 /// HTTP tool for making web requests
 pub struct HttpTool;
 
@@ -602,13 +1036,6 @@ impl Tool for HttpTool {
     }
 
     async fn invoke(&self, input: Value, context: &ToolContext<'_>) -> Result<Value> {
-        // Check cancellation
-        if let Some(token) = &context.cancellation {
-            if token.is_cancelled() {
-                return Err(anyhow!("Operation cancelled"));
-            }
-        }
-
         let url = input["url"]
             .as_str()
             .ok_or_else(|| anyhow!("Missing 'url' parameter"))?;
@@ -639,43 +1066,50 @@ impl Tool for HttpTool {
 
 // ============================ Observation Context ============================
 
-/// Context for correlating workflow execution with DI events
-#[derive(Clone, Debug)]
-pub struct ObservationContext {
-    pub run_id: String,
-    pub workflow_name: String,
-    pub step: u32,
-    pub correlation_id: String,
-}
+/// Bridges the run context into ferrous-di's `ObservationContext`,
+/// so correlation ids match the container's `run:workflow:step` format.
+impl RunContextInfo for RunContext {
+    fn run_id(&self) -> &str {
+        &self.run_id
+    }
 
-impl ObservationContext {
-    pub fn new(run_id: impl Into<String>, workflow_name: impl Into<String>, step: u32) -> Self {
-        let run_id = run_id.into();
-        let workflow_name = workflow_name.into();
-        let correlation_id = format!("{}:{}:{}", run_id, workflow_name, step);
-        
-        Self {
-            run_id,
-            workflow_name,
-            step,
-            correlation_id,
-        }
+    fn workflow_name(&self) -> &str {
+        &self.workflow_name
     }
 
-    pub fn correlation_id(&self) -> &str {
-        &self.correlation_id
+    fn step(&self) -> u32 {
+        self.step
     }
 }
 
-impl Default for ObservationContext {
-    fn default() -> Self {
-        Self {
-            run_id: "unknown".to_string(),
-            workflow_name: "unknown".to_string(),
-            step: 0,
-            correlation_id: "unknown:unknown:0".to_string(),
-        }
-    }
+// ============================ Workflow Events ============================
+
+/// Progress event emitted by the [`WorkflowEngine`] while a plan executes
+#[derive(Clone, Debug)]
+pub enum WorkflowEvent {
+    StepStarted {
+        step: u32,
+        tool_name: String,
+    },
+    StepCompleted {
+        step: u32,
+        tool_name: String,
+        duration: Duration,
+    },
+    StepFailed {
+        step: u32,
+        tool_name: String,
+        duration: Duration,
+        error: String,
+    },
+    Checkpointed {
+        step: u32,
+        tool_name: String,
+    },
+    WorkflowCompleted {
+        completed_steps: u32,
+        elapsed: Duration,
+    },
 }
 
 // ============================ Workflow Engine ============================
@@ -683,6 +1117,7 @@ impl Default for ObservationContext {
 /// Main workflow executor
 pub struct WorkflowEngine {
     tools: HashMap<String, Arc<dyn Tool>>,
+    events: Option<mpsc::Sender<WorkflowEvent>>,
 }
 
 impl WorkflowEngine {
@@ -690,7 +1125,32 @@ impl WorkflowEngine {
         let tools = tools.into_iter()
             .map(|t| (t.name().to_string(), t))
             .collect();
-        Self { tools }
+        Self { tools, events: None }
+    }
+
+    /// Streams [`WorkflowEvent`]s to `sender` as the workflow runs
+    pub fn with_events(mut self, sender: mpsc::Sender<WorkflowEvent>) -> Self {
+        self.events = Some(sender);
+        self
+    }
+
+    fn emit(&self, event: WorkflowEvent) {
+        if let Some(sender) = &self.events {
+            // A dropped receiver just means nobody is watching
+            let _ = sender.send(event);
+        }
+    }
+
+    /// Checks that every tool the plan refers to is registered, returning the
+    /// missing tool names in plan order
+    pub fn validate_plan(&self, plan: &[(String, Value)]) -> std::result::Result<(), Vec<String>> {
+        let mut missing: Vec<String> = Vec::new();
+        for (tool_name, _) in plan {
+            if !self.tools.contains_key(tool_name) && !missing.contains(tool_name) {
+                missing.push(tool_name.clone());
+            }
+        }
+        if missing.is_empty() { Ok(()) } else { Err(missing) }
     }
 
     pub async fn execute_step(
@@ -704,31 +1164,37 @@ impl WorkflowEngine {
             .get(tool_name)
             .ok_or_else(|| anyhow!("Unknown tool: {}", tool_name))?;
 
+        let step = run_context.step;
         let context = ToolContext::new_with_resolver(resolver, run_context);
         
         // Get decorators
         let checkpoint_decorator = CheckpointDecorator;
-        
-        // For simplicity, use defaults for now
-        let logging_decorator = LoggingDecorator::new(true);
 
-        // Apply decorators (manual for now, could use ferrous-di's decoration pipeline)
-        if logging_decorator.enabled {
-            println!("[TRACE] Executing {} with input: {}", tool_name, input);
+        // Cancellation is checked between every layer, so tools don't need to
+        // check the token themselves
+        context.check_cancelled()?;
+
+        // Malformed input is rejected before the tool sees it
+        #[cfg(feature = "schema-validation")]
+        if let Err(rejection) = validate_input(tool.as_ref(), &input) {
+            checkpoint_decorator.checkpoint_rejected(tool_name, &input, &rejection, &context, resolver).await?;
+            self.emit(WorkflowEvent::Checkpointed { step, tool_name: tool_name.to_string() });
+            return Err(rejection.into());
         }
 
         // Checkpoint before
         checkpoint_decorator.checkpoint_before(tool_name, &input, &context, resolver).await?;
+        context.check_cancelled()?;
 
         // Execute tool
         let result = tool.invoke(input.clone(), &context).await;
 
+        // Output produced after cancellation is discarded; the step reruns on resume
+        context.check_cancelled()?;
+
         // Checkpoint after
         checkpoint_decorator.checkpoint_after(tool_name, &input, &result, &context, resolver).await?;
-
-        if logging_decorator.enabled {
-            println!("[TRACE] Result: {:?}", result);
-        }
+        self.emit(WorkflowEvent::Checkpointed { step, tool_name: tool_name.to_string() });
 
         result
     }
@@ -740,8 +1206,13 @@ impl WorkflowEngine {
         run_context: Arc<RunContext>,
         crash_after_step: Option<u32>,
     ) -> Result<Value> {
+        // Reject the whole plan before any step has side effects
+        self.validate_plan(&plan)
+            .map_err(|missing| anyhow!("Unknown tools in plan: {}", missing.join(", ")))?;
+
         let mut current_step = run_context.step;
         let mut transcript = Vec::new();
+        let pause = resolver.get::<PauseToken>().ok();
 
         for (i, (tool_name, input)) in plan.iter().enumerate() {
             if (current_step as usize) > i {
@@ -749,7 +1220,31 @@ impl WorkflowEngine {
                 continue;
             }
 
-            println!("[WORKFLOW] Step {}: {}", current_step, tool_name);
+            // Pausing takes effect between steps, never inside one
+            if pause.as_ref().is_some_and(|token| token.is_paused()) {
+                let next_context = Arc::new(RunContext {
+                    run_id: run_context.run_id.clone(),
+                    workflow_name: run_context.workflow_name.clone(),
+                    step: current_step,
+                    started_at: run_context.started_at,
+                    metadata: run_context.metadata.clone(),
+                });
+                let context = ToolContext::new_with_resolver(resolver, next_context);
+                CheckpointDecorator.checkpoint_paused(tool_name, input, &context, resolver).await?;
+                self.emit(WorkflowEvent::Checkpointed { step: current_step, tool_name: tool_name.clone() });
+                return Ok(serde_json::json!({
+                    "run_id": run_context.run_id,
+                    "workflow": run_context.workflow_name,
+                    "status": "paused",
+                    "next_step": current_step,
+                    "completed_steps": current_step,
+                    "transcript": transcript,
+                    "elapsed_ms": run_context.elapsed().as_millis(),
+                }));
+            }
+
+            self.emit(WorkflowEvent::StepStarted { step: current_step, tool_name: tool_name.clone() });
+            let step_started = Instant::now();
             
             // Create updated context for this step
             let step_context = Arc::new(RunContext {
@@ -760,7 +1255,39 @@ impl WorkflowEngine {
                 metadata: run_context.metadata.clone(),
             });
             
-            let output = self.execute_step(tool_name, input.clone(), resolver, step_context).await?;
+            let result = self.execute_step(tool_name, input.clone(), resolver, step_context.clone()).await;
+            let duration = step_started.elapsed();
+            match &result {
+                Ok(_) => self.emit(WorkflowEvent::StepCompleted {
+                    step: current_step,
+                    tool_name: tool_name.clone(),
+                    duration,
+                }),
+                Err(e) => self.emit(WorkflowEvent::StepFailed {
+                    step: current_step,
+                    tool_name: tool_name.clone(),
+                    duration,
+                    error: e.to_string(),
+                }),
+            }
+
+            let output = match result {
+                Ok(output) => output,
+                Err(e) if e.is::<Cancelled>() => {
+                    let context = ToolContext::new_with_resolver(resolver, step_context);
+                    CheckpointDecorator.checkpoint_cancelled(tool_name, input, &context, resolver).await?;
+                    self.emit(WorkflowEvent::Checkpointed { step: current_step, tool_name: tool_name.clone() });
+                    return Ok(serde_json::json!({
+                        "run_id": run_context.run_id,
+                        "workflow": run_context.workflow_name,
+                        "status": "cancelled",
+                        "completed_steps": current_step,
+                        "transcript": transcript,
+                        "elapsed_ms": run_context.elapsed().as_millis(),
+                    }));
+                }
+                Err(e) => return Err(e),
+            };
             transcript.push(serde_json::json!({
                 "step": current_step,
                 "tool": tool_name,
@@ -777,14 +1304,77 @@ impl WorkflowEngine {
             }
         }
 
+        self.emit(WorkflowEvent::WorkflowCompleted {
+            completed_steps: current_step,
+            elapsed: run_context.elapsed(),
+        });
+
         Ok(serde_json::json!({
             "run_id": run_context.run_id,
             "workflow": run_context.workflow_name,
+            "status": "completed",
             "completed_steps": current_step,
             "transcript": transcript,
             "elapsed_ms": run_context.elapsed().as_millis(),
         }))
     }
+
+    /// Re-executes a run from `step` with the inputs its checkpoints recorded
+    ///
+    /// The steps to replay are read up front from the checkpoints saved under
+    /// `step`, `step + 1`, ... until one is missing or only records the
+    /// previous step's outcome, so re-checkpointing can't clobber them. With
+    /// `dry_run` the tools are invoked directly and no checkpoints are written.
+    pub async fn replay_from(
+        &self,
+        run_id: &str,
+        step: u32,
+        resolver: &ScopedResolver,
+        dry_run: bool,
+    ) -> Result<Value> {
+        let checkpoints = resolver.get_trait::<dyn CheckpointService>()?;
+        let mut steps = Vec::new();
+        while let Some(checkpoint) = checkpoints.load_at(run_id, step + steps.len() as u32).await? {
+            // Saved after the previous step finished; nothing ran past it
+            if checkpoint.step != step + steps.len() as u32 {
+                break;
+            }
+            steps.push((checkpoint.tool_name, checkpoint.input));
+        }
+        if steps.is_empty() {
+            return Err(anyhow!("No checkpoint for run {} at step {}", run_id, step));
+        }
+
+        let started = Instant::now();
+        let mut transcript = Vec::new();
+        for (current_step, (tool_name, input)) in (step..).zip(steps) {
+            let run_context = Arc::new(RunContext::new(run_id, "replayed_workflow").with_step(current_step));
+            let output = if dry_run {
+                let tool = self.tools
+                    .get(&tool_name)
+                    .ok_or_else(|| anyhow!("Unknown tool: {}", tool_name))?;
+                let context = ToolContext::new_with_resolver(resolver, run_context);
+                tool.invoke(input.clone(), &context).await?
+            } else {
+                self.execute_step(&tool_name, input.clone(), resolver, run_context).await?
+            };
+            transcript.push(serde_json::json!({
+                "step": current_step,
+                "tool": tool_name,
+                "input": input,
+                "output": output,
+            }));
+        }
+
+        Ok(serde_json::json!({
+            "run_id": run_id,
+            "status": "replayed",
+            "from_step": step,
+            "dry_run": dry_run,
+            "transcript": transcript,
+            "elapsed_ms": started.elapsed().as_millis(),
+        }))
+    }
 }
 
 // ============================ Service Collection Extensions ============================
@@ -859,8 +1449,9 @@ impl DurableAgentServiceCollectionExt for ServiceCollection {
             Arc::new(RunContext::new(run_id.clone(), workflow_name.clone()))
         });
 
-        // Add cancellation token
+        // Add cancellation and pause tokens
         self.add_scoped_factory::<CancellationToken, _>(|_| CancellationToken::new());
+        self.add_scoped_factory::<PauseToken, _>(|_| PauseToken::new());
 
         self
     }
@@ -1109,17 +1700,17 @@ pub fn export_workflow_graph(provider: &ServiceProvider) -> Result<String> {
     
     let graph_builder = GraphBuilder::new();
     let graph = graph_builder.build_graph(provider)
-        .map_err(|e| anyhow::anyhow!("Graph build error: {}", e))?;
+        .context("Graph build error")?;
     
     // Export in multiple formats to show the capabilities
     let mermaid = graph_builder.export(&graph, ExportFormat::Mermaid)
-        .map_err(|e| anyhow::anyhow!("Mermaid export error: {}", e))?;
+        .context("Mermaid export error")?;
     
     let json = graph_builder.export(&graph, ExportFormat::Json)
-        .map_err(|e| anyhow::anyhow!("JSON export error: {}", e))?;
+        .context("JSON export error")?;
     
     let dot = graph_builder.export(&graph, ExportFormat::Dot)
-        .map_err(|e| anyhow::anyhow!("DOT export error: {}", e))?;
+        .context("DOT export error")?;
     
     // Return a comprehensive output showing all formats
     Ok(format!(
@@ -1190,6 +1781,459 @@ mod tests {
         assert!(keys.contains(&"prefix:b".to_string()));
     }
 
+    /// In-memory store that counts reads reaching it
+    #[derive(Default)]
+    struct CountingStore {
+        store: InMemoryStateStore,
+        gets: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl StateStore for CountingStore {
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+            self.gets.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.store.get(key).await
+        }
+        async fn put(&self, key: &str, val: Vec<u8>) -> Result<()> { self.store.put(key, val).await }
+        async fn delete(&self, key: &str) -> Result<()> { self.store.delete(key).await }
+        async fn list_keys(&self, prefix: &str) -> Result<Vec<String>> { self.store.list_keys(prefix).await }
+    }
+
+    #[tokio::test]
+    async fn test_caching_state_store_serves_hot_reads() {
+        use std::sync::atomic::Ordering;
+
+        let store = CachingStateStore::new(CountingStore::default(), 2, Duration::from_secs(60));
+        store.put("a", vec![1]).await.unwrap();
+        assert_eq!(store.get("a").await.unwrap(), Some(vec![1]));
+        assert_eq!(store.inner().gets.load(Ordering::SeqCst), 0);
+
+        // Writes go through, so the inner store has the value too
+        assert_eq!(store.inner().store.get("a").await.unwrap(), Some(vec![1]));
+
+        // Least recently used entries are evicted past capacity
+        store.put("b", vec![2]).await.unwrap();
+        store.get("a").await.unwrap();
+        store.put("c", vec![3]).await.unwrap();
+        assert_eq!(store.get("b").await.unwrap(), Some(vec![2]));
+        assert_eq!(store.inner().gets.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_caching_state_store_delete_and_ttl_invalidate() {
+        use std::sync::atomic::Ordering;
+
+        let store = CachingStateStore::new(CountingStore::default(), 8, Duration::from_secs(60));
+        store.put("a", vec![1]).await.unwrap();
+        store.delete("a").await.unwrap();
+        assert_eq!(store.get("a").await.unwrap(), None);
+        assert_eq!(store.inner().gets.load(Ordering::SeqCst), 1);
+
+        let expiring = CachingStateStore::new(CountingStore::default(), 8, Duration::ZERO);
+        expiring.put("a", vec![1]).await.unwrap();
+        assert_eq!(expiring.get("a").await.unwrap(), Some(vec![1]));
+        assert_eq!(expiring.inner().gets.load(Ordering::SeqCst), 1);
+    }
+
+    /// In-memory store whose next `get` pauses after reading until released
+    #[derive(Default)]
+    struct GatedStore {
+        store: InMemoryStateStore,
+        armed: std::sync::atomic::AtomicBool,
+        reached: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl StateStore for GatedStore {
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+            let val = self.store.get(key).await;
+            if self.armed.swap(false, std::sync::atomic::Ordering::SeqCst) {
+                self.reached.notify_one();
+                self.release.notified().await;
+            }
+            val
+        }
+        async fn put(&self, key: &str, val: Vec<u8>) -> Result<()> { self.store.put(key, val).await }
+        async fn delete(&self, key: &str) -> Result<()> { self.store.delete(key).await }
+        async fn list_keys(&self, prefix: &str) -> Result<Vec<String>> { self.store.list_keys(prefix).await }
+    }
+
+    #[tokio::test]
+    async fn test_caching_state_store_slow_read_does_not_resurrect_deleted_value() {
+        use std::sync::atomic::Ordering;
+
+        let store = CachingStateStore::new(GatedStore::default(), 8, Duration::from_secs(60));
+        store.inner().store.put("a", vec![1]).await.unwrap();
+        store.inner().armed.store(true, Ordering::SeqCst);
+
+        let read = store.get("a");
+        let delete = async {
+            store.inner().reached.notified().await;
+            store.delete("a").await.unwrap();
+            store.inner().release.notify_one();
+        };
+        let (stale, ()) = tokio::join!(read, delete);
+        assert_eq!(stale.unwrap(), Some(vec![1]));
+
+        // The read that raced with the delete must not have filled the cache
+        assert_eq!(store.get("a").await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_run_context_correlation() {
+        let ctx = RunContext::new("run-123", "test-workflow").with_step(2);
+        let observation = ObservationContext::from_run_context(&ctx);
+        assert_eq!(observation.correlation_id(), "run-123:test-workflow:2");
+    }
+
+    /// Cancels the run's token as its only side effect
+    struct CancelTool;
+
+    #[async_trait]
+    impl Tool for CancelTool {
+        fn name(&self) -> &'static str { "test.cancel" }
+        fn description(&self) -> &'static str { "Cancels the current run" }
+        fn schema(&self) -> &'static str { r#"{"type":["object","null"]}"# }
+
+        async fn invoke(&self, _input: Value, context: &ToolContext<'_>) -> Result<Value> {
+            context.cancellation.as_ref().expect("token registered").cancel();
+            Ok(serde_json::json!({ "type": "test.cancel" }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_mid_plan_skips_remaining_steps() {
+        let mut services = ServiceCollection::new();
+        services.add_state_services();
+        DurableAgentServiceCollectionExt::add_workflow_context(&mut services, "run-cancel".to_string(), "cancel-test".to_string());
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        let add = serde_json::json!({ "operation": "add", "a": 1, "b": 2 });
+        let plan = vec![
+            ("math.calculate".to_string(), add.clone()),
+            ("test.cancel".to_string(), Value::Null),
+            ("math.calculate".to_string(), add),
+        ];
+
+        let result = scope.using(|resolver| async move {
+            let engine = WorkflowEngine::new(vec![Arc::new(CalculatorTool), Arc::new(CancelTool)]);
+            let run_context = Arc::new(RunContext::new("run-cancel", "cancel-test"));
+            let output = engine.run_workflow(plan, &resolver, run_context, None).await?;
+
+            let checkpoints = resolver.get_trait::<dyn CheckpointService>()?;
+            let latest = checkpoints.load_latest("run-cancel").await?.expect("checkpoint saved");
+            Ok::<_, anyhow::Error>((output, latest))
+        }).await;
+        let (output, latest) = result.unwrap();
+
+        assert_eq!(output["status"], "cancelled");
+        assert_eq!(output["completed_steps"], 1);
+        assert_eq!(output["transcript"].as_array().unwrap().len(), 1);
+
+        assert_eq!(latest.step, 1);
+        assert_eq!(latest.tool_name, "test.cancel");
+        assert_eq!(latest.error.as_deref(), Some("Operation cancelled"));
+    }
+
+    /// Pauses the run as its only side effect
+    struct PauseTool;
+
+    #[async_trait]
+    impl Tool for PauseTool {
+        fn name(&self) -> &'static str { "test.pause" }
+        fn description(&self) -> &'static str { "Pauses the current run" }
+        fn schema(&self) -> &'static str { r#"{"type":"object"}"# }
+
+        async fn invoke(&self, _input: Value, context: &ToolContext<'_>) -> Result<Value> {
+            context.pause.as_ref().expect("token registered").pause();
+            Ok(serde_json::json!({ "type": "test.pause" }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pause_checkpoints_and_resumes_to_completion() {
+        let mut services = ServiceCollection::new();
+        services.add_state_services();
+        DurableAgentServiceCollectionExt::add_workflow_context(&mut services, "run-pause".to_string(), "pause-test".to_string());
+        let provider = services.build();
+
+        let add = serde_json::json!({ "operation": "add", "a": 1, "b": 2 });
+        let plan = vec![
+            ("math.calculate".to_string(), add.clone()),
+            ("test.pause".to_string(), serde_json::json!({})),
+            ("math.calculate".to_string(), add.clone()),
+            ("math.calculate".to_string(), add),
+        ];
+        let engine = Arc::new(WorkflowEngine::new(vec![Arc::new(CalculatorTool), Arc::new(PauseTool)]));
+
+        let first_run = {
+            let (engine, plan) = (engine.clone(), plan.clone());
+            provider.create_scope().using(|resolver| async move {
+                let run_context = Arc::new(RunContext::new("run-pause", "pause-test"));
+                let output = engine.run_workflow(plan, &resolver, run_context, None).await?;
+
+                let checkpoints = resolver.get_trait::<dyn CheckpointService>()?;
+                let latest = checkpoints.load_latest("run-pause").await?.expect("checkpoint saved");
+                Ok::<_, anyhow::Error>((output, latest))
+            }).await
+        };
+        let (paused, latest) = first_run.unwrap();
+
+        assert_eq!(paused["status"], "paused");
+        assert_eq!(paused["run_id"], "run-pause");
+        assert_eq!(paused["next_step"], 2);
+        assert_eq!(paused["transcript"].as_array().unwrap().len(), 2);
+        assert_eq!(latest.step, 2);
+        assert_eq!(latest.metadata.get("status").map(String::as_str), Some("paused"));
+
+        let resumed = provider.create_scope().using(|resolver| async move {
+            let run_context = rehydrate_workflow("run-pause", &resolver).await?.expect("checkpoint found");
+            engine.run_workflow(plan, &resolver, Arc::new(run_context), None).await
+        }).await.unwrap();
+
+        assert_eq!(resumed["status"], "completed");
+        assert_eq!(resumed["completed_steps"], 4);
+        assert_eq!(resumed["transcript"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_workflow_emits_events_in_order() {
+        let mut services = ServiceCollection::new();
+        services.add_state_services();
+        DurableAgentServiceCollectionExt::add_workflow_context(&mut services, "run-events".to_string(), "events-test".to_string());
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        let (sender, receiver) = mpsc::channel();
+        let add = serde_json::json!({ "operation": "add", "a": 1, "b": 2 });
+        let plan = vec![
+            ("math.calculate".to_string(), add.clone()),
+            ("math.calculate".to_string(), add),
+        ];
+
+        scope.using(|resolver| async move {
+            let engine = WorkflowEngine::new(vec![Arc::new(CalculatorTool)]).with_events(sender);
+            let run_context = Arc::new(RunContext::new("run-events", "events-test"));
+            engine.run_workflow(plan, &resolver, run_context, None).await
+        }).await.unwrap();
+
+        let events: Vec<String> = receiver.try_iter()
+            .map(|event| match event {
+                WorkflowEvent::StepStarted { step, .. } => format!("started:{step}"),
+                WorkflowEvent::StepCompleted { step, .. } => format!("completed:{step}"),
+                WorkflowEvent::StepFailed { step, .. } => format!("failed:{step}"),
+                WorkflowEvent::Checkpointed { step, .. } => format!("checkpointed:{step}"),
+                WorkflowEvent::WorkflowCompleted { completed_steps, .. } => format!("done:{completed_steps}"),
+            })
+            .collect();
+
+        assert_eq!(events, [
+            "started:0", "checkpointed:0", "completed:0",
+            "started:1", "checkpointed:1", "completed:1",
+            "done:2",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_plan_with_unknown_tool_fails_before_any_step() {
+        let mut services = ServiceCollection::new();
+        services.add_state_services();
+        DurableAgentServiceCollectionExt::add_workflow_context(&mut services, "run-invalid".to_string(), "invalid-test".to_string());
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        let (sender, receiver) = mpsc::channel();
+        let add = serde_json::json!({ "operation": "add", "a": 1, "b": 2 });
+        let plan = vec![
+            ("math.calculate".to_string(), add.clone()),
+            ("web.search".to_string(), Value::Null),
+            ("math.calculate".to_string(), add),
+        ];
+
+        let engine = WorkflowEngine::new(vec![Arc::new(CalculatorTool)]).with_events(sender);
+        assert_eq!(engine.validate_plan(&plan), Err(vec!["web.search".to_string()]));
+
+        let result = scope.using(|resolver| async move {
+            let run_context = Arc::new(RunContext::new("run-invalid", "invalid-test"));
+            engine.run_workflow(plan, &resolver, run_context, None).await
+        }).await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("web.search"), "{error}");
+        assert_eq!(receiver.try_iter().count(), 0);
+    }
+
+    /// Counts invocations so tests can tell whether a tool ran
+    #[cfg(feature = "schema-validation")]
+    struct CountingCalculator(Arc<std::sync::atomic::AtomicUsize>);
+
+    #[cfg(feature = "schema-validation")]
+    #[async_trait]
+    impl Tool for CountingCalculator {
+        fn name(&self) -> &'static str { CalculatorTool.name() }
+        fn description(&self) -> &'static str { CalculatorTool.description() }
+        fn schema(&self) -> &'static str { CalculatorTool.schema() }
+
+        async fn invoke(&self, input: Value, context: &ToolContext<'_>) -> Result<Value> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            CalculatorTool.invoke(input, context).await
+        }
+    }
+
+    #[cfg(feature = "schema-validation")]
+    #[tokio::test]
+    async fn test_schema_validation_rejects_input_before_tool_runs() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut services = ServiceCollection::new();
+        services.add_state_services();
+        DurableAgentServiceCollectionExt::add_workflow_context(&mut services, "run-schema".to_string(), "schema-test".to_string());
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let engine = WorkflowEngine::new(vec![Arc::new(CountingCalculator(invocations.clone()))]);
+
+        let result = scope.using(|resolver| async move {
+            let run_context = Arc::new(RunContext::new("run-schema", "schema-test"));
+            // `b` is required by the calculator's schema
+            let input = serde_json::json!({ "operation": "add", "a": 1 });
+            let error = engine.execute_step("math.calculate", input, &resolver, run_context).await.unwrap_err();
+
+            let checkpoints = resolver.get_trait::<dyn CheckpointService>()?;
+            let latest = checkpoints.load_latest("run-schema").await?.expect("rejection checkpointed");
+            Ok::<_, anyhow::Error>((error, latest))
+        }).await;
+        let (error, latest) = result.unwrap();
+
+        let rejection = error.downcast_ref::<InvalidInput>().expect("validation error");
+        assert_eq!(rejection.tool_name, "math.calculate");
+        assert!(rejection.errors.iter().any(|e| e.contains("\"b\"")), "{:?}", rejection.errors);
+        assert_eq!(invocations.load(Ordering::SeqCst), 0);
+
+        assert_eq!(latest.step, 0);
+        assert_eq!(latest.metadata.get("status").map(String::as_str), Some("rejected"));
+    }
+
+    #[test]
+    fn test_invalid_input_converts_to_factory_failed() {
+        let rejection = InvalidInput {
+            tool_name: "math.calculate".to_string(),
+            errors: vec!["\"b\" is a required property".to_string()],
+        };
+        match DiError::from(rejection.clone()) {
+            DiError::FactoryFailed { key, source } => {
+                assert_eq!(key.display_name(), std::any::type_name::<dyn Tool>());
+                assert_eq!(source.to_string(), rejection.to_string());
+            }
+            other => panic!("expected FactoryFailed, got {:?}", other),
+        }
+    }
+
+    /// Records every input it is invoked with, then calculates as usual
+    struct RecordingCalculator(Arc<Mutex<Vec<Value>>>);
+
+    #[async_trait]
+    impl Tool for RecordingCalculator {
+        fn name(&self) -> &'static str { CalculatorTool.name() }
+        fn description(&self) -> &'static str { CalculatorTool.description() }
+        fn schema(&self) -> &'static str { CalculatorTool.schema() }
+
+        async fn invoke(&self, input: Value, context: &ToolContext<'_>) -> Result<Value> {
+            self.0.lock().push(input.clone());
+            CalculatorTool.invoke(input, context).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_from_middle_step_uses_stored_inputs() {
+        let mut services = ServiceCollection::new();
+        services.add_state_services();
+        DurableAgentServiceCollectionExt::add_workflow_context(&mut services, "run-replay".to_string(), "replay-test".to_string());
+        let provider = services.build();
+
+        let inputs: Vec<Value> = (1..=4)
+            .map(|b| serde_json::json!({ "operation": "add", "a": 10, "b": b }))
+            .collect();
+        let plan: Vec<(String, Value)> = inputs.iter()
+            .map(|input| ("math.calculate".to_string(), input.clone()))
+            .collect();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let engine = Arc::new(WorkflowEngine::new(vec![Arc::new(RecordingCalculator(seen.clone()))]));
+
+        let replay = provider.create_scope().using(|resolver| {
+            let engine = engine.clone();
+            let seen = seen.clone();
+            async move {
+                let run_context = Arc::new(RunContext::new("run-replay", "replay-test"));
+                engine.run_workflow(plan, &resolver, run_context, None).await?;
+                seen.lock().clear();
+
+                let checkpoints = resolver.get_trait::<dyn CheckpointService>()?;
+                let before = checkpoints.load_at("run-replay", 2).await?.expect("step 2 checkpointed");
+                let dry = engine.replay_from("run-replay", 2, &resolver, true).await?;
+                let after_dry = checkpoints.load_at("run-replay", 2).await?.expect("still there");
+                assert_eq!(after_dry.timestamp, before.timestamp);
+
+                engine.replay_from("run-replay", 2, &resolver, false).await?;
+                let after_replay = checkpoints.load_at("run-replay", 2).await?.expect("rewritten");
+                assert!(after_replay.timestamp > before.timestamp);
+                Ok::<_, anyhow::Error>(dry)
+            }
+        }).await.unwrap();
+
+        // Execution resumed at step 2 with the inputs stored for steps 2 and 3
+        let steps: Vec<u64> = replay["transcript"].as_array().unwrap().iter()
+            .map(|entry| entry["step"].as_u64().unwrap())
+            .collect();
+        assert_eq!(steps, [2, 3]);
+        assert_eq!(replay["dry_run"], true);
+        assert_eq!(replay["transcript"][0]["output"]["result"], 13.0);
+        assert_eq!(*seen.lock(), [&inputs[2..], &inputs[2..]].concat());
+
+        let missing = provider.create_scope().using(|resolver| async move {
+            engine.replay_from("run-unknown", 0, &resolver, true).await
+        }).await;
+        assert!(missing.is_err());
+    }
+
+    fn sample_checkpoint(run_id: &str, step: u32) -> Checkpoint {
+        Checkpoint {
+            run_id: run_id.to_string(),
+            step,
+            timestamp: chrono::Utc::now(),
+            tool_name: "math.calculate".to_string(),
+            input: serde_json::json!({ "operation": "add", "a": 1, "b": 2 }),
+            output: Some(serde_json::json!({ "result": 3.0 })),
+            error: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_service_from_config() {
+        let in_memory = CheckpointConfig { backend: CheckpointBackend::InMemory, compress: false };
+        let service = <dyn CheckpointService>::from_config(&in_memory).unwrap();
+        service.save("run-mem", 1, sample_checkpoint("run-mem", 1)).await.unwrap();
+        assert_eq!(service.load_latest("run-mem").await.unwrap().unwrap().step, 1);
+
+        let dir = std::env::temp_dir().join(format!("durable-agent-config-{}", uuid::Uuid::new_v4()));
+        let file: CheckpointConfig = serde_json::from_value(serde_json::json!({
+            "backend": { "kind": "file", "path": dir },
+        })).unwrap();
+        let writer = <dyn CheckpointService>::from_config(&file).unwrap();
+        writer.save("run-file", 2, sample_checkpoint("run-file", 2)).await.unwrap();
+
+        // A fresh service over the same directory sees what the first one wrote
+        let reader = <dyn CheckpointService>::from_config(&file).unwrap();
+        let latest = reader.load_latest("run-file").await.unwrap().unwrap();
+        assert_eq!(latest.step, 2);
+        assert_eq!(latest.output, Some(serde_json::json!({ "result": 3.0 })));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_run_context() {
         let ctx = RunContext::new("run-123", "test-workflow");
@@ -1200,4 +2244,52 @@ mod tests {
         let ctx = ctx.with_step(5);
         assert_eq!(ctx.step, 5);
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "compression")]
+    fn large_checkpoint() -> Checkpoint {
+        let rows: Vec<Value> = (0..500)
+            .map(|i| serde_json::json!({ "id": i, "title": "search result", "body": "lorem ipsum dolor sit amet" }))
+            .collect();
+        Checkpoint {
+            run_id: "run-1".to_string(),
+            step: 3,
+            timestamp: chrono::Utc::now(),
+            tool_name: "search".to_string(),
+            input: serde_json::json!({ "query": "rust di" }),
+            output: Some(Value::Array(rows)),
+            error: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_compressing_serializer_round_trip() {
+        let checkpoint = large_checkpoint();
+        let plain = JsonSerializer.serialize_checkpoint(&checkpoint).unwrap();
+
+        let serializer = CompressingSerializer::new(JsonSerializer);
+        let compressed = serializer.serialize_checkpoint(&checkpoint).unwrap();
+        assert!(compressed.len() < plain.len() / 4);
+
+        let restored = serializer.deserialize_checkpoint(&compressed).unwrap();
+        assert_eq!(restored.run_id, checkpoint.run_id);
+        assert_eq!(restored.step, checkpoint.step);
+        assert_eq!(restored.output, checkpoint.output);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_compressing_serializer_reads_legacy_blob() {
+        let checkpoint = large_checkpoint();
+        let legacy = JsonSerializer.serialize_checkpoint(&checkpoint).unwrap();
+
+        let serializer = CompressingSerializer::new(JsonSerializer);
+        let restored = serializer.deserialize_checkpoint(&legacy).unwrap();
+        assert_eq!(restored.output, checkpoint.output);
+
+        let value = serde_json::json!({ "k": "v" });
+        let legacy_value = JsonSerializer.serialize_value(&value).unwrap();
+        assert_eq!(serializer.deserialize_value(&legacy_value).unwrap(), value);
+    }
+}
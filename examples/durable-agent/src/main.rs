@@ -122,12 +122,21 @@ async fn run_workflow(
     
     // Create a scope for this workflow execution
     let scope = provider.create_scope();
+
+    // Trace progress events as they arrive
+    let (events, receiver) = std::sync::mpsc::channel();
+    let tracer = std::thread::spawn(move || {
+        let logger = LoggingDecorator::new(true);
+        for event in receiver {
+            logger.log(&event);
+        }
+    });
     
     let run_id_clone = run_id.clone();
     let result = scope.using(|resolver| async move {
         // Get the workflow engine (building from resolved tools)
-        let tools = resolver.get_all_trait::<dyn Tool>().map_err(|e| anyhow::anyhow!("Tools error: {}", e))?;
-        let engine = WorkflowEngine::new(tools);
+        let tools = resolver.get_all_trait::<dyn Tool>()?;
+        let engine = WorkflowEngine::new(tools).with_events(events);
         
         // Execute the workflow
         let run_context = Arc::new(RunContext::new(run_id_clone.clone(), workflow_name.clone()));
@@ -136,6 +145,7 @@ async fn run_workflow(
         // Convert DiError to anyhow::Error for consistency
         result.map_err(|e| anyhow::anyhow!("Workflow error: {}", e))
     }).await;
+    let _ = tracer.join();
     
     match result {
         Ok(output) => {
@@ -170,7 +180,7 @@ async fn resume_workflow(run_id: String) -> Result<()> {
             
             // Update the scoped RunContext
             // Note: In a real implementation, we'd provide a factory that does this automatically
-            let tools = resolver.get_all_trait::<dyn Tool>().map_err(|e| anyhow::anyhow!("Tools error: {}", e))?;
+            let tools = resolver.get_all_trait::<dyn Tool>()?;
             let engine = WorkflowEngine::new(tools);
             
             // Continue execution (no crash this time)  
@@ -318,7 +328,7 @@ async fn demo_concurrent_workflows() -> Result<()> {
             let scope = provider.create_scope();
             scope.using(|resolver| async move {
                 println!("  [{}] Starting concurrent execution", run_id);
-                let tools = resolver.get_all_trait::<dyn Tool>().map_err(|e| anyhow::anyhow!("Tools error: {}", e))?;
+                let tools = resolver.get_all_trait::<dyn Tool>()?;
                 let engine = WorkflowEngine::new(tools);
                 
                 // Small random delay to show concurrency
@@ -390,7 +400,7 @@ async fn main() -> Result<()> {
             let scope = provider.create_scope();
             
             let _ = scope.using(|resolver| async move {
-                let tools = resolver.get_all_trait::<dyn Tool>().map_err(|e| anyhow::anyhow!("Tools error: {}", e))?;
+                let tools = resolver.get_all_trait::<dyn Tool>()?;
                 let engine = WorkflowEngine::new(tools);
                 let run_context = Arc::new(RunContext::new(demo_run_id.to_string(), "crash-demo".to_string()));
                 engine.run_workflow(plan, &resolver, run_context, Some(2)).await // Crash after step 2
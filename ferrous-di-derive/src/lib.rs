@@ -0,0 +1,88 @@
+//! Derive macros for ferrous-di.
+//!
+//! These are re-exported from `ferrous_di` when its `derive` feature is
+//! enabled; depend on that crate rather than on this one directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+/// Generates `resolve_from`, which builds the struct by resolving every field.
+///
+/// Each field must be an `Arc<T>` or an `Arc<dyn Trait>`. Concrete types are
+/// resolved with `Resolver::get` and trait objects with `Resolver::get_trait`,
+/// in declaration order; the first failure is returned.
+#[proc_macro_derive(ResolveInto)]
+pub fn derive_resolve_into(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_resolve_into(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_resolve_into(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(input.ident.span(), "ResolveInto can only be derived for structs"));
+    };
+
+    let body = match &data.fields {
+        Fields::Named(fields) => {
+            let inits = fields.named.iter()
+                .map(|field| {
+                    let name = &field.ident;
+                    let value = resolve_field(&field.ty)?;
+                    Ok(quote! { #name: #value })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { Self { #(#inits,)* } }
+        }
+        Fields::Unnamed(fields) => {
+            let values = fields.unnamed.iter()
+                .map(|field| resolve_field(&field.ty))
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { Self(#(#values,)*) }
+        }
+        Fields::Unit => quote! { Self },
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Builds the struct by resolving each field from `resolver`.
+            pub fn resolve_from<R>(resolver: &R) -> ::ferrous_di::DiResult<Self>
+            where
+                R: ::ferrous_di::Resolver + ?Sized,
+            {
+                ::core::result::Result::Ok(#body)
+            }
+        }
+    })
+}
+
+/// The resolution expression for one `Arc<_>` field.
+fn resolve_field(ty: &Type) -> syn::Result<TokenStream2> {
+    let inner = arc_inner(ty).ok_or_else(|| {
+        syn::Error::new(ty.span(), "ResolveInto fields must be `Arc<T>` or `Arc<dyn Trait>`")
+    })?;
+    Ok(match inner {
+        Type::TraitObject(_) => quote! { ::ferrous_di::Resolver::get_trait::<#inner>(resolver)? },
+        _ => quote! { ::ferrous_di::Resolver::get::<#inner>(resolver)? },
+    })
+}
+
+/// The `T` in a field typed `Arc<T>` (matched on the last path segment).
+fn arc_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Arc" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
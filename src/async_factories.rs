@@ -5,6 +5,7 @@
 //! model warm-up, or authentication flows.
 
 use std::sync::Arc;
+use std::time::Duration;
 use async_trait::async_trait;
 use crate::traits::ResolverCore;
 
@@ -57,7 +58,7 @@ use crate::traits::ResolverCore;
 /// # }
 /// ```
 #[async_trait]
-pub trait AsyncFactory<T: Send + Sync + 'static>: Send + Sync {
+pub trait AsyncFactory<T: ?Sized + Send + Sync + 'static>: Send + Sync {
     /// Creates a new instance of the service asynchronously.
     ///
     /// The resolver can be used to access other services that this
@@ -81,13 +82,13 @@ pub(crate) enum AsyncServiceState<T> {
 }
 
 /// Internal wrapper for async factory functions.
-pub(crate) struct AsyncFactoryWrapper<T> {
+pub(crate) struct AsyncFactoryWrapper<T: ?Sized> {
     factory: Box<dyn AsyncFactory<T>>,
     // State would be managed by the ServiceProvider
     // using OnceCell or similar for thread-safe lazy initialization
 }
 
-impl<T: Send + Sync + 'static> AsyncFactoryWrapper<T> {
+impl<T: ?Sized + Send + Sync + 'static> AsyncFactoryWrapper<T> {
     pub fn new(factory: impl AsyncFactory<T> + 'static) -> Self {
         Self {
             factory: Box::new(factory),
@@ -99,11 +100,249 @@ impl<T: Send + Sync + 'static> AsyncFactoryWrapper<T> {
     }
 }
 
+/// What a synchronous resolution does once it has nested more async
+/// factories than the limit set with
+/// [`ServiceCollection::max_async_depth`](crate::ServiceCollection::max_async_depth).
+///
+/// Each async factory resolved through the sync API is driven by its own
+/// runtime, and a factory that resolves another async service synchronously
+/// stacks another one on the same thread. Deep chains can overflow the stack
+/// or hit tokio's restriction on blocking inside a current-thread runtime.
+///
+/// The two policies trade differently:
+///
+/// - [`Fail`](Self::Fail) is cheap and predictable, but a legitimately deep
+///   graph stops resolving and has to be flattened or resolved with the async
+///   API instead.
+/// - [`FreshThread`](Self::FreshThread) keeps deep graphs working, at the cost
+///   of spawning an OS thread (and allocating its stack) every time the limit
+///   is reached. The new thread starts its own depth count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsyncDepthPolicy {
+    /// Fail with [`DiError::MaxAsyncDepthExceeded`](crate::DiError::MaxAsyncDepthExceeded).
+    Fail,
+    /// Resolve the rest of the chain on a new thread with a `stack_size`-byte stack.
+    ///
+    /// A dedicated thread is used rather than `tokio::task::spawn_blocking`
+    /// because the blocking pool's stack size is fixed when the runtime is
+    /// built and cannot be raised for a single call.
+    FreshThread {
+        /// Stack size of the new thread, in bytes.
+        stack_size: usize,
+    },
+}
+
+thread_local! {
+    // Async factories currently being driven by block_on_factory on this thread
+    static ASYNC_DEPTH: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Counts one level of nesting in `ASYNC_DEPTH` for as long as it lives.
+struct AsyncDepthGuard;
+
+impl AsyncDepthGuard {
+    fn enter() -> Self {
+        ASYNC_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        AsyncDepthGuard
+    }
+}
+
+impl Drop for AsyncDepthGuard {
+    fn drop(&mut self) {
+        ASYNC_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Runs an async factory to completion from a synchronous resolution.
+///
+/// Used as the sync fallback for async registrations: inside a tokio runtime
+/// the future is driven on a blocking thread, otherwise a runtime is created.
+/// Once the nesting reaches the provider's async depth limit the
+/// [`AsyncDepthPolicy`] decides whether to fail or move to a fresh thread.
+pub(crate) fn block_on_factory<F>(resolver: &dyn ResolverCore, future: F) -> crate::DiResult<F::Output>
+where
+    F: std::future::Future + Send,
+    F::Output: Send,
+{
+    let limit = resolver.provider().and_then(|provider| provider.inner().async_depth_limit);
+    if let Some((max_depth, policy)) = limit {
+        if ASYNC_DEPTH.with(|depth| depth.get()) >= max_depth {
+            return match policy {
+                AsyncDepthPolicy::Fail => Err(crate::DiError::MaxAsyncDepthExceeded(max_depth)),
+                AsyncDepthPolicy::FreshThread { stack_size } => std::thread::scope(|s| {
+                    // The worker inherits the resolution stack so a cycle back
+                    // to a service still being built here is reported rather
+                    // than waiting on that service's initialization forever
+                    let stack = crate::internal::resolution_stack();
+                    let worker = std::thread::Builder::new()
+                        .name("ferrous-di-async-depth".to_string())
+                        .stack_size(stack_size)
+                        .spawn_scoped(s, move || {
+                            crate::internal::seed_resolution_stack(stack);
+                            run_to_completion(future)
+                        })
+                        .expect("Failed to spawn async resolution thread");
+                    match worker.join() {
+                        Ok(output) => Ok(output),
+                        Err(panic) => std::panic::resume_unwind(panic),
+                    }
+                }),
+            };
+        }
+    }
+    Ok(run_to_completion(future))
+}
+
+/// Like [`block_on_factory`], but reports a panicking factory the way
+/// `get_async` does: as [`DiError::FactoryPanicked`](crate::DiError::FactoryPanicked)
+/// after notifying the provider's `factory_panic` observers.
+pub(crate) fn block_on_catching<F>(
+    resolver: &dyn ResolverCore,
+    key: &crate::Key,
+    lifetime: crate::Lifetime,
+    future: F,
+) -> crate::DiResult<crate::registration::AnyArc>
+where
+    F: std::future::Future<Output = crate::DiResult<crate::registration::AnyArc>> + Send,
+{
+    match resolver.provider() {
+        Some(provider) => block_on_factory(resolver, provider.catch_factory_panic(key, lifetime, future))?,
+        None => block_on_factory(resolver, future)?,
+    }
+}
+
+/// Spawns `task` on the current Tokio runtime, or outside one on a dedicated
+/// thread running a runtime of its own until the task ends or is aborted.
+pub(crate) fn spawn_background<F>(task: F) -> tokio::task::AbortHandle
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        return handle.spawn(task).abort_handle();
+    }
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to create background runtime");
+    let task = runtime.spawn(task);
+    let abort = task.abort_handle();
+    std::thread::Builder::new()
+        .name("ferrous-di-background".to_string())
+        .spawn(move || {
+            let _ = runtime.block_on(task);
+        })
+        .expect("Failed to spawn background thread");
+    abort
+}
+
+fn run_to_completion<F: std::future::Future>(future: F) -> F::Output {
+    let _depth = AsyncDepthGuard::enter();
+    if tokio::runtime::Handle::try_current().is_ok() {
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create blocking runtime")
+                .block_on(future)
+        })
+    } else {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create async runtime")
+            .block_on(future)
+    }
+}
+
+/// Retry schedule with exponential backoff for
+/// [`add_singleton_async_retry`](crate::ServiceCollection::add_singleton_async_retry).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first
+    pub max_attempts: u32,
+    /// Delay before the second attempt
+    pub initial_delay: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_delay: Duration,
+    /// Factor the delay grows by after each failed attempt
+    pub backoff_multiplier: f64,
+    /// Randomize delays by ±10% so that retrying callers spread out
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            backoff_multiplier: 2.0,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Runs `operation` until it succeeds or `max_attempts` is used up,
+    /// sleeping without blocking the runtime between attempts. Returns the
+    /// last error on failure.
+    pub async fn execute_async<T, E, F, Fut>(&self, mut operation: F) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            match operation().await {
+                Ok(result) => return Ok(result),
+                Err(error) if attempt >= self.max_attempts => return Err(error),
+                Err(_) => tokio::time::sleep(self.delay_after(attempt)).await,
+            }
+        }
+    }
+
+    /// Delay after the `attempt`-th failed attempt (1-based).
+    fn delay_after(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.backoff_multiplier.powi(exponent);
+        let delay = delay.min(self.max_delay.as_secs_f64());
+        let delay = if self.jitter { delay * (0.9 + 0.2 * jitter_sample()) } else { delay };
+        Duration::from_secs_f64(delay.max(0.0))
+    }
+}
+
+/// Cheap pseudo-random value in `[0, 1]`, good enough to spread out retries.
+fn jitter_sample() -> f64 {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static SEED: AtomicU32 = AtomicU32::new(1);
+    let next = SEED.load(Ordering::Relaxed).wrapping_mul(1103515245).wrapping_add(12345);
+    SEED.store(next, Ordering::Relaxed);
+    f64::from(next) / f64::from(u32::MAX)
+}
+
+/// Runs an async factory under `policy`, reporting the last failure as
+/// [`DiError::FactoryFailed`](crate::DiError::FactoryFailed).
+pub(crate) async fn create_with_retry<T: Send + Sync + 'static>(
+    factory: &AsyncFactoryWrapper<T>,
+    policy: &RetryPolicy,
+    key: &crate::Key,
+    resolver: &dyn ResolverCore,
+) -> crate::DiResult<crate::registration::AnyArc> {
+    match policy.execute_async(|| factory.create(resolver)).await {
+        Ok(service) => Ok(service),
+        Err(e) => Err(crate::DiError::FactoryFailed { key: key.clone(), source: e.into() }),
+    }
+}
+
 /// Helper trait for creating async factories from closures.
 #[async_trait]
 impl<T, F, Fut> AsyncFactory<T> for F
 where
-    T: Send + Sync + 'static,
+    T: ?Sized + Send + Sync + 'static,
     F: Fn(&dyn ResolverCore) -> Fut + Send + Sync,
     Fut: std::future::Future<Output = Result<Arc<T>, Box<dyn std::error::Error + Send + Sync>>> + Send,
 {
@@ -237,4 +476,519 @@ mod tests {
         assert_eq!(*service, "closure async service");
         assert_eq!(init_count.load(Ordering::Relaxed), 1);
     }
-}
\ No newline at end of file
+
+    trait Connection: Send + Sync {
+        fn handshakes(&self) -> u32;
+    }
+
+    struct TcpConnection {
+        handshakes: u32,
+    }
+
+    impl Connection for TcpConnection {
+        fn handshakes(&self) -> u32 {
+            self.handshakes
+        }
+    }
+
+    trait Session: Send + Sync {}
+    struct UserSession;
+    impl Session for UserSession {}
+
+    #[tokio::test]
+    async fn test_async_trait_factory_resolves_natively() {
+        let init_count = Arc::new(AtomicU32::new(0));
+        let count = init_count.clone();
+
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_trait_async::<dyn Connection, _>(move |_resolver: &dyn ResolverCore| {
+            let count = count.clone();
+            async move {
+                // Simulated handshake
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                let handshakes = count.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(Arc::new(TcpConnection { handshakes }) as Arc<dyn Connection>)
+            }
+        });
+        services.add_scoped_trait_async::<dyn Session, _>(async_factory!(|_resolver| async {
+            Arc::new(UserSession) as Arc<dyn Session>
+        }));
+
+        let provider = services.build();
+        let first = provider.get_required_trait_async::<dyn Connection>().await;
+        let second = provider.get_required_trait_async::<dyn Connection>().await;
+
+        assert_eq!(first.handshakes(), 1);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(init_count.load(Ordering::SeqCst), 1);
+
+        let scope = provider.create_scope();
+        let from_scope = scope.get_required_trait_async::<dyn Connection>().await;
+        assert!(Arc::ptr_eq(&first, &from_scope));
+
+        let session_a = scope.get_required_trait_async::<dyn Session>().await;
+        let session_b = scope.get_required_trait_async::<dyn Session>().await;
+        assert!(Arc::ptr_eq(&session_a, &session_b));
+        assert!(provider.get_trait_async::<dyn Session>().await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_async_singleton_resolves_as_plain_type() {
+        let init_count = Arc::new(AtomicU32::new(0));
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_async::<AsyncService, _>(AsyncServiceFactory {
+            init_count: init_count.clone(),
+        });
+
+        let provider = services.build();
+        let service = crate::Resolver::get_required::<AsyncService>(&provider);
+        let again = crate::Resolver::get_required::<AsyncService>(&provider);
+
+        assert_eq!(service.value, "async initialized");
+        assert!(Arc::ptr_eq(&service, &again));
+        assert_eq!(init_count.load(Ordering::Relaxed), 1);
+        assert!(crate::Resolver::get::<Arc<AsyncService>>(&provider).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scope_deadline_aborts_slow_async_factory() {
+        struct SlowIndex;
+        struct FastConfig;
+
+        let mut services = crate::ServiceCollection::new();
+        services.add_scoped_async::<SlowIndex, _>(|resolver: &dyn ResolverCore| {
+            let has_deadline = resolver.deadline().is_some();
+            async move {
+                assert!(has_deadline);
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(Arc::new(SlowIndex))
+            }
+        });
+        services.add_scoped_async::<FastConfig, _>(async_factory!(|_resolver| async { Arc::new(FastConfig) }));
+
+        let provider = services.build();
+        let scope = provider.create_scope()
+            .with_deadline(std::time::Instant::now() + Duration::from_millis(20));
+
+        assert!(scope.get_async::<FastConfig>().await.is_ok());
+        match scope.get_async::<SlowIndex>().await {
+            Err(crate::DiError::DeadlineExceeded(name)) => assert!(name.ends_with("SlowIndex")),
+            other => panic!("expected DeadlineExceeded, got {:?}", other.err()),
+        }
+
+        // Once past the deadline nothing else resolves either
+        assert!(matches!(
+            scope.get_async::<FastConfig>().await,
+            Err(crate::DiError::DeadlineExceeded(_)),
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_spawn_resolving_runs_work_with_async_service() {
+        let init_count = Arc::new(AtomicU32::new(0));
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_async::<AsyncService, _>(AsyncServiceFactory {
+            init_count: init_count.clone(),
+        });
+        let provider = services.build();
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| provider.spawn_resolving::<AsyncService, _, _>(move |service| async move {
+                tokio::task::yield_now().await;
+                format!("{} #{}", service.value, i)
+            }))
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.await.unwrap().unwrap(), format!("async initialized #{}", i));
+        }
+        assert!(init_count.load(Ordering::Relaxed) >= 1);
+
+        struct Missing;
+        let missing = provider.spawn_resolving::<Missing, _, _>(|_| async {}).await.unwrap();
+        assert!(matches!(missing, Err(crate::DiError::NotFound(_))));
+    }
+
+    /// Observer that records the key and message of each factory panic
+    #[derive(Default)]
+    struct PanicRecorder {
+        panics: std::sync::Mutex<Vec<(String, String)>>,
+    }
+
+    impl crate::DiObserver for PanicRecorder {
+        fn resolving(&self, _key: &Key) {}
+        fn resolved(&self, _key: &Key, _duration: Duration) {}
+        fn factory_panic(&self, key: &Key, message: &str) {
+            self.panics.lock().unwrap().push((key.display_name().to_string(), message.to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_factory_panic_becomes_structured_error() {
+        struct Broken;
+
+        let recorder = Arc::new(PanicRecorder::default());
+        let mut services = crate::ServiceCollection::new();
+        services.add_observer(recorder.clone());
+        services.add_scoped_async::<Broken, _>(|_resolver: &dyn ResolverCore| async {
+            if true {
+                panic!("connection string missing");
+            }
+            Ok(Arc::new(Broken))
+        });
+
+        let provider = services.build();
+        let scope = provider.create_scope();
+        match scope.get_async::<Broken>().await {
+            Err(crate::DiError::FactoryPanicked { key, message }) => {
+                assert_eq!(key.display_name(), std::any::type_name::<Broken>());
+                assert_eq!(message, "connection string missing");
+            }
+            other => panic!("expected FactoryPanicked, got {:?}", other.err()),
+        }
+
+        let panics = recorder.panics.lock().unwrap();
+        assert_eq!(panics.len(), 1);
+        assert_eq!(panics[0], (std::any::type_name::<Broken>().to_string(), "connection string missing".to_string()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_async_factory_panic_on_sync_resolution_reaches_observers() {
+        struct Broken;
+
+        let recorder = Arc::new(PanicRecorder::default());
+        let mut services = crate::ServiceCollection::new();
+        services.add_observer(recorder.clone());
+        services.add_singleton_async::<Broken, _>(|_resolver: &dyn ResolverCore| async {
+            if true {
+                panic!("credentials expired");
+            }
+            Ok(Arc::new(Broken))
+        });
+
+        let provider = services.build();
+        match crate::Resolver::get::<Broken>(&provider) {
+            Err(crate::DiError::FactoryPanicked { message, .. }) => assert_eq!(message, "credentials expired"),
+            other => panic!("expected FactoryPanicked, got {:?}", other.err()),
+        }
+        let panics = recorder.panics.lock().unwrap();
+        assert_eq!(panics.len(), 1);
+        assert_eq!(panics[0].1, "credentials expired");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_ring_buffer_records_failed_resolutions_distinctly() {
+        use crate::{ResolutionOutcome, Resolver, RingBufferObserver};
+
+        struct Token;
+        struct Session;
+
+        let recent = Arc::new(RingBufferObserver::new(8));
+        let mut services = crate::ServiceCollection::new();
+        services.add_observer(recent.clone());
+        services.add_transient_factory::<Token, _>(|_| Token);
+        services.add_singleton_async::<Session, _>(|_resolver: &dyn ResolverCore| async {
+            Err("identity provider unreachable".into())
+        });
+
+        let provider = services.build();
+        provider.get_required::<Token>();
+        assert!(provider.get::<Session>().is_err());
+
+        let outcomes: Vec<_> = recent.snapshot().into_iter().map(|(_, _, outcome)| outcome).collect();
+        assert_eq!(outcomes[0], ResolutionOutcome::Resolved);
+        match &outcomes[1] {
+            ResolutionOutcome::Failed(message) => assert!(message.contains("identity provider unreachable")),
+            other => panic!("expected Failed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_failed_ephemeral_scope_keeps_async_disposers_for_dispose_all() {
+        struct Rejected;
+
+        let closed = Arc::new(AtomicU32::new(0));
+        let mut services = crate::ServiceCollection::new();
+        let hook_closed = closed.clone();
+        services.add_scoped_async::<Rejected, _>(move |resolver: &dyn ResolverCore| {
+            let closed = hook_closed.clone();
+            resolver.push_async_disposer(Box::new(move || Box::pin(async move {
+                closed.fetch_add(1, Ordering::SeqCst);
+            })));
+            async { Err("handshake refused".into()) }
+        });
+        services.scoped_from_root_policy(crate::ScopedFromRootPolicy::EphemeralScope);
+
+        let provider = services.build();
+        assert!(crate::Resolver::get::<Rejected>(&provider).is_err());
+        assert_eq!(closed.load(Ordering::SeqCst), 0);
+
+        provider.dispose_all().await;
+        assert_eq!(closed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reactive_singleton_follows_updates_until_disposed() {
+        use crate::Resolver;
+
+        #[derive(Debug, PartialEq)]
+        struct Limits { max_tokens: u32 }
+
+        async fn wait_for(provider: &crate::ServiceProvider, max_tokens: u32) {
+            for _ in 0..100 {
+                if provider.get_required::<Limits>().max_tokens == max_tokens {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+            panic!("update to {} never arrived", max_tokens);
+        }
+
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_reactive(Limits { max_tokens: 100 }, rx);
+        let provider = services.build();
+        assert_eq!(provider.get_required::<Limits>().max_tokens, 100);
+
+        tx.unbounded_send(Limits { max_tokens: 200 }).unwrap();
+        wait_for(&provider, 200).await;
+        tx.unbounded_send(Limits { max_tokens: 300 }).unwrap();
+        wait_for(&provider, 300).await;
+
+        provider.dispose_all().await;
+        for _ in 0..100 {
+            if tx.is_closed() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        // The aborted task dropped the receiver
+        assert!(tx.is_closed());
+        assert!(tx.unbounded_send(Limits { max_tokens: 400 }).is_err());
+        assert_eq!(provider.get_required::<Limits>().max_tokens, 300);
+    }
+
+    #[test]
+    fn test_reactive_singleton_registers_and_runs_outside_a_runtime() {
+        use crate::Resolver;
+
+        struct Limits { max_tokens: u32 }
+
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_reactive(Limits { max_tokens: 100 }, rx);
+        let lifetimes: Vec<_> = services.get_service_descriptors().iter().map(|d| d.lifetime).collect();
+        assert_eq!(lifetimes, vec![crate::Lifetime::Singleton]);
+
+        let provider = services.build();
+        let scope = provider.create_scope();
+        tx.unbounded_send(Limits { max_tokens: 200 }).unwrap();
+        for _ in 0..100 {
+            if scope.get_required::<Limits>().max_tokens == 200 {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        // Root and scopes share the latest value
+        assert_eq!(scope.get_required::<Limits>().max_tokens, 200);
+        assert!(Arc::ptr_eq(&provider.get_required::<Limits>(), &scope.get_required::<Limits>()));
+
+        // Dropping the provider stops the task, which drops the receiver
+        drop((scope, provider));
+        for _ in 0..100 {
+            if tx.is_closed() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(tx.is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_async_retry_singleton_survives_transient_failures() {
+        struct Client { attempt: u32 }
+
+        let policy = || crate::RetryPolicy {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            ..crate::RetryPolicy::default()
+        };
+        // Fails twice, then connects
+        let flaky = |attempts: Arc<AtomicU32>| move |_: &dyn ResolverCore| {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if attempt <= 2 {
+                    return Err(format!("handshake {} timed out", attempt).into());
+                }
+                Ok(Arc::new(Client { attempt }))
+            }
+        };
+
+        let attempts = Arc::new(AtomicU32::new(0));
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_async_retry::<Client, _>(flaky(attempts.clone()), policy());
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        let client = scope.get_async::<Client>().await.unwrap();
+        assert_eq!(client.attempt, 3);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        // The successful result is cached
+        let again = scope.get_async::<Client>().await.unwrap();
+        assert!(Arc::ptr_eq(&client, &again));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // Running out of attempts caches nothing, so the next resolution retries
+        let attempts = Arc::new(AtomicU32::new(0));
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_async_retry::<Client, _>(
+            flaky(attempts.clone()),
+            crate::RetryPolicy { max_attempts: 2, ..policy() },
+        );
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        match scope.get_async::<Client>().await {
+            Err(crate::DiError::FactoryFailed { source, .. }) => assert_eq!(source.to_string(), "handshake 2 timed out"),
+            other => panic!("expected FactoryFailed, got {:?}", other.err()),
+        }
+        assert_eq!(scope.get_async::<Client>().await.unwrap().attempt, 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_failing_async_factory_returns_factory_failed() {
+        struct Database;
+
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_async::<Database, _>(|_resolver: &dyn ResolverCore| async {
+            Err::<Arc<Database>, _>("connection refused".into())
+        });
+        let provider = services.build();
+
+        let err = crate::Resolver::get::<Database>(&provider).err().expect("factory should fail");
+        match &err {
+            crate::DiError::FactoryFailed { key, source } => {
+                assert!(key.display_name().ends_with("Database"));
+                assert_eq!(source.to_string(), "connection refused");
+            }
+            other => panic!("expected FactoryFailed, got {:?}", other),
+        }
+        // The factory's error is the next link in the chain
+        let source = std::error::Error::source(&err).expect("factory error as source");
+        assert_eq!(source.to_string(), "connection refused");
+        assert!(matches!(
+            provider.create_scope().get_async::<Database>().await,
+            Err(crate::DiError::FactoryFailed { .. }),
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_failing_async_trait_factory_returns_factory_failed() {
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_trait_async::<dyn Connection, _>(|_resolver: &dyn ResolverCore| async {
+            Err::<Arc<dyn Connection>, _>("handshake rejected".into())
+        });
+        let provider = services.build();
+
+        match crate::Resolver::get_trait::<dyn Connection>(&provider) {
+            Err(crate::DiError::FactoryFailed { source, .. }) => assert_eq!(source.to_string(), "handshake rejected"),
+            other => panic!("expected FactoryFailed, got {:?}", other.err()),
+        }
+        assert!(matches!(
+            provider.get_trait_async::<dyn Connection>().await,
+            Err(crate::DiError::FactoryFailed { .. }),
+        ));
+    }
+
+    struct Link<const N: usize>;
+
+    /// Registers `Link<N>` as an async singleton whose factory synchronously
+    /// resolves `Next`, recording any resolution error in `failure`.
+    fn add_link<const N: usize, Next: Send + Sync + 'static>(
+        services: &mut crate::ServiceCollection,
+        built: Arc<AtomicU32>,
+        failure: Arc<std::sync::Mutex<Option<crate::DiError>>>,
+    ) {
+        services.add_singleton_async::<Link<N>, _>(move |resolver: &dyn ResolverCore| {
+            let next = resolver.resolve_any(&Key::Type(std::any::TypeId::of::<Next>(), std::any::type_name::<Next>()));
+            if let Err(e) = next {
+                failure.lock().unwrap().get_or_insert(e);
+            }
+            built.fetch_add(1, Ordering::SeqCst);
+            async { Ok(Arc::new(Link::<N>)) }
+        });
+    }
+
+    fn deep_chain(policy: AsyncDepthPolicy) -> (crate::ServiceProvider, Arc<AtomicU32>, Arc<std::sync::Mutex<Option<crate::DiError>>>) {
+        let built = Arc::new(AtomicU32::new(0));
+        let failure = Arc::new(std::sync::Mutex::new(None));
+        let mut services = crate::ServiceCollection::new();
+        add_link::<0, Link<1>>(&mut services, built.clone(), failure.clone());
+        add_link::<1, Link<2>>(&mut services, built.clone(), failure.clone());
+        add_link::<2, Link<3>>(&mut services, built.clone(), failure.clone());
+        add_link::<3, Link<4>>(&mut services, built.clone(), failure.clone());
+        add_link::<4, Link<5>>(&mut services, built.clone(), failure.clone());
+        services.add_singleton(Link::<5>);
+        services.max_async_depth(2, policy);
+        (services.build(), built, failure)
+    }
+
+    #[test]
+    fn test_async_depth_limit_fails_deep_sync_chain() {
+        let (provider, built, failure) = deep_chain(AsyncDepthPolicy::Fail);
+
+        // The two outer factories run; the third nested one is refused
+        assert!(crate::Resolver::get::<Link<0>>(&provider).is_ok());
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        assert!(matches!(
+            failure.lock().unwrap().take(),
+            Some(crate::DiError::MaxAsyncDepthExceeded(2)),
+        ));
+    }
+
+    #[test]
+    fn test_async_depth_limit_moves_deep_chain_to_fresh_thread() {
+        let (provider, built, failure) = deep_chain(AsyncDepthPolicy::FreshThread { stack_size: 4 * 1024 * 1024 });
+
+        assert!(crate::Resolver::get::<Link<0>>(&provider).is_ok());
+        assert_eq!(built.load(Ordering::SeqCst), 5);
+        assert!(failure.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_async_depth_fresh_thread_reports_cycles() {
+        struct Left;
+        struct Right;
+
+        fn add_side<T: Send + Sync + 'static, Other: Send + Sync + 'static>(
+            services: &mut crate::ServiceCollection,
+            make: fn() -> T,
+            failure: Arc<std::sync::Mutex<Option<crate::DiError>>>,
+        ) {
+            services.add_singleton_async::<T, _>(move |resolver: &dyn ResolverCore| {
+                let other = resolver.resolve_any(&Key::Type(std::any::TypeId::of::<Other>(), std::any::type_name::<Other>()));
+                if let Err(e) = other {
+                    failure.lock().unwrap().get_or_insert(e);
+                }
+                async move { Ok(Arc::new(make())) }
+            });
+        }
+
+        let failure = Arc::new(std::sync::Mutex::new(None));
+        let mut services = crate::ServiceCollection::new();
+        add_side::<Left, Right>(&mut services, || Left, failure.clone());
+        add_side::<Right, Left>(&mut services, || Right, failure.clone());
+        services.max_async_depth(1, AsyncDepthPolicy::FreshThread { stack_size: 4 * 1024 * 1024 });
+        let provider = services.build();
+
+        // Resolve on a helper thread so a regression fails instead of hanging
+        let (done, finished) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = crate::Resolver::get::<Left>(&provider);
+            let _ = done.send(());
+        });
+        finished.recv_timeout(Duration::from_secs(10)).expect("cyclic resolution hung");
+        assert!(matches!(failure.lock().unwrap().take(), Some(crate::DiError::Circular(_))));
+    }
+}
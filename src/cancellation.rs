@@ -307,7 +307,7 @@ impl ScopeCancellationExt for crate::provider::Scope {
         #[cfg(feature = "once-cell")]
         {
             // Find the slot for CancellationToken in the registry
-            if let Some(reg) = child_scope.root.inner().registry.registrations.get(&token_key) {
+            if let Some(reg) = child_scope.root.inner().registry.get(&token_key) {
                 if let Some(slot) = reg.scoped_slot {
                     // Initialize the slot with our child token
                     let _ = child_scope.scoped_cells[slot].set(Arc::new(child_token) as crate::registration::AnyArc);
@@ -84,6 +84,81 @@ pub trait ToolCapability: Send + Sync {
     fn reliability(&self) -> Option<f64> {
         None
     }
+
+    /// Optional input-dependent cost model used for plan budgeting.
+    ///
+    /// When present, it takes precedence over [`estimated_cost`](Self::estimated_cost)
+    /// in [`ServiceProvider::estimate_plan_cost`](crate::ServiceProvider::estimate_plan_cost).
+    #[cfg(feature = "cost-estimation")]
+    fn cost_model(&self) -> Option<CostModel> {
+        None
+    }
+}
+
+/// Input-dependent cost model for a tool.
+///
+/// Combines a static per-invocation cost with an optional estimator that
+/// derives additional cost from the invocation input (for example, the
+/// number of tokens in a prompt or the size of a file to read).
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::CostModel;
+/// use serde_json::json;
+///
+/// // $0.01 per call plus $0.001 per requested result
+/// let model = CostModel::fixed(0.01).with_estimator(|input| {
+///     input["limit"].as_f64().unwrap_or(0.0) * 0.001
+/// });
+///
+/// assert!((model.estimate(&json!({ "limit": 10 })) - 0.02).abs() < 1e-9);
+/// ```
+#[cfg(feature = "cost-estimation")]
+#[derive(Clone)]
+pub struct CostModel {
+    /// Static cost charged for every invocation.
+    pub base_cost: f64,
+    estimator: Option<CostEstimator>,
+}
+
+/// Estimator deriving additional cost from a tool's input.
+#[cfg(feature = "cost-estimation")]
+type CostEstimator = Arc<dyn Fn(&serde_json::Value) -> f64 + Send + Sync>;
+
+#[cfg(feature = "cost-estimation")]
+impl CostModel {
+    /// Creates a cost model with a fixed per-invocation cost.
+    pub fn fixed(base_cost: f64) -> Self {
+        Self {
+            base_cost,
+            estimator: None,
+        }
+    }
+
+    /// Adds an estimator whose result is added to the base cost.
+    pub fn with_estimator<F>(mut self, estimator: F) -> Self
+    where
+        F: Fn(&serde_json::Value) -> f64 + Send + Sync + 'static,
+    {
+        self.estimator = Some(Arc::new(estimator));
+        self
+    }
+
+    /// Estimates the cost of invoking the tool with `input`.
+    pub fn estimate(&self, input: &serde_json::Value) -> f64 {
+        self.base_cost + self.estimator.as_ref().map_or(0.0, |f| f(input))
+    }
+}
+
+#[cfg(feature = "cost-estimation")]
+impl std::fmt::Debug for CostModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CostModel")
+            .field("base_cost", &self.base_cost)
+            .field("has_estimator", &self.estimator.is_some())
+            .finish()
+    }
 }
 
 /// Capability requirement for tool selection.
@@ -239,6 +314,9 @@ pub struct ToolInfo {
     pub estimated_cost: Option<f64>,
     /// Reliability score.
     pub reliability: Option<f64>,
+    /// Input-dependent cost model.
+    #[cfg(feature = "cost-estimation")]
+    pub cost_model: Option<CostModel>,
 }
 
 impl ToolInfo {
@@ -314,6 +392,8 @@ impl CapabilityRegistry {
             tags: tool.tags().into_iter().map(|s| s.to_string()).collect(),
             estimated_cost: tool.estimated_cost(),
             reliability: tool.reliability(),
+            #[cfg(feature = "cost-estimation")]
+            cost_model: tool.cost_model(),
         };
         
         self.tools.insert(key, info);
@@ -407,6 +487,60 @@ impl CapabilityRegistry {
     pub(crate) fn get_tool(&self, key: &Key) -> Option<&ToolInfo> {
         self.tools.get(key)
     }
+
+    /// Gets tool info by tool name.
+    #[cfg(feature = "cost-estimation")]
+    pub(crate) fn get_tool_by_name(&self, name: &str) -> Option<&ToolInfo> {
+        self.tools.values().find(|tool| tool.name == name)
+    }
+}
+
+#[cfg(feature = "cost-estimation")]
+impl crate::ServiceProvider {
+    /// Estimates the total cost of executing a plan.
+    ///
+    /// Each step is a `(tool_name, input)` pair. A step's cost comes from the
+    /// tool's [`CostModel`] if it has one, otherwise from its static
+    /// `estimated_cost`. Steps naming unknown tools, or tools without any cost
+    /// information, contribute zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, ToolCapability, CostModel};
+    /// use serde_json::json;
+    ///
+    /// struct Search;
+    /// impl ToolCapability for Search {
+    ///     fn name(&self) -> &str { "search" }
+    ///     fn description(&self) -> &str { "Web search" }
+    ///     fn version(&self) -> &str { "1.0.0" }
+    ///     fn capabilities(&self) -> Vec<&str> { vec!["web_search"] }
+    ///     fn requires(&self) -> Vec<&str> { vec![] }
+    ///     fn cost_model(&self) -> Option<CostModel> { Some(CostModel::fixed(0.5)) }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_tool_singleton(Search);
+    /// let provider = services.build();
+    ///
+    /// let plan = vec![
+    ///     ("search".to_string(), json!({ "q": "rust" })),
+    ///     ("search".to_string(), json!({ "q": "di" })),
+    /// ];
+    /// assert_eq!(provider.estimate_plan_cost(&plan), 1.0);
+    /// ```
+    pub fn estimate_plan_cost(&self, plan: &[(String, serde_json::Value)]) -> f64 {
+        plan.iter()
+            .filter_map(|(name, input)| {
+                let tool = self.inner().capabilities.get_tool_by_name(name)?;
+                match &tool.cost_model {
+                    Some(model) => Some(model.estimate(input)),
+                    None => tool.estimated_cost,
+                }
+            })
+            .sum()
+    }
 }
 
 impl ServiceCollection {
@@ -0,0 +1,195 @@
+//! Snapshot and restore of scoped service state.
+//!
+//! Durable workflows checkpoint their own step outputs, but per-run state that
+//! lives in scoped services (conversation memory, counters, accumulated
+//! context) is lost when a run resumes in a fresh scope. Services that
+//! implement [`CheckpointableScoped`] and are registered with
+//! [`ServiceCollection::add_scoped_checkpointable`] can be captured with
+//! [`Scope::snapshot_scoped`] and put back with [`Scope::restore_scoped`].
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::registration::AnyArc;
+use crate::traits::ResolverCore;
+use crate::{Key, Lifetime, ResolverContext, Scope, ServiceCollection};
+
+/// Scoped service whose state can be saved into a checkpoint and restored
+/// into a freshly constructed instance.
+///
+/// `snapshot` runs against the shared instance, so state that changes during
+/// a run must sit behind interior mutability. `restore` runs once on a newly
+/// built instance before it is shared.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{CheckpointableScoped, Resolver, ServiceCollection};
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// #[derive(Default)]
+/// struct TurnCounter(AtomicU64);
+///
+/// impl CheckpointableScoped for TurnCounter {
+///     fn snapshot(&self) -> serde_json::Value {
+///         self.0.load(Ordering::SeqCst).into()
+///     }
+///     fn restore(&mut self, state: serde_json::Value) {
+///         *self.0.get_mut() = state.as_u64().unwrap_or(0);
+///     }
+/// }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_scoped_checkpointable(|_| TurnCounter::default());
+/// let provider = services.build();
+///
+/// let run = provider.create_scope();
+/// run.get_required::<TurnCounter>().0.fetch_add(3, Ordering::SeqCst);
+/// let snapshot = run.snapshot_scoped();
+///
+/// let resumed = provider.create_scope();
+/// resumed.restore_scoped(&snapshot);
+/// assert_eq!(resumed.get_required::<TurnCounter>().0.load(Ordering::SeqCst), 3);
+/// ```
+pub trait CheckpointableScoped: Send + Sync + 'static {
+    /// Captures the current state as JSON.
+    fn snapshot(&self) -> Value;
+
+    /// Replaces the state of a fresh instance with a previously captured one.
+    fn restore(&mut self, state: Value);
+}
+
+/// Builds a fresh instance and applies saved state to it.
+type RestoreFn = Arc<dyn Fn(&ResolverContext, Value) -> AnyArc + Send + Sync>;
+
+/// Registration metadata recording how to snapshot and rebuild a service.
+pub(crate) struct CheckpointHooks {
+    snapshot: fn(&AnyArc) -> Option<Value>,
+    restore: RestoreFn,
+}
+
+fn snapshot_erased<T: CheckpointableScoped>(any: &AnyArc) -> Option<Value> {
+    any.downcast_ref::<T>().map(T::snapshot)
+}
+
+impl ServiceCollection {
+    /// Registers a scoped factory whose instances take part in
+    /// [`Scope::snapshot_scoped`] and [`Scope::restore_scoped`].
+    ///
+    /// Resolution behaves exactly like
+    /// [`add_scoped_factory`](ServiceCollection::add_scoped_factory).
+    pub fn add_scoped_checkpointable<T, F>(&mut self, factory: F) -> &mut Self
+    where
+        T: CheckpointableScoped,
+        F: Fn(&ResolverContext) -> T + Send + Sync + 'static,
+    {
+        let factory = Arc::new(factory);
+        let build = factory.clone();
+        self.add_scoped_factory::<T, _>(move |r| factory(r));
+
+        let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+        self.set_metadata(&key, CheckpointHooks {
+            snapshot: snapshot_erased::<T>,
+            restore: Arc::new(move |r, state| {
+                let mut value = build(r);
+                value.restore(state);
+                Arc::new(value) as AnyArc
+            }),
+        });
+        self
+    }
+}
+
+impl Scope {
+    /// Captures the state of every checkpointable scoped service this scope
+    /// has already created, keyed by type name.
+    ///
+    /// Services that were never resolved in this scope are left out; they
+    /// will be built from scratch on resume as usual.
+    pub fn snapshot_scoped(&self) -> HashMap<String, Value> {
+        let mut snapshot = HashMap::new();
+        for (key, reg) in self.root.inner().registry.iter() {
+            let Some(hooks) = checkpoint_hooks(reg) else { continue };
+            if !self.has_scoped_instance(reg, key) {
+                continue;
+            }
+            if let Some(state) = self.resolve_any(key).ok().as_ref().and_then(hooks.snapshot) {
+                snapshot.insert(key.display_name().to_string(), state);
+            }
+        }
+        snapshot
+    }
+
+    /// Rebuilds checkpointable scoped services from a snapshot taken by
+    /// [`snapshot_scoped`](Scope::snapshot_scoped).
+    ///
+    /// Call this on a freshly created scope when resuming, before resolving
+    /// anything from it. Each service named in the snapshot is constructed by
+    /// its factory, restored, and cached in this scope. Entries for services
+    /// that are no longer registered are ignored, and a service this scope
+    /// already created keeps its current state.
+    pub fn restore_scoped(&self, snapshot: &HashMap<String, Value>) {
+        for (key, reg) in self.root.inner().registry.iter() {
+            let Some(hooks) = checkpoint_hooks(reg) else { continue };
+            let Some(state) = snapshot.get(key.display_name()) else { continue };
+            if self.has_scoped_instance(reg, key) {
+                continue;
+            }
+            let value = (hooks.restore)(&ResolverContext::new(self), state.clone());
+            self.store_scoped(reg, key, value);
+        }
+    }
+}
+
+fn checkpoint_hooks(reg: &crate::registration::Registration) -> Option<&CheckpointHooks> {
+    if reg.lifetime != Lifetime::Scoped {
+        return None;
+    }
+    reg.metadata.as_ref()?.downcast_ref::<CheckpointHooks>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Resolver;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Counter {
+        count: Mutex<u64>,
+    }
+
+    impl CheckpointableScoped for Counter {
+        fn snapshot(&self) -> Value {
+            Value::from(*self.count.lock().unwrap())
+        }
+
+        fn restore(&mut self, state: Value) {
+            *self.count.get_mut().unwrap() = state.as_u64().unwrap_or_default();
+        }
+    }
+
+    #[test]
+    fn test_snapshot_restores_into_fresh_scope() {
+        let mut services = ServiceCollection::new();
+        services.add_scoped_checkpointable(|_| Counter::default());
+        let provider = services.build();
+
+        let first = provider.create_scope();
+        assert!(first.snapshot_scoped().is_empty());
+        *first.get_required::<Counter>().count.lock().unwrap() += 5;
+        let snapshot = first.snapshot_scoped();
+        assert_eq!(snapshot.len(), 1);
+
+        let resumed = provider.create_scope();
+        resumed.restore_scoped(&snapshot);
+        assert_eq!(*resumed.get_required::<Counter>().count.lock().unwrap(), 5);
+
+        // Scopes without a restore still start from the factory's state.
+        let fresh = provider.create_scope();
+        assert_eq!(*fresh.get_required::<Counter>().count.lock().unwrap(), 0);
+    }
+}
@@ -4,27 +4,79 @@
 //! for registering services and building service providers.
 
 use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::{DiResult, DiError, Key, Lifetime, ServiceDescriptor, DiObserver};
+use crate::{DiResult, DiError, Discriminant, Key, Lifetime, ServiceDescriptor, DiObserver};
+use crate::descriptors::RegistrationKind;
 use crate::registration::{Registry, Registration, AnyArc};
 use crate::provider::ResolverContext;
 use crate::observer::Observers;
 use crate::prewarm::PrewarmSet;
 use crate::capabilities::CapabilityRegistry;
+use crate::traits::{Resolver, ResolverCore};
 use crate::ServiceProvider;
 
 
 pub mod module_system;
 pub use module_system::*;
+pub mod typed_module;
+pub use typed_module::{Dependencies, Proof, Registered, RegisteredTrait, TypedModule};
+
+/// Compile-time check that `T` is unsized, i.e. a trait object such as
+/// `dyn Logger`, for APIs that register under a trait key.
+struct AssertTraitObject<T: ?Sized>(std::marker::PhantomData<T>);
+
+impl<T: ?Sized> AssertTraitObject<T> {
+    const OK: () = assert!(
+        std::mem::size_of::<&T>() > std::mem::size_of::<&()>(),
+        "expected a trait object such as `dyn Trait`, found a sized type"
+    );
+}
 
 pub struct ServiceCollection {
     registry: Registry,
     observers: Observers,
     prewarm: PrewarmSet,
     pub(crate) capabilities: CapabilityRegistry,
+    pub(crate) expensive: HashSet<&'static str>,
+    deprecations: HashMap<Key, &'static str>,
+    pub(crate) lint_unused: bool,
+    pub(crate) check_trait_casts: bool,
+    dedup_multi: bool,
+    pub(crate) roots: HashSet<&'static str>,
+    eager: Vec<Key>,
+    dispose_observers: Vec<Arc<dyn crate::DisposeObserver>>,
+    assertions: Vec<BuildAssertion>,
+    labels: crate::service_labels::ServiceLabels,
+    key_rewriters: Vec<Arc<dyn crate::KeyRewriter>>,
+    scoped_from_root: crate::ScopedFromRootPolicy,
+    #[cfg(feature = "async")]
+    async_depth_limit: Option<(usize, crate::AsyncDepthPolicy)>,
+    pub(crate) memory_probes: HashMap<Key, crate::metrics::SizeProbe>,
+    null_objects: Vec<(Key, Registration)>,
+    #[cfg(feature = "async")]
+    background_tasks: Vec<BackgroundTask>,
+}
+
+/// Aborts a background task when dropped.
+#[cfg(feature = "async")]
+struct AbortOnDrop(tokio::task::AbortHandle);
+
+#[cfg(feature = "async")]
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
 }
 
+/// Starts a task that runs alongside the provider, returning its abort handle.
+#[cfg(feature = "async")]
+type BackgroundTask = Box<dyn FnOnce() -> tokio::task::AbortHandle + Send>;
+
+/// Invariant check registered with [`ServiceCollection::assert_on_build`].
+type BuildAssertion = Box<dyn Fn(&ServiceProvider) -> Result<(), String> + Send + Sync>;
+
 impl ServiceCollection {
     /// Creates a new empty service collection.
     pub fn new() -> Self {
@@ -33,9 +85,32 @@ impl ServiceCollection {
             observers: Observers::new(),
             prewarm: PrewarmSet::new(),
             capabilities: CapabilityRegistry::new(),
+            expensive: HashSet::new(),
+            deprecations: HashMap::new(),
+            lint_unused: false,
+            check_trait_casts: false,
+            dedup_multi: false,
+            roots: HashSet::new(),
+            eager: Vec::new(),
+            dispose_observers: Vec::new(),
+            assertions: Vec::new(),
+            labels: Default::default(),
+            key_rewriters: Vec::new(),
+            scoped_from_root: crate::ScopedFromRootPolicy::Error,
+            #[cfg(feature = "async")]
+            async_depth_limit: None,
+            memory_probes: HashMap::new(),
+            null_objects: Vec::new(),
+            #[cfg(feature = "async")]
+            background_tasks: Vec::new(),
         }
     }
     
+    /// Creates a collection around an existing set of registrations.
+    pub(crate) fn from_registry(registry: Registry) -> Self {
+        Self { registry, ..Self::new() }
+    }
+
     // ----- Concrete Type Registrations -----
     
     /// Registers a singleton instance that will be shared across the entire application.
@@ -67,7 +142,7 @@ impl ServiceCollection {
             Arc::new(ctor),
             None,
             Some(TypeId::of::<T>()),
-        ));
+        ).instance());
         self
     }
     
@@ -100,6 +175,110 @@ impl ServiceCollection {
     {
         self.add_factory(Lifetime::Singleton, factory)
     }
+
+    /// Registers a singleton factory along with the dependencies it resolves.
+    ///
+    /// The graph exporter normally discovers edges by running each factory
+    /// against a tracking resolver, which has side effects and only sees the
+    /// branch taken on that run. For a service registered here it uses the
+    /// declared edges instead and never invokes the factory. Resolution is
+    /// unaffected: the factory still resolves whatever it asks for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{DependencyDecl, Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// struct Database;
+    /// struct Cache;
+    /// struct UserService { db: Arc<Database>, cache: Option<Arc<Cache>> }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Database);
+    /// services.add_singleton_factory_declared::<UserService, _>(
+    ///     &[DependencyDecl::of::<Database>(), DependencyDecl::of::<Cache>().optional()],
+    ///     |r| UserService { db: r.get_required::<Database>(), cache: r.get::<Cache>().ok() },
+    /// );
+    /// ```
+    pub fn add_singleton_factory_declared<T, F>(&mut self, deps: &[crate::DependencyDecl], factory: F) -> &mut Self
+    where
+        T: 'static + Send + Sync,
+        F: Fn(&ResolverContext) -> T + Send + Sync + 'static,
+    {
+        self.add_factory(Lifetime::Singleton, factory);
+        let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+        if let Some(reg) = self.registry.get_mut(&key) {
+            reg.declared_deps = Some(deps.into());
+        }
+        self
+    }
+
+    /// Registers a singleton factory that runs during [`build`](Self::build)
+    /// instead of on first resolution.
+    ///
+    /// A factory that panics or fails to resolve its dependencies makes
+    /// `build()` panic, so misconfiguration surfaces at startup rather than
+    /// on the first request. The instance is cached from then on. Unlike
+    /// prewarming, nothing needs to call `ready()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{ServiceCollection, Resolver};
+    /// struct Config { port: u16 }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_eager_factory::<Config, _>(|_| Config { port: 8080 });
+    ///
+    /// let provider = services.build(); // factory has already run
+    /// assert_eq!(provider.get_required::<Config>().port, 8080);
+    /// ```
+    pub fn add_singleton_eager_factory<T, F>(&mut self, factory: F) -> &mut Self
+    where
+        T: 'static + Send + Sync,
+        F: Fn(&ResolverContext) -> T + Send + Sync + 'static,
+    {
+        self.eager.push(Key::Type(TypeId::of::<T>(), std::any::type_name::<T>()));
+        self.add_singleton_factory(factory)
+    }
+    
+    /// Registers a singleton factory that only resolves while a runtime feature flag is on.
+    ///
+    /// On every resolution the registered `dyn FeatureFlags` is consulted; if
+    /// `flag` is off, resolution fails with [`DiError::FeatureDisabled`]. The
+    /// instance is still created once and shared, so turning the flag off and
+    /// on again returns the same instance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{ServiceCollection, FeatureFlags, Resolver};
+    /// # use std::sync::Arc;
+    /// struct AllOn;
+    /// impl FeatureFlags for AllOn {
+    ///     fn is_enabled(&self, _flag: &str) -> bool { true }
+    /// }
+    /// struct Summarizer;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_trait::<dyn FeatureFlags>(Arc::new(AllOn));
+    /// services.add_singleton_gated::<Summarizer, _>("summarizer-v2", |_| Summarizer);
+    ///
+    /// let provider = services.build();
+    /// assert!(provider.get::<Summarizer>().is_ok());
+    /// ```
+    pub fn add_singleton_gated<T, F>(&mut self, flag: &'static str, factory: F) -> &mut Self
+    where
+        T: 'static + Send + Sync,
+        F: Fn(&ResolverContext) -> T + Send + Sync + 'static,
+    {
+        self.add_factory(Lifetime::Singleton, factory);
+        if let Some(reg) = self.registry.get_mut(&crate::key_of_type::<T>()) {
+            reg.feature_gate = Some(flag);
+        }
+        self
+    }
     
     /// Registers a scoped factory that creates one instance per scope.
     ///
@@ -161,7 +340,42 @@ impl ServiceCollection {
     {
         self.add_factory(Lifetime::Transient, factory)
     }
-    
+
+    /// Registers a factory whose lifetime is chosen by the caller at runtime.
+    ///
+    /// Equivalent to `add_singleton_factory`, `add_scoped_factory` or
+    /// `add_transient_factory` depending on `lifetime`, for modules that take
+    /// the lifetime as a parameter because the host decides it (a service
+    /// shared process-wide when single-tenant but per request when multi-tenant).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Lifetime, Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// struct TenantCache;
+    ///
+    /// fn register_cache(services: &mut ServiceCollection, lifetime: Lifetime) {
+    ///     services.add_factory_with_lifetime::<TenantCache, _>(lifetime, |_| TenantCache);
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// register_cache(&mut services, Lifetime::Singleton);
+    /// let provider = services.build();
+    /// assert!(Arc::ptr_eq(
+    ///     &provider.get_required::<TenantCache>(),
+    ///     &provider.create_scope().get_required::<TenantCache>(),
+    /// ));
+    /// ```
+    pub fn add_factory_with_lifetime<T, F>(&mut self, lifetime: Lifetime, factory: F) -> &mut Self
+    where
+        T: 'static + Send + Sync,
+        F: Fn(&ResolverContext) -> T + Send + Sync + 'static,
+    {
+        self.add_factory(lifetime, factory)
+    }
+
     fn add_factory<T, F>(&mut self, lifetime: Lifetime, factory: F) -> &mut Self
     where
         T: 'static + Send + Sync,
@@ -226,9 +440,36 @@ impl ServiceCollection {
             Arc::new(ctor),
             None,
             None, // We don't know the concrete implementation type for trait objects
-        ));
+        ).instance());
         self
     }
+
+    /// Registers a closure as the singleton implementation of trait `T`.
+    ///
+    /// `T` must be a single-method trait declared with
+    /// [`trait_fn!`](crate::trait_fn), which maps the closure onto the method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{trait_fn, ServiceCollection, Resolver};
+    /// trait Logger: Send + Sync {
+    ///     fn log(&self, message: &str);
+    /// }
+    /// trait_fn!(dyn Logger => fn log(&self, message: &str));
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_trait_fn::<dyn Logger, _>(|message: &str| println!("{}", message));
+    ///
+    /// let provider = services.build();
+    /// provider.get_required_trait::<dyn Logger>().log("started");
+    /// ```
+    pub fn add_singleton_trait_fn<T, F>(&mut self, f: F) -> &mut Self
+    where
+        T: ?Sized + crate::TraitFn<F> + 'static,
+    {
+        self.add_singleton_trait::<T>(T::from_fn(f))
+    }
     
     /// Registers a singleton trait factory.
     ///
@@ -357,7 +598,231 @@ impl ServiceCollection {
         ));
         self
     }
-    
+
+    /// Makes a trait registration resolvable through one of its supertraits.
+    ///
+    /// Installs a `Super` trait registration that resolves the existing `Sub`
+    /// registration and upcasts it, so both keys hand out the same instance
+    /// without a duplicate registration. The `Sub` registration keeps its own
+    /// lifetime and caching; the upcast key simply delegates to it.
+    ///
+    /// The `cast` function performs the coercion. With trait upcasting this
+    /// is just the identity closure `|sub| sub`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{ServiceCollection, Resolver};
+    /// # use std::sync::Arc;
+    /// trait Named: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    /// trait ApiClient: Named {
+    ///     fn call(&self) -> String;
+    /// }
+    ///
+    /// struct HttpClient;
+    /// impl Named for HttpClient {
+    ///     fn name(&self) -> &str { "http" }
+    /// }
+    /// impl ApiClient for HttpClient {
+    ///     fn call(&self) -> String { "ok".into() }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_trait::<dyn ApiClient>(Arc::new(HttpClient));
+    /// services.register_upcast::<dyn ApiClient, dyn Named>(|client| client);
+    ///
+    /// let provider = services.build();
+    /// let named = provider.get_required_trait::<dyn Named>();
+    /// assert_eq!(named.name(), "http");
+    /// ```
+    pub fn register_upcast<Sub, Super>(&mut self, cast: fn(Arc<Sub>) -> Arc<Super>) -> &mut Self
+    where
+        Sub: ?Sized + 'static + Send + Sync,
+        Super: ?Sized + 'static + Send + Sync,
+    {
+        let key = Key::Trait(std::any::type_name::<Super>());
+        let ctor = move |r: &ResolverContext| -> DiResult<AnyArc> {
+            let sub = r.get_trait::<Sub>()?;
+            // Store as Arc<Arc<dyn Super>> like every other trait registration
+            Ok(Arc::new(cast(sub)))
+        };
+        // Transient: the upcast itself holds no state, caching is left to `Sub`
+        self.registry.insert(key, Registration::with_metadata(
+            Lifetime::Transient,
+            Arc::new(ctor),
+            None,
+            None,
+        ));
+        self
+    }
+
+    /// Makes trait `Old` resolve to the existing concrete registration `New`.
+    ///
+    /// Useful while migrating callers from a trait-based lookup to a concrete
+    /// service (or when renaming). `Old` is a redirect to the `New`
+    /// registration: it has `New`'s lifetime and resolves to `New`'s cached
+    /// instance, so singletons and scoped instances are shared rather than
+    /// duplicated. `cast` performs the unsizing coercion, normally just
+    /// `|new| new`.
+    ///
+    /// `Old` must be a trait object. Concrete-to-concrete aliases are
+    /// rejected at compile time: a type is identified by its `TypeId`, so a
+    /// renamed type should use a `type Old = New;` alias.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{ServiceCollection, Resolver};
+    /// # use std::sync::Arc;
+    /// trait Clock: Send + Sync {
+    ///     fn now(&self) -> u64;
+    /// }
+    /// struct SystemClock;
+    /// impl Clock for SystemClock {
+    ///     fn now(&self) -> u64 { 42 }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(SystemClock);
+    /// services.add_alias::<dyn Clock, SystemClock>(|clock| clock);
+    ///
+    /// let provider = services.build();
+    /// assert_eq!(provider.get_required_trait::<dyn Clock>().now(), 42);
+    /// ```
+    ///
+    /// A sized `Old` does not build:
+    ///
+    /// ```compile_fail
+    /// # use ferrous_di::ServiceCollection;
+    /// # use std::sync::Arc;
+    /// struct OldClock;
+    /// struct SystemClock;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_alias::<OldClock, SystemClock>(|_| Arc::new(OldClock));
+    /// ```
+    pub fn add_alias<Old, New>(&mut self, cast: fn(Arc<New>) -> Arc<Old>) -> &mut Self
+    where
+        Old: ?Sized + 'static + Send + Sync,
+        New: 'static + Send + Sync,
+    {
+        let () = AssertTraitObject::<Old>::OK;
+        let target = Key::Type(TypeId::of::<New>(), std::any::type_name::<New>());
+        let cast = move |value: AnyArc| -> DiResult<AnyArc> {
+            let new = value.downcast::<New>().map_err(|_| DiError::TypeMismatch(std::any::type_name::<New>()))?;
+            // Store as Arc<Arc<dyn Old>> like every other trait registration
+            Ok(Arc::new(cast(new)))
+        };
+        let mut registration = Registration::alias(target, Arc::new(cast));
+        registration.impl_id = Some(TypeId::of::<New>());
+        self.registry.insert(Key::Trait(std::any::type_name::<Old>()), registration);
+        self
+    }
+
+    /// Makes trait `Old` resolve to the existing trait registration `New`.
+    ///
+    /// Supports gradual trait renames: declare `trait New: Old` (or keep
+    /// the old trait as a supertrait) and callers asking for `dyn Old` get
+    /// the same instance as `dyn New`. Like [`add_alias`](Self::add_alias),
+    /// `Old` redirects to the `New` registration and shares its lifetime and
+    /// cache. Both `Old` and `New` must be trait objects, which is checked at
+    /// compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{ServiceCollection, Resolver};
+    /// # use std::sync::Arc;
+    /// trait Storage: Send + Sync {
+    ///     fn get(&self) -> String;
+    /// }
+    /// trait BlobStore: Storage {}
+    ///
+    /// struct S3;
+    /// impl Storage for S3 {
+    ///     fn get(&self) -> String { "blob".into() }
+    /// }
+    /// impl BlobStore for S3 {}
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_trait::<dyn BlobStore>(Arc::new(S3));
+    /// services.add_trait_alias::<dyn Storage, dyn BlobStore>(|store| store);
+    ///
+    /// let provider = services.build();
+    /// assert_eq!(provider.get_required_trait::<dyn Storage>().get(), "blob");
+    /// ```
+    pub fn add_trait_alias<Old, New>(&mut self, cast: fn(Arc<New>) -> Arc<Old>) -> &mut Self
+    where
+        Old: ?Sized + 'static + Send + Sync,
+        New: ?Sized + 'static + Send + Sync,
+    {
+        let () = AssertTraitObject::<Old>::OK;
+        let () = AssertTraitObject::<New>::OK;
+        let target = Key::Trait(std::any::type_name::<New>());
+        let cast = move |value: AnyArc| -> DiResult<AnyArc> {
+            let new = value.downcast::<Arc<New>>().map_err(|_| DiError::TypeMismatch(std::any::type_name::<New>()))?;
+            Ok(Arc::new(cast((*new).clone())))
+        };
+        self.registry.insert(Key::Trait(std::any::type_name::<Old>()), Registration::alias(target, Arc::new(cast)));
+        self
+    }
+
+    /// Registers a trait whose implementation is chosen by a scope label.
+    ///
+    /// Resolving `T` from a scope reads the scope's `label_key` label and
+    /// passes its value to `factory`. Instances are cached per label value
+    /// within a scope tree: a top-level labeled scope and every scope forked
+    /// from it share one instance per tenant, while separate top-level scopes
+    /// each create their own. Resolving from a scope without the label fails
+    /// with [`DiError::MissingScopeLabel`]; resolving from the root provider
+    /// fails like any scoped service.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ferrous_di::{ServiceCollection, Resolver, LabeledScopeExt};
+    /// # use std::sync::Arc;
+    /// trait Database: Send + Sync {
+    ///     fn name(&self) -> String;
+    /// }
+    /// struct TenantDb(String);
+    /// impl Database for TenantDb {
+    ///     fn name(&self) -> String { self.0.clone() }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_label_routed_trait::<dyn Database, _>("tenant", |tenant| {
+    ///     Arc::new(TenantDb(format!("db_{tenant}")))
+    /// });
+    ///
+    /// let provider = services.build();
+    /// let scope = provider.create_labeled_scope_with("request", &[("tenant", "acme")]);
+    /// let db = scope.as_scope().get_required_trait::<dyn Database>();
+    /// assert_eq!(db.name(), "db_acme");
+    /// ```
+    pub fn add_label_routed_trait<T, F>(&mut self, label_key: &'static str, factory: F) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+        F: Fn(&str) -> Arc<T> + Send + Sync + 'static,
+    {
+        let name = std::any::type_name::<T>();
+        let ctor = move |r: &ResolverContext| -> DiResult<AnyArc> {
+            let labels = r.scope_labels().ok_or(DiError::MissingScopeLabel(label_key))?;
+            let value = labels.value(label_key).ok_or(DiError::MissingScopeLabel(label_key))?;
+            // Expert fix: Store as Arc<Arc<dyn Trait>> in Any
+            Ok(labels.routed_or_insert_with(name, value, |value| Arc::new(factory(value))))
+        };
+        self.registry.insert(Key::Trait(name), Registration::with_metadata(
+            Lifetime::Scoped,
+            Arc::new(ctor),
+            None,
+            None,
+        ));
+        self
+    }
+
     // ----- Trait Multi-Binding Registrations -----
     
     /// Add trait implementation to multi-binding list
@@ -371,15 +836,137 @@ impl ServiceCollection {
         let ctor = move |_: &ResolverContext| -> DiResult<AnyArc> {
             Ok(any_arc.clone())
         };
-        self.registry.many.entry(name).or_default().push(Registration::with_metadata(
+        let mut reg = Registration::with_metadata(
             lifetime,
             Arc::new(ctor),
             None,
             None, // We don't know the concrete implementation type for trait objects
-        ));
+        ).instance();
+        reg.instance_addr = Some(Arc::as_ptr(&value).cast::<()>() as usize);
+        self.registry.push_many(name, reg);
         self
     }
     
+    /// Add trait implementation to multi-binding list with string tags.
+    ///
+    /// Tags are stored as the registration's metadata (a `HashMap<String, String>`)
+    /// and can be used to group implementations with
+    /// [`ServiceProvider::get_all_trait_grouped`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Lifetime};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {}
+    /// struct FileReader;
+    /// impl Tool for FileReader {}
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation_tagged::<dyn Tool>(
+    ///     Arc::new(FileReader),
+    ///     Lifetime::Singleton,
+    ///     &[("category", "read")],
+    /// );
+    /// ```
+    pub fn add_trait_implementation_tagged<T>(
+        &mut self,
+        value: Arc<T>,
+        lifetime: Lifetime,
+        tags: &[(&str, &str)],
+    ) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+    {
+        self.add_trait_implementation(value, lifetime);
+        let tags: HashMap<String, String> = tags.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        if let Some(reg) = self.registry.many.get_mut(std::any::type_name::<T>()).and_then(|regs| regs.last_mut()) {
+            reg.metadata = Some(Box::new(tags));
+        }
+        self
+    }
+
+    /// Adds each implementation to the multi-binding list of `T`, in order.
+    ///
+    /// Equivalent to calling [`add_trait_implementation`](Self::add_trait_implementation)
+    /// once per item, which is handy when loading a plugin list. Returns the
+    /// number of implementations registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Lifetime, Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// trait Plugin: Send + Sync {}
+    /// struct Audit;
+    /// impl Plugin for Audit {}
+    /// struct Metrics;
+    /// impl Plugin for Metrics {}
+    ///
+    /// let plugins: Vec<Arc<dyn Plugin>> = vec![Arc::new(Audit), Arc::new(Metrics)];
+    /// let mut services = ServiceCollection::new();
+    /// let count = services.add_trait_implementations::<dyn Plugin>(plugins, Lifetime::Singleton);
+    /// assert_eq!(count, 2);
+    ///
+    /// let provider = services.build();
+    /// assert_eq!(provider.get_all_trait::<dyn Plugin>().unwrap().len(), 2);
+    /// ```
+    pub fn add_trait_implementations<T>(
+        &mut self,
+        impls: impl IntoIterator<Item = Arc<T>>,
+        lifetime: Lifetime,
+    ) -> usize
+    where
+        T: ?Sized + 'static + Send + Sync,
+    {
+        let mut count = 0;
+        for value in impls {
+            self.add_trait_implementation(value, lifetime);
+            count += 1;
+        }
+        count
+    }
+    
+    /// Collapses multi-bindings of the same `Arc` when the provider is built.
+    ///
+    /// Registering one instance twice with
+    /// [`add_trait_implementation`](Self::add_trait_implementation), for
+    /// example through two modules that both import a shared default, makes
+    /// [`get_all_trait`](crate::Resolver::get_all_trait) return it twice. With
+    /// this enabled, `build()` keeps only the first binding of each instance.
+    /// Factory-backed bindings are never collapsed, and bindings after a
+    /// removed duplicate move up one position. Labels follow the bindings they
+    /// were attached to; a removed duplicate's labels pass to the binding it
+    /// repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Lifetime, Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {}
+    /// struct Search;
+    /// impl Tool for Search {}
+    ///
+    /// let search: Arc<dyn Tool> = Arc::new(Search);
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation(search.clone(), Lifetime::Singleton);
+    /// services.add_trait_implementation(search, Lifetime::Singleton);
+    /// services.dedup_multi_bindings();
+    ///
+    /// let provider = services.build();
+    /// assert_eq!(provider.get_all_trait::<dyn Tool>().unwrap().len(), 1);
+    /// ```
+    pub fn dedup_multi_bindings(&mut self) -> &mut Self {
+        self.dedup_multi = true;
+        self
+    }
+
     /// Add trait factory to multi-binding list
     pub fn add_trait_factory<Trait, F>(&mut self, lifetime: Lifetime, factory: F) -> &mut Self
     where
@@ -392,7 +979,7 @@ impl ServiceCollection {
             // Expert fix: Store as Arc<Arc<dyn Trait>> in Any
             Ok(Arc::new(factory(r)))
         };
-        self.registry.many.entry(name).or_default().push(Registration::with_metadata(
+        self.registry.push_many(name, Registration::with_metadata(
             lifetime,
             Arc::new(ctor),
             None,
@@ -432,12 +1019,15 @@ impl ServiceCollection {
         
         // Single-binding services
         for (key, registration) in self.registry.iter() {
+            // Aliases take their target's lifetime only once built, so look it up
+            let lifetime = self.registry.alias_target(key).map_or(registration.lifetime, |(_, target)| target.lifetime);
             descriptors.push(ServiceDescriptor {
                 key: key.clone(),
-                lifetime: registration.lifetime,
+                lifetime,
                 impl_type_id: registration.impl_id,
                 impl_type_name: registration.impl_id.map(|_| key.display_name()), // Use the key's display name as impl name
                 has_metadata: registration.metadata.is_some(),
+                registration_kind: registration.kind,
             });
         }
         
@@ -450,12 +1040,57 @@ impl ServiceCollection {
                     impl_type_id: registration.impl_id,
                     impl_type_name: registration.impl_id.map(|_| *trait_name),
                     has_metadata: registration.metadata.is_some(),
+                    registration_kind: registration.kind,
                 });
             }
         }
         
         descriptors
     }
+
+    /// Compares this collection's registrations with `other`'s.
+    ///
+    /// `other` is treated as the newer wiring: keys only it registers are
+    /// `added`, keys only this collection registers are `removed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Lifetime, ServiceCollection};
+    ///
+    /// struct Cache;
+    ///
+    /// let mut before = ServiceCollection::new();
+    /// before.add_transient_factory::<Cache, _>(|_| Cache);
+    ///
+    /// let mut after = ServiceCollection::new();
+    /// after.add_singleton_factory::<Cache, _>(|_| Cache);
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.lifetime_changed[0].1, Lifetime::Transient);
+    /// assert_eq!(diff.lifetime_changed[0].2, Lifetime::Singleton);
+    /// ```
+    pub fn diff(&self, other: &ServiceCollection) -> crate::RegistryDiff {
+        let lifetimes = |services: &ServiceCollection| -> std::collections::BTreeMap<Key, Lifetime> {
+            services.get_service_descriptors()
+                .into_iter()
+                .map(|descriptor| (descriptor.key, descriptor.lifetime))
+                .collect()
+        };
+        let before = lifetimes(self);
+        let after = lifetimes(other);
+
+        let mut diff = crate::RegistryDiff::default();
+        for (key, &old) in &before {
+            match after.get(key) {
+                None => diff.removed.push(key.clone()),
+                Some(&new) if new != old => diff.lifetime_changed.push((key.clone(), old, new)),
+                Some(_) => {}
+            }
+        }
+        diff.added = after.into_keys().filter(|key| !before.contains_key(key)).collect();
+        diff
+    }
     
     /// Register a service with custom metadata.
     ///
@@ -499,7 +1134,7 @@ impl ServiceCollection {
             Arc::new(ctor),
             Some(Box::new(metadata)),
             Some(TypeId::of::<T>()),
-        ));
+        ).instance());
         self
     }
     
@@ -526,6 +1161,62 @@ impl ServiceCollection {
             .downcast_ref::<M>()
     }
     
+    /// Replaces the metadata of an existing single-binding registration.
+    pub(crate) fn set_metadata<M: Send + Sync + 'static>(&mut self, key: &Key, metadata: M) {
+        if let Some(reg) = self.registry.get_mut(key) {
+            reg.metadata = Some(Box::new(metadata));
+        }
+    }
+    
+    /// Runs `f` on the single binding and every multi-binding of a trait.
+    pub(crate) fn for_each_trait_registration(&mut self, trait_name: &'static str, mut f: impl FnMut(&mut Registration)) {
+        if let Some(reg) = self.registry.get_mut(&Key::Trait(trait_name)) {
+            f(reg);
+        }
+        for reg in self.registry.many.get_mut(trait_name).into_iter().flatten() {
+            f(reg);
+        }
+    }
+    
+    /// Attaches a typed label to the most recent registration.
+    ///
+    /// Labels can be any hashable value, typically an enum, and group
+    /// services for bulk operations such as prewarming everything critical.
+    /// Query them with [`ServiceProvider::services_with_label`]. A
+    /// registration can carry any number of labels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if nothing has been registered yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{key_of_type, ServiceCollection};
+    ///
+    /// #[derive(PartialEq, Eq, Hash)]
+    /// enum Tier { Critical, BestEffort }
+    ///
+    /// struct Database;
+    /// struct Telemetry;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Database).label(Tier::Critical);
+    /// services.add_singleton(Telemetry).label(Tier::BestEffort);
+    ///
+    /// let provider = services.build();
+    /// assert_eq!(provider.services_with_label(Tier::Critical), vec![key_of_type::<Database>()]);
+    /// ```
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Eq + std::hash::Hash + Send + Sync + 'static,
+    {
+        let key = self.registry.last_key.clone()
+            .expect("label() must follow a registration");
+        self.labels.insert(key, label);
+        self
+    }
+    
     // ----- Conditional Registration (TryAdd*) -----
     
     /// Register a singleton if not already registered.
@@ -660,6 +1351,55 @@ impl ServiceCollection {
         }
     }
     
+    /// Registers a null-object fallback for trait `T`, used only if nothing
+    /// else is bound to `T`, singly or as a multi-binding, when the
+    /// collection is built.
+    ///
+    /// Unlike [`try_add_singleton_trait`](Self::try_add_singleton_trait), the
+    /// check is deferred to [`build`](Self::build), so a real implementation
+    /// registered afterwards still wins. Optional dependencies can then be
+    /// resolved with `get_required_trait` instead of being threaded through as
+    /// `Option`. If several null objects are given for one trait, the first is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// trait Logger: Send + Sync {
+    ///     fn log(&self, msg: &str);
+    /// }
+    ///
+    /// struct NullLogger;
+    /// impl Logger for NullLogger {
+    ///     fn log(&self, _msg: &str) {}
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_null_object_trait::<dyn Logger>(Arc::new(NullLogger));
+    ///
+    /// let provider = services.build();
+    /// provider.get_required_trait::<dyn Logger>().log("discarded");
+    /// ```
+    pub fn add_null_object_trait<T>(&mut self, null_object: Arc<T>) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+    {
+        let key = Key::Trait(std::any::type_name::<T>());
+        let any_arc: AnyArc = Arc::new(null_object);
+        let ctor = move |_: &ResolverContext| -> DiResult<AnyArc> {
+            Ok(any_arc.clone())
+        };
+        self.null_objects.push((key, Registration::with_metadata(
+            Lifetime::Singleton,
+            Arc::new(ctor),
+            None,
+            None,
+        ).instance()));
+        self
+    }
+
     /// Add enumerable trait registration (always adds, doesn't check for existing).
     ///
     /// This method is equivalent to `add_trait_implementation` but with a name that matches
@@ -702,7 +1442,7 @@ impl ServiceCollection {
             Arc::new(ctor),
             None,
             Some(TypeId::of::<T>()),
-        ));
+        ).instance());
         self
     }
     
@@ -781,7 +1521,7 @@ impl ServiceCollection {
             Arc::new(ctor),
             None,
             None, // We don't know the concrete implementation type for trait objects
-        ));
+        ).instance());
         self
     }
     
@@ -845,6 +1585,52 @@ impl ServiceCollection {
         self
     }
     
+    /// Register a singleton trait implementation selected by a typed discriminant.
+    ///
+    /// Works like [`add_named_singleton_trait`](Self::add_named_singleton_trait)
+    /// but keys the registration by a value such as an enum variant, so a
+    /// misspelled selector is a compile error rather than a `NotFound`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Resolver};
+    /// use std::sync::Arc;
+    ///
+    /// trait Backend: Send + Sync { fn name(&self) -> &str; }
+    /// struct Gpu;
+    /// impl Backend for Gpu { fn name(&self) -> &str { "gpu" } }
+    ///
+    /// #[derive(Hash, PartialEq, Eq)]
+    /// enum BackendKind { Gpu, Cpu }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_discriminated_trait::<dyn Backend, _>(BackendKind::Gpu, Arc::new(Gpu));
+    ///
+    /// let provider = services.build();
+    /// let backend = provider.get_discriminated_trait::<dyn Backend, _>(BackendKind::Gpu).unwrap();
+    /// assert_eq!(backend.name(), "gpu");
+    /// assert!(provider.get_discriminated_trait::<dyn Backend, _>(BackendKind::Cpu).is_err());
+    /// ```
+    pub fn add_discriminated_trait<T, D>(&mut self, kind: D, value: Arc<T>) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+        D: std::hash::Hash + Eq + Send + Sync + 'static,
+    {
+        let key = Key::TraitDiscriminated(std::any::type_name::<T>(), Discriminant::new(kind));
+        let any_arc: AnyArc = Arc::new(value);
+        let ctor = move |_: &ResolverContext| -> DiResult<AnyArc> {
+            Ok(any_arc.clone())
+        };
+        self.registry.insert(key, Registration::with_metadata(
+            Lifetime::Singleton,
+            Arc::new(ctor),
+            None,
+            None,
+        ).instance());
+        self
+    }
+    
     /// Add named multi-trait registration.
     pub fn add_named_trait_implementation<T>(&mut self, name: &'static str, value: Arc<T>, lifetime: Lifetime) -> &mut Self
     where
@@ -861,12 +1647,12 @@ impl ServiceCollection {
         let combined_key = format!("{}#{}", trait_name, name);
         let static_key: &'static str = Box::leak(combined_key.into_boxed_str());
         
-        self.registry.many.entry(static_key).or_default().push(Registration::with_metadata(
+        self.registry.push_many(static_key, Registration::with_metadata(
             lifetime,
             Arc::new(ctor),
             None,
             None,
-        ));
+        ).instance());
         self
     }
     
@@ -881,44 +1667,158 @@ impl ServiceCollection {
     ///
     /// # Performance
     ///
-    /// Observer calls are made synchronously during resolution. Keep observer
-    /// implementations lightweight to avoid impacting performance.
+    /// Observer calls are made synchronously during resolution. Keep observer
+    /// implementations lightweight to avoid impacting performance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, LoggingObserver, DiObserver};
+    /// use std::sync::Arc;
+    ///
+    /// // Using the built-in logging observer
+    /// let mut services = ServiceCollection::new();
+    /// services.add_observer(Arc::new(LoggingObserver::new()));
+    ///
+    /// // Using a custom observer
+    /// struct MetricsObserver {
+    ///     counter: std::sync::Arc<std::sync::atomic::AtomicU64>,
+    /// }
+    ///
+    /// impl DiObserver for MetricsObserver {
+    ///     fn resolving(&self, key: &ferrous_di::Key) {
+    ///         self.counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    ///     }
+    ///
+    ///     fn resolved(&self, _key: &ferrous_di::Key, _duration: std::time::Duration) {}
+    ///     fn factory_panic(&self, _key: &ferrous_di::Key, _message: &str) {}
+    /// }
+    ///
+    /// let counter = Arc::new(std::sync::atomic::AtomicU64::new(0));
+    /// services.add_observer(Arc::new(MetricsObserver { counter: counter.clone() }));
+    ///
+    /// let provider = services.build();
+    /// // All resolutions will be observed
+    /// ```
+    pub fn add_observer(&mut self, observer: Arc<dyn DiObserver>) -> &mut Self {
+        self.observers.add(observer);
+        self
+    }
+    
+    /// Registers an invariant check that runs at the end of [`build`](Self::build).
+    ///
+    /// Assertions run in registration order against the finished provider,
+    /// after eager singletons are built, so they can resolve services and
+    /// compare their configuration. The first one to return `Err` fails the
+    /// build with its message.
+    ///
+    /// # Panics
+    ///
+    /// `build()` panics with `Build assertion failed: <message>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct PoolConfig { size: usize }
+    /// struct WorkerConfig { workers: usize }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(PoolConfig { size: 8 });
+    /// services.add_singleton(WorkerConfig { workers: 4 });
+    /// services.assert_on_build(|provider| {
+    ///     let pool = provider.get::<PoolConfig>().map_err(|e| e.to_string())?;
+    ///     let workers = provider.get::<WorkerConfig>().map_err(|e| e.to_string())?;
+    ///     if pool.size < workers.workers {
+    ///         return Err(format!("pool size {} is below worker count {}", pool.size, workers.workers));
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// let provider = services.build();
+    /// ```
+    pub fn assert_on_build<F>(&mut self, assertion: F) -> &mut Self
+    where
+        F: Fn(&ServiceProvider) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.assertions.push(Box::new(assertion));
+        self
+    }
+
+    /// Adds a policy that redirects resolutions of one key to another.
+    ///
+    /// Rewriters run in registration order before every single-service
+    /// resolution; see [`KeyRewriter`](crate::KeyRewriter). A chain of
+    /// rewrites that returns to a key it already visited fails with
+    /// [`DiError::KeyRewriteLoop`]. Multi-binding lookups are not rewritten.
+    pub fn add_key_rewriter(&mut self, rewriter: Arc<dyn crate::KeyRewriter>) -> &mut Self {
+        self.key_rewriters.push(rewriter);
+        self
+    }
+
+    /// Sets how scoped services resolved from the root provider are handled.
+    ///
+    /// The default, [`ScopedFromRootPolicy::Error`](crate::ScopedFromRootPolicy::Error),
+    /// fails with [`DiError::WrongLifetime`]. With
+    /// [`ScopedFromRootPolicy::EphemeralScope`](crate::ScopedFromRootPolicy::EphemeralScope)
+    /// each such resolution builds a fresh instance in its own throwaway
+    /// scope, so the service behaves like a transient at the root.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ferrous_di::{ServiceCollection, LoggingObserver, DiObserver};
-    /// use std::sync::Arc;
+    /// use ferrous_di::{Resolver, ScopedFromRootPolicy, ServiceCollection};
+    ///
+    /// struct RequestContext;
     ///
-    /// // Using the built-in logging observer
     /// let mut services = ServiceCollection::new();
-    /// services.add_observer(Arc::new(LoggingObserver::new()));
+    /// services.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    /// services.scoped_from_root_policy(ScopedFromRootPolicy::EphemeralScope);
     ///
-    /// // Using a custom observer
-    /// struct MetricsObserver {
-    ///     counter: std::sync::Arc<std::sync::atomic::AtomicU64>,
-    /// }
+    /// let provider = services.build();
+    /// assert!(provider.get::<RequestContext>().is_ok());
+    /// ```
+    pub fn scoped_from_root_policy(&mut self, policy: crate::ScopedFromRootPolicy) -> &mut Self {
+        self.scoped_from_root = policy;
+        self
+    }
+
+    /// Limits how many async factories a synchronous resolution may nest.
     ///
-    /// impl DiObserver for MetricsObserver {
-    ///     fn resolving(&self, key: &ferrous_di::Key) {
-    ///         self.counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-    ///     }
+    /// Resolving an async service through the sync API drives its factory on
+    /// a runtime of its own, and a factory that synchronously resolves another
+    /// async service nests a further runtime on the same thread. Once `depth`
+    /// factories are nested, `policy` decides whether the next one fails with
+    /// [`DiError::MaxAsyncDepthExceeded`] or runs on a fresh thread; see
+    /// [`AsyncDepthPolicy`](crate::AsyncDepthPolicy) for the tradeoff. Without
+    /// a limit the nesting is unbounded.
     ///
-    ///     fn resolved(&self, _key: &ferrous_di::Key, _duration: std::time::Duration) {}
-    ///     fn factory_panic(&self, _key: &ferrous_di::Key, _message: &str) {}
-    /// }
+    /// # Examples
     ///
-    /// let counter = Arc::new(std::sync::atomic::AtomicU64::new(0));
-    /// services.add_observer(Arc::new(MetricsObserver { counter: counter.clone() }));
+    /// ```
+    /// use ferrous_di::{AsyncDepthPolicy, ServiceCollection};
     ///
-    /// let provider = services.build();
-    /// // All resolutions will be observed
+    /// let mut services = ServiceCollection::new();
+    /// services.max_async_depth(8, AsyncDepthPolicy::FreshThread { stack_size: 8 * 1024 * 1024 });
     /// ```
-    pub fn add_observer(&mut self, observer: Arc<dyn DiObserver>) -> &mut Self {
-        self.observers.add(observer);
+    #[cfg(feature = "async")]
+    pub fn max_async_depth(&mut self, depth: usize, policy: crate::AsyncDepthPolicy) -> &mut Self {
+        self.async_depth_limit = Some((depth, policy));
         self
     }
-    
+
+    /// Adds an observer notified as each disposal hook runs.
+    ///
+    /// Hooks registered with [`register_disposer`](Resolver::register_disposer)
+    /// and its async and context variants report their service key to every
+    /// observer after running, in disposal order, for the root provider and
+    /// all scopes. See [`DisposeObserver`](crate::DisposeObserver).
+    pub fn on_dispose(&mut self, observer: Arc<dyn crate::DisposeObserver>) -> &mut Self {
+        self.dispose_observers.push(observer);
+        self
+    }
+
     // ----- Decoration / Interceptors -----
     
     /// Decorates all registrations of a trait with a wrapper function.
@@ -998,6 +1898,8 @@ impl ServiceCollection {
             let old_ctor = registration.ctor.clone();
             let decorator_clone = decorator.clone();
             
+            registration.kind = RegistrationKind::Decorated;
+            
             registration.ctor = Arc::new(move |resolver| {
                 // Call original constructor
                 let original = old_ctor(resolver)?;
@@ -1018,6 +1920,8 @@ impl ServiceCollection {
                 let old_ctor = registration.ctor.clone();
                 let decorator_clone = decorator.clone();
                 
+                registration.kind = RegistrationKind::Decorated;
+                
                 registration.ctor = Arc::new(move |resolver| {
                     // Call original constructor
                     let original = old_ctor(resolver)?;
@@ -1036,6 +1940,52 @@ impl ServiceCollection {
         self
     }
 
+    /// Registers a singleton trait implementation wrapped by `decorator`.
+    ///
+    /// Unlike [`decorate_trait`](Self::decorate_trait), the decorator applies
+    /// only to this registration: implementations registered for the same
+    /// trait before or after are left alone. The decorator runs once, on first
+    /// resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Resolver};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {
+    ///     fn execute(&self, input: &str) -> String;
+    /// }
+    ///
+    /// struct FileTool;
+    /// impl Tool for FileTool {
+    ///     fn execute(&self, input: &str) -> String { format!("read {}", input) }
+    /// }
+    ///
+    /// struct Audited(Arc<dyn Tool>);
+    /// impl Tool for Audited {
+    ///     fn execute(&self, input: &str) -> String { format!("[audited] {}", self.0.execute(input)) }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_trait_decorated::<dyn Tool, _>(Arc::new(FileTool), |tool| Arc::new(Audited(tool)));
+    ///
+    /// let provider = services.build();
+    /// let tool = provider.get_required_trait::<dyn Tool>();
+    /// assert_eq!(tool.execute("a.txt"), "[audited] read a.txt");
+    /// ```
+    pub fn add_singleton_trait_decorated<T, F>(&mut self, value: Arc<T>, decorator: F) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+        F: Fn(Arc<T>) -> Arc<T> + Send + Sync + 'static,
+    {
+        self.add_singleton_trait_factory::<T, _>(move |_| decorator(value.clone()));
+        if let Some(registration) = self.registry.get_mut(&Key::Trait(std::any::type_name::<T>())) {
+            registration.kind = RegistrationKind::Decorated;
+        }
+        self
+    }
+
     /// Decorates a concrete service type with a first-class decorator.
     ///
     /// This is the modern, type-safe approach to service decoration that provides
@@ -1084,6 +2034,9 @@ impl ServiceCollection {
             let old_ctor = registration.ctor.clone();
             let wrapper = Arc::new(DecorationWrapper::new(decorator));
             
+            registration.kind = RegistrationKind::Decorated;
+            registration.undecorated_ctor.get_or_insert_with(|| old_ctor.clone());
+            
             registration.ctor = Arc::new(move |resolver| {
                 // Call original constructor
                 let original = old_ctor(resolver)?;
@@ -1168,6 +2121,9 @@ impl ServiceCollection {
             let old_ctor = registration.ctor.clone();
             let wrapper_clone = wrapper.clone();
             
+            registration.kind = RegistrationKind::Decorated;
+            registration.undecorated_ctor.get_or_insert_with(|| old_ctor.clone());
+            
             registration.ctor = Arc::new(move |resolver| {
                 // Call original constructor
                 let original = old_ctor(resolver)?;
@@ -1188,6 +2144,9 @@ impl ServiceCollection {
                 let old_ctor = registration.ctor.clone();
                 let wrapper_clone = wrapper.clone();
                 
+                registration.kind = RegistrationKind::Decorated;
+                registration.undecorated_ctor.get_or_insert_with(|| old_ctor.clone());
+                
                 registration.ctor = Arc::new(move |resolver| {
                     // Call original constructor
                     let original = old_ctor(resolver)?;
@@ -1294,6 +2253,72 @@ impl ServiceCollection {
         self.prewarm.add_trait::<T>();
         self
     }
+
+    /// Declares the order in which order-sensitive singletons must be initialized.
+    ///
+    /// Singletons are normally created lazily in whatever order they are first
+    /// resolved. Services whose factories have side effects (registering global
+    /// handlers, installing hooks) can be listed here and then created eagerly,
+    /// in exactly this order, by calling
+    /// [`ServiceProvider::initialize_in_order`] at startup. Calling this again
+    /// replaces the previous order. Declare the order after registering the
+    /// services so that errors about them can name the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::ServiceCollection;
+    /// use std::any::TypeId;
+    ///
+    /// struct Metrics;
+    /// struct Tracing;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_factory::<Metrics, _>(|_| Metrics);
+    /// services.add_singleton_factory::<Tracing, _>(|_| Tracing);
+    /// services.init_order(&[TypeId::of::<Tracing>(), TypeId::of::<Metrics>()]);
+    ///
+    /// let provider = services.build();
+    /// provider.initialize_in_order().unwrap();
+    /// ```
+    pub fn init_order(&mut self, order: &[TypeId]) -> &mut Self {
+        let registry = &self.registry;
+        self.prewarm.set_init_order(order, |type_id| {
+            registry.iter().find_map(|(key, _)| match key {
+                Key::Type(id, name) if *id == type_id => Some(*name),
+                _ => None,
+            })
+        });
+        self
+    }
+    
+    /// Marks service `T` as deprecated.
+    ///
+    /// The first time `T` is resolved from the built provider, every observer
+    /// receives [`DiObserver::deprecated_resolution`] with `message`. Later
+    /// resolutions are not reported again. Can be called before or after `T`
+    /// is registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, LoggingObserver, Resolver};
+    /// use std::sync::Arc;
+    ///
+    /// struct LegacyClient;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(LegacyClient);
+    /// services.deprecate::<LegacyClient>("use HttpClient instead");
+    /// services.add_observer(Arc::new(LoggingObserver::new()));
+    ///
+    /// let provider = services.build();
+    /// let _client = provider.get_required::<LegacyClient>(); // logs the deprecation once
+    /// ```
+    pub fn deprecate<T: 'static>(&mut self, message: &'static str) -> &mut Self {
+        self.deprecations.insert(crate::key_of_type::<T>(), message);
+        self
+    }
     
     /// Builds the final service provider from this collection.
     ///
@@ -1324,20 +2349,72 @@ impl ServiceCollection {
     /// assert_eq!(&*text, "Hello");
     /// ```
     pub fn build(mut self) -> ServiceProvider {
+        for (key, registration) in std::mem::take(&mut self.null_objects) {
+            let multi_bound = matches!(key, Key::Trait(name) if self.registry.many.get(name).is_some_and(|regs| !regs.is_empty()));
+            if !self.registry.contains_key(&key) && !multi_bound {
+                self.registry.insert(key, registration);
+            }
+        }
+        for (key, message) in &self.deprecations {
+            if let Some(reg) = self.registry.get_mut(key) {
+                reg.deprecated = Some(message);
+            }
+        }
+        if self.dedup_multi {
+            let remapped = self.registry.dedup_many_instances();
+            self.labels.remap_multi_bindings(&remapped);
+        }
         // Finalize registry by assigning scoped slot indices
         self.registry.finalize();
-        ServiceProvider::new_with_observers_and_capabilities(self.registry, self.observers, self.capabilities)
+        let provider = ServiceProvider::new_with_observers_and_capabilities(self.registry, self.observers, self.capabilities)
+            .with_prewarm(self.prewarm)
+            .with_expensive(self.expensive)
+            .with_dispose_observers(self.dispose_observers)
+            .with_service_labels(self.labels)
+            .with_key_rewriters(self.key_rewriters)
+            .with_scoped_from_root_policy(self.scoped_from_root)
+            .with_memory_probes(self.memory_probes);
+        #[cfg(feature = "async")]
+        let provider = provider.with_async_depth_limit(self.async_depth_limit);
+        #[cfg(feature = "async")]
+        for start in self.background_tasks {
+            let task = start();
+            provider.push_sync_disposer(Box::new(move || task.abort()));
+        }
+        for key in &self.eager {
+            if let Err(err) = provider.resolve_any(key) {
+                panic!("Eager singleton {} failed to build: {}", key.display_name(), err);
+            }
+        }
+        let provider = if self.lint_unused {
+            provider.with_unused_lint(&self.roots)
+        } else {
+            provider
+        };
+        let provider = if self.check_trait_casts {
+            provider.with_trait_cast_lint()
+        } else {
+            provider
+        };
+        for assertion in &self.assertions {
+            if let Err(message) = assertion(&provider) {
+                panic!("Build assertion failed: {}", message);
+            }
+        }
+        provider
     }
 
     /// Registers an async singleton service with a factory.
     ///
     /// Perfect for workflow engines where nodes/tools need async initialization
-    /// (network handshakes, auth, model warm-up).
+    /// (network handshakes, auth, model warm-up). The service is registered
+    /// under `T` itself, so it resolves with `get_required::<T>()` exactly like
+    /// a sync registration.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ferrous_di::{ServiceCollection, AsyncFactory};
+    /// use ferrous_di::{ServiceCollection, AsyncFactory, Resolver, ResolverCore};
     /// use async_trait::async_trait;
     /// use std::sync::Arc;
     ///
@@ -1349,69 +2426,101 @@ impl ServiceCollection {
     ///
     /// #[async_trait]
     /// impl AsyncFactory<DatabasePool> for AsyncDbPoolFactory {
-    ///     async fn create(&self, _resolver: &dyn ferrous_di::Resolver) -> Arc<DatabasePool> {
+    ///     async fn create(
+    ///         &self,
+    ///         _resolver: &dyn ResolverCore,
+    ///     ) -> Result<Arc<DatabasePool>, Box<dyn std::error::Error + Send + Sync>> {
     ///         // Simulate async database connection setup
-    ///         Arc::new(DatabasePool {
+    ///         Ok(Arc::new(DatabasePool {
     ///             connection_string: "postgres://localhost".to_string(),
-    ///         })
+    ///         }))
     ///     }
     /// }
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut services = ServiceCollection::new();
     /// services.add_singleton_async::<DatabasePool, _>(AsyncDbPoolFactory);
-    /// # Ok(())
-    /// # }
+    ///
+    /// let provider = services.build();
+    /// let pool = provider.get_required::<DatabasePool>();
+    /// assert_eq!(pool.connection_string, "postgres://localhost");
     /// ```
     #[cfg(feature = "async")]
     pub fn add_singleton_async<T, F>(&mut self, factory: F) -> &mut Self
     where
-        T: Send + Sync + Clone + 'static,
+        T: Send + Sync + 'static,
         F: crate::async_factories::AsyncFactory<T> + 'static,
     {
-        use crate::async_factories::AsyncFactoryWrapper;
-        
-        let wrapper = AsyncFactoryWrapper::new(factory);
-        
-        // Create a sync factory that executes the async factory in a blocking context
-        let sync_factory = move |resolver: &ResolverContext| -> Arc<T> {
-            // Try to get current tokio runtime handle
-            if let Ok(_handle) = tokio::runtime::Handle::try_current() {
-                // We're in an async context, but we can't block_on from within the runtime
-                // Instead, use block_in_place to run the async task
-                let future = wrapper.create(resolver);
-                let result = tokio::task::block_in_place(|| {
-                    // Create a new runtime for this blocking task
-                    let rt = tokio::runtime::Builder::new_current_thread()
-                        .enable_all()
-                        .build()
-                        .expect("Failed to create blocking runtime");
-                    rt.block_on(future)
-                });
-                
-                match result {
-                    Ok(result) => result,
-                    Err(e) => {
-                        panic!("Async factory failed: {}", e);
-                    }
-                }
-            } else {
-                // No async runtime, create a new one for this operation
-                let rt = tokio::runtime::Builder::new_multi_thread()
-                    .enable_all()
-                    .build()
-                    .expect("Failed to create async runtime");
-                match rt.block_on(wrapper.create(resolver)) {
-                    Ok(result) => result,
-                    Err(e) => {
-                        panic!("Async factory failed: {}", e);
-                    }
-                }
-            }
+        self.add_async_impl(Lifetime::Singleton, factory)
+    }
+
+    /// Registers an async singleton whose factory is retried on failure.
+    ///
+    /// Meant for services whose initialization can fail transiently, such as
+    /// a network handshake. Each resolution runs the factory up to
+    /// `policy.max_attempts` times, sleeping between attempts, and only a
+    /// successful result is cached. If every attempt fails the resolution
+    /// returns [`DiError::FactoryFailed`] and nothing is cached, so a later
+    /// resolution starts a fresh round of attempts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, RetryPolicy, ServiceCollection};
+    /// use ferrous_di::traits::ResolverCore;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// struct Client;
+    ///
+    /// let attempts = Arc::new(AtomicU32::new(0));
+    /// let counter = attempts.clone();
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_async_retry::<Client, _>(
+    ///     move |_: &dyn ResolverCore| {
+    ///         let attempt = counter.fetch_add(1, Ordering::SeqCst) + 1;
+    ///         async move {
+    ///             if attempt < 2 {
+    ///                 return Err("handshake timed out".into());
+    ///             }
+    ///             Ok(Arc::new(Client))
+    ///         }
+    ///     },
+    ///     RetryPolicy { initial_delay: Duration::from_millis(1), ..RetryPolicy::default() },
+    /// );
+    ///
+    /// let provider = services.build();
+    /// assert!(provider.get::<Client>().is_ok());
+    /// assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn add_singleton_async_retry<T, F>(&mut self, factory: F, policy: crate::RetryPolicy) -> &mut Self
+    where
+        T: Send + Sync + 'static,
+        F: crate::async_factories::AsyncFactory<T> + 'static,
+    {
+        use crate::async_factories::{block_on_catching, create_with_retry, AsyncFactoryWrapper};
+        use crate::registration::AsyncCtor;
+
+        let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+        let factory = Arc::new(AsyncFactoryWrapper::new(factory));
+        let policy = Arc::new(policy);
+
+        let (sync_factory, sync_policy, sync_key) = (factory.clone(), policy.clone(), key.clone());
+        let ctor = move |r: &ResolverContext| -> DiResult<AnyArc> {
+            let future = create_with_retry(&sync_factory, &sync_policy, &sync_key, r);
+            block_on_catching(r, &sync_key, Lifetime::Singleton, future)
         };
-        
-        // Register as singleton with the sync factory that returns Arc<T>
-        self.add_singleton_factory::<Arc<T>, _>(sync_factory);
+        let async_key = key.clone();
+        let async_ctor: AsyncCtor = Arc::new(move |r| {
+            let (factory, policy, key) = (factory.clone(), policy.clone(), async_key.clone());
+            Box::pin(async move { create_with_retry(&factory, &policy, &key, r).await })
+        });
+
+        let mut reg = Registration::with_metadata(Lifetime::Singleton, Arc::new(ctor), None, Some(TypeId::of::<T>()));
+        reg.async_ctor = Some(async_ctor);
+        reg.kind = RegistrationKind::AsyncFactory;
+        self.registry.insert(key, reg);
         self
     }
 
@@ -1438,52 +2547,218 @@ impl ServiceCollection {
     #[cfg(feature = "async")]
     pub fn add_scoped_async<T, F>(&mut self, factory: F) -> &mut Self
     where
-        T: Send + Sync + Clone + 'static,
+        T: Send + Sync + 'static,
         F: crate::async_factories::AsyncFactory<T> + 'static,
     {
-        use crate::async_factories::AsyncFactoryWrapper;
-        
-        let wrapper = AsyncFactoryWrapper::new(factory);
-        
-        // Create a sync factory that executes the async factory in a blocking context
-        let sync_factory = move |resolver: &ResolverContext| -> Arc<T> {
-            // Try to get current tokio runtime handle
-            if let Ok(_handle) = tokio::runtime::Handle::try_current() {
-                // We're in an async context, but we can't block_on from within the runtime
-                // Instead, use spawn_blocking to run the async task
-                let future = wrapper.create(resolver);
-                let result = tokio::task::block_in_place(|| {
-                    // Create a new runtime for this blocking task
-                    let rt = tokio::runtime::Builder::new_current_thread()
-                        .enable_all()
-                        .build()
-                        .expect("Failed to create blocking runtime");
-                    rt.block_on(future)
-                });
-                
-                match result {
-                    Ok(result) => result,
-                    Err(e) => {
-                        panic!("Async factory failed: {}", e);
-                    }
-                }
-            } else {
-                // No async runtime, create a new one for this operation
-                let rt = tokio::runtime::Builder::new_multi_thread()
-                    .enable_all()
-                    .build()
-                    .expect("Failed to create async runtime");
-                match rt.block_on(wrapper.create(resolver)) {
-                    Ok(result) => result,
-                    Err(e) => {
-                        panic!("Async factory failed: {}", e);
+        self.add_async_impl(Lifetime::Scoped, factory)
+    }
+
+    /// Registers a singleton trait implementation created by an async factory.
+    ///
+    /// The trait-object counterpart of [`add_singleton_async`](Self::add_singleton_async),
+    /// for implementations that need async initialization (connection
+    /// handshakes, auth, model warm-up). The factory is awaited natively by
+    /// [`ServiceProvider::get_trait_async`](crate::ServiceProvider::get_trait_async)
+    /// and blocked on when resolved through the synchronous API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, async_factory};
+    /// use std::sync::Arc;
+    ///
+    /// trait Connection: Send + Sync {
+    ///     fn endpoint(&self) -> &str;
+    /// }
+    /// struct TcpConnection;
+    /// impl Connection for TcpConnection {
+    ///     fn endpoint(&self) -> &str { "db:5432" }
+    /// }
+    ///
+    /// # async fn example() {
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_trait_async::<dyn Connection, _>(async_factory!(|_resolver| async {
+    ///     // Simulate the connection handshake
+    ///     Arc::new(TcpConnection) as Arc<dyn Connection>
+    /// }));
+    ///
+    /// let provider = services.build();
+    /// let conn = provider.get_required_trait_async::<dyn Connection>().await;
+    /// assert_eq!(conn.endpoint(), "db:5432");
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn add_singleton_trait_async<Trait, F>(&mut self, factory: F) -> &mut Self
+    where
+        Trait: ?Sized + 'static + Send + Sync,
+        F: crate::async_factories::AsyncFactory<Trait> + 'static,
+    {
+        self.add_trait_async_impl(Lifetime::Singleton, factory)
+    }
+
+    /// Registers a scoped trait implementation created by an async factory.
+    ///
+    /// See [`add_singleton_trait_async`](Self::add_singleton_trait_async).
+    /// Resolve it from a scope with [`Scope::get_trait_async`](crate::Scope::get_trait_async).
+    #[cfg(feature = "async")]
+    pub fn add_scoped_trait_async<Trait, F>(&mut self, factory: F) -> &mut Self
+    where
+        Trait: ?Sized + 'static + Send + Sync,
+        F: crate::async_factories::AsyncFactory<Trait> + 'static,
+    {
+        self.add_trait_async_impl(Lifetime::Scoped, factory)
+    }
+
+    /// Registers a transient trait implementation created by an async factory.
+    ///
+    /// See [`add_singleton_trait_async`](Self::add_singleton_trait_async).
+    #[cfg(feature = "async")]
+    pub fn add_transient_trait_async<Trait, F>(&mut self, factory: F) -> &mut Self
+    where
+        Trait: ?Sized + 'static + Send + Sync,
+        F: crate::async_factories::AsyncFactory<Trait> + 'static,
+    {
+        self.add_trait_async_impl(Lifetime::Transient, factory)
+    }
+
+    /// Registers a service whose value is replaced by each item of `updates`.
+    ///
+    /// A background task consumes the stream and swaps in every new value, so
+    /// `get_required::<T>()` always returns the latest one. Suits services
+    /// driven by live configuration, such as feature flags pushed from a
+    /// control plane. Instances already handed out keep the value they had.
+    ///
+    /// The registration is a singleton: every resolution shares the current
+    /// value, which the provider holds instead of caching the first one. The
+    /// task starts when the collection is built, on the current Tokio runtime
+    /// or, outside one, on a dedicated thread. It stops when the stream ends,
+    /// when the provider is disposed with
+    /// [`dispose_all`](crate::ServiceProvider::dispose_all), or when the
+    /// provider is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct FeatureFlags { new_checkout: bool }
+    ///
+    /// # async fn example() {
+    /// let (tx, rx) = futures::channel::mpsc::unbounded();
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_reactive(FeatureFlags { new_checkout: false }, rx);
+    /// let provider = services.build();
+    ///
+    /// tx.unbounded_send(FeatureFlags { new_checkout: true }).unwrap();
+    /// // Shortly after, provider.get_required::<FeatureFlags>().new_checkout is true
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn add_singleton_reactive<T, S>(&mut self, initial: T, updates: S) -> &mut Self
+    where
+        T: Send + Sync + 'static,
+        S: futures::Stream<Item = T> + Send + 'static,
+    {
+        use futures::StreamExt;
+
+        let current = Arc::new(arc_swap::ArcSwap::from_pointee(initial));
+        // Dropping the provider drops the constructor, which stops the task
+        let guard = Arc::new(std::sync::OnceLock::new());
+        {
+            let (current, guard) = (current.clone(), guard.clone());
+            self.background_tasks.push(Box::new(move || {
+                let task = crate::async_factories::spawn_background(async move {
+                    let mut updates = std::pin::pin!(updates);
+                    while let Some(value) = updates.next().await {
+                        current.store(Arc::new(value));
                     }
+                });
+                let _ = guard.set(AbortOnDrop(task.clone()));
+                task
+            }));
+        }
+
+        let ctor = move |_: &ResolverContext| -> DiResult<AnyArc> {
+            let _ = &guard;
+            Ok(current.load_full())
+        };
+        let mut reg = Registration::with_metadata(Lifetime::Singleton, Arc::new(ctor), None, Some(TypeId::of::<T>()));
+        // Without a cache cell each resolution reads the latest value
+        reg.single_runtime = None;
+        self.registry.insert(Key::Type(TypeId::of::<T>(), std::any::type_name::<T>()), reg);
+        self
+    }
+
+    /// Shared registration for the concrete-type async registrations.
+    ///
+    /// The factory's `Arc<T>` is stored as-is under `Key::Type(T)`, so the
+    /// service resolves with `get_required::<T>()` like its sync equivalent.
+    #[cfg(feature = "async")]
+    fn add_async_impl<T, F>(&mut self, lifetime: Lifetime, factory: F) -> &mut Self
+    where
+        T: 'static + Send + Sync,
+        F: crate::async_factories::AsyncFactory<T> + 'static,
+    {
+        let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+        self.add_async_registration(key, lifetime, Some(TypeId::of::<T>()), Arc::new(factory), |service| service)
+    }
+
+    #[cfg(feature = "async")]
+    fn add_trait_async_impl<Trait, F>(&mut self, lifetime: Lifetime, factory: F) -> &mut Self
+    where
+        Trait: ?Sized + 'static + Send + Sync,
+        F: crate::async_factories::AsyncFactory<Trait> + 'static,
+    {
+        // Trait objects are stored as Arc<Arc<dyn Trait>> in Any
+        let key = Key::Trait(std::any::type_name::<Trait>());
+        self.add_async_registration(key, lifetime, None, Arc::new(factory), |service| Arc::new(service))
+    }
+
+    /// Registers `factory` under `key` with both a sync ctor (driven by
+    /// `block_on_catching`) and a native async ctor. `erase` turns the
+    /// factory's `Arc<T>` into the stored `AnyArc`; a factory error becomes
+    /// [`DiError::FactoryFailed`] and a panic [`DiError::FactoryPanicked`]
+    /// on either path.
+    #[cfg(feature = "async")]
+    fn add_async_registration<T>(
+        &mut self,
+        key: Key,
+        lifetime: Lifetime,
+        impl_id: Option<TypeId>,
+        factory: Arc<dyn crate::async_factories::AsyncFactory<T>>,
+        erase: fn(Arc<T>) -> AnyArc,
+    ) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+    {
+        use crate::async_factories::block_on_catching;
+        use crate::registration::AsyncCtor;
+
+        let (sync_factory, sync_key) = (factory.clone(), key.clone());
+        let ctor = move |r: &ResolverContext| -> DiResult<AnyArc> {
+            let future = async {
+                match sync_factory.create(r).await {
+                    Ok(service) => Ok(erase(service)),
+                    Err(e) => Err(DiError::FactoryFailed { key: sync_key.clone(), source: e.into() }),
                 }
-            }
+            };
+            block_on_catching(r, &sync_key, lifetime, future)
         };
-        
-        // Register as scoped with the sync factory that returns Arc<T>
-        self.add_scoped_factory::<Arc<T>, _>(sync_factory);
+        let async_key = key.clone();
+        let async_ctor: AsyncCtor = Arc::new(move |r| {
+            let (factory, key) = (factory.clone(), async_key.clone());
+            Box::pin(async move {
+                match factory.create(r).await {
+                    Ok(service) => Ok(erase(service)),
+                    Err(e) => Err(DiError::FactoryFailed { key, source: e.into() }),
+                }
+            })
+        });
+
+        let mut reg = Registration::with_metadata(lifetime, Arc::new(ctor), None, impl_id);
+        reg.async_ctor = Some(async_ctor);
+        reg.kind = RegistrationKind::AsyncFactory;
+        self.registry.insert(key, reg);
         self
     }
 }
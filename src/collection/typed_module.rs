@@ -0,0 +1,206 @@
+//! Registration with compile-time proof of dependencies.
+//!
+//! A [`TypedModule`] hands back a zero-sized token for every service it
+//! registers. Services with dependencies are registered with a tuple of those
+//! tokens and receive exactly the instances they prove, so a factory that
+//! needs a service nobody registered does not compile.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::traits::Resolver;
+use crate::{Lifetime, ResolverContext, ServiceCollection};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Proof that the concrete service `T` was registered in a [`TypedModule`].
+///
+/// Only the module creates these. Tokens are `Copy`, so one registration can
+/// satisfy any number of dependents.
+pub struct Registered<T>(PhantomData<fn() -> T>);
+
+/// Proof that trait `T` was bound in a [`TypedModule`].
+pub struct RegisteredTrait<T: ?Sized>(PhantomData<fn() -> Box<T>>);
+
+impl<T> Clone for Registered<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Registered<T> {}
+
+impl<T: ?Sized> Clone for RegisteredTrait<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for RegisteredTrait<T> {}
+
+/// A registration token that can resolve the service it proves.
+///
+/// Sealed: implemented only by [`Registered`] and [`RegisteredTrait`].
+pub trait Proof: sealed::Sealed + Copy {
+    /// The resolved service handle.
+    type Output;
+
+    /// Resolves the proven service.
+    fn resolve(resolver: &ResolverContext) -> Self::Output;
+}
+
+impl<T> sealed::Sealed for Registered<T> {}
+impl<T: 'static + Send + Sync> Proof for Registered<T> {
+    type Output = Arc<T>;
+
+    fn resolve(resolver: &ResolverContext) -> Arc<T> {
+        resolver.get_required::<T>()
+    }
+}
+
+impl<T: ?Sized> sealed::Sealed for RegisteredTrait<T> {}
+impl<T: ?Sized + 'static + Send + Sync> Proof for RegisteredTrait<T> {
+    type Output = Arc<T>;
+
+    fn resolve(resolver: &ResolverContext) -> Arc<T> {
+        resolver.get_required_trait::<T>()
+    }
+}
+
+/// A tuple of registration tokens, resolved together into a tuple of
+/// instances. Implemented for tuples of up to eight [`Proof`]s.
+pub trait Dependencies: sealed::Sealed + Copy + Send + Sync + 'static {
+    /// Tuple of resolved services, in token order.
+    type Resolved;
+
+    /// Resolves every proven service.
+    fn resolve(resolver: &ResolverContext) -> Self::Resolved;
+}
+
+macro_rules! impl_dependencies {
+    ($($proof:ident),*) => {
+        impl<$($proof: Proof),*> sealed::Sealed for ($($proof,)*) {}
+
+        impl<$($proof: Proof + Send + Sync + 'static),*> Dependencies for ($($proof,)*) {
+            type Resolved = ($($proof::Output,)*);
+
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn resolve(resolver: &ResolverContext) -> Self::Resolved {
+                ($($proof::resolve(resolver),)*)
+            }
+        }
+    };
+}
+
+impl_dependencies!();
+impl_dependencies!(A);
+impl_dependencies!(A, B);
+impl_dependencies!(A, B, C);
+impl_dependencies!(A, B, C, D);
+impl_dependencies!(A, B, C, D, E);
+impl_dependencies!(A, B, C, D, E, F);
+impl_dependencies!(A, B, C, D, E, F, G);
+impl_dependencies!(A, B, C, D, E, F, G, H);
+
+/// Builder whose registrations return proof tokens.
+///
+/// Factories registered with [`add_with`](Self::add_with) never see a
+/// resolver, only the instances proven by the tokens they were given, so a
+/// forgotten registration is a compile error rather than a `NotFound` at run
+/// time. Finish with [`into_collection`](Self::into_collection) to add
+/// untyped registrations or build a provider.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{Lifetime, Resolver, TypedModule};
+/// use std::sync::Arc;
+///
+/// struct Config { url: String }
+/// struct Database { url: String }
+/// struct UserService { db: Arc<Database> }
+///
+/// let mut module = TypedModule::new();
+/// let config = module.add_instance(Config { url: "postgres://localhost".into() });
+/// let db = module.add_with(Lifetime::Singleton, (config,), |(config,)| Database {
+///     url: config.url.clone(),
+/// });
+/// module.add_with(Lifetime::Transient, (db,), |(db,)| UserService { db });
+///
+/// let provider = module.into_collection().build();
+/// let users = provider.get_required::<UserService>();
+/// assert_eq!(users.db.url, "postgres://localhost");
+/// ```
+///
+/// Omitting the `Database` registration leaves no token to pass, and the
+/// factory's argument no longer type-checks:
+///
+/// ```compile_fail
+/// use ferrous_di::{Lifetime, TypedModule};
+/// use std::sync::Arc;
+///
+/// struct Config;
+/// struct Database;
+/// struct UserService { db: Arc<Database> }
+///
+/// let mut module = TypedModule::new();
+/// let config = module.add_instance(Config);
+/// module.add_with(Lifetime::Transient, (config,), |(db,)| UserService { db });
+/// ```
+#[derive(Default)]
+pub struct TypedModule {
+    services: ServiceCollection,
+}
+
+impl TypedModule {
+    /// Creates an empty module.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a singleton instance.
+    pub fn add_instance<T: 'static + Send + Sync>(&mut self, value: T) -> Registered<T> {
+        self.services.add_singleton(value);
+        Registered(PhantomData)
+    }
+
+    /// Binds trait `T` to a singleton instance.
+    pub fn add_trait_instance<T: ?Sized + 'static + Send + Sync>(&mut self, value: Arc<T>) -> RegisteredTrait<T> {
+        self.services.add_singleton_trait::<T>(value);
+        RegisteredTrait(PhantomData)
+    }
+
+    /// Registers `T` with the given lifetime, built from the services proven
+    /// by `deps`.
+    pub fn add_with<T, D, F>(&mut self, lifetime: Lifetime, _deps: D, factory: F) -> Registered<T>
+    where
+        T: 'static + Send + Sync,
+        D: Dependencies,
+        F: Fn(D::Resolved) -> T + Send + Sync + 'static,
+    {
+        let build = move |r: &ResolverContext| factory(D::resolve(r));
+        match lifetime {
+            Lifetime::Singleton => self.services.add_singleton_factory::<T, _>(build),
+            Lifetime::Scoped => self.services.add_scoped_factory::<T, _>(build),
+            Lifetime::Transient => self.services.add_transient_factory::<T, _>(build),
+        };
+        Registered(PhantomData)
+    }
+
+    /// Binds trait `T` with the given lifetime, built from the services
+    /// proven by `deps`.
+    pub fn add_trait_with<T, D, F>(&mut self, lifetime: Lifetime, _deps: D, factory: F) -> RegisteredTrait<T>
+    where
+        T: ?Sized + 'static + Send + Sync,
+        D: Dependencies,
+        F: Fn(D::Resolved) -> Arc<T> + Send + Sync + 'static,
+    {
+        self.services.add_trait_factory::<T, _>(lifetime, move |r| factory(D::resolve(r)));
+        RegisteredTrait(PhantomData)
+    }
+
+    /// Returns the collection holding this module's registrations.
+    pub fn into_collection(self) -> ServiceCollection {
+        self.services
+    }
+}
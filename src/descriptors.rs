@@ -81,6 +81,45 @@ pub struct ServiceDescriptor {
     pub impl_type_name: Option<&'static str>,
     /// Whether this registration has metadata attached
     pub has_metadata: bool,
+    /// How the registration produces its instances
+    pub registration_kind: RegistrationKind,
+}
+
+/// How a registration produces its instances.
+///
+/// Instance-backed registrations are already built and cost nothing to
+/// resolve; the other kinds run code on resolution, which is where graph
+/// exports and lints should look for expensive construction.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{RegistrationKind, ServiceCollection};
+///
+/// struct Config;
+/// struct Client;
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton(Config);
+/// services.add_singleton_factory::<Client, _>(|_| Client);
+///
+/// let descriptors = services.get_service_descriptors();
+/// let kind_of = |name: &str| descriptors.iter()
+///     .find(|d| d.type_name().ends_with(name))
+///     .map(|d| d.registration_kind);
+/// assert_eq!(kind_of("Config"), Some(RegistrationKind::Instance));
+/// assert_eq!(kind_of("Client"), Some(RegistrationKind::Factory));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistrationKind {
+    /// A pre-built value handed out as-is
+    Instance,
+    /// A synchronous factory that runs on resolution
+    Factory,
+    /// A factory registered through one of the `*_async` methods
+    AsyncFactory,
+    /// A registration whose constructor has been wrapped by a decorator
+    Decorated,
 }
 
 impl ServiceDescriptor {
@@ -172,4 +211,26 @@ impl ServiceDescriptor {
     pub fn is_named(&self) -> bool {
         self.service_name().is_some()
     }
-}
\ No newline at end of file
+}
+
+/// Differences between the registrations of two service collections.
+///
+/// Produced by [`ServiceCollection::diff`](crate::ServiceCollection::diff).
+/// Keys are sorted. Multi-bindings are compared by position, as
+/// [`Key::MultiTrait`] entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryDiff {
+    /// Keys registered only in the other collection
+    pub added: Vec<Key>,
+    /// Keys registered only in this collection
+    pub removed: Vec<Key>,
+    /// Keys registered in both with different lifetimes, as `(key, before, after)`
+    pub lifetime_changed: Vec<(Key, Lifetime, Lifetime)>,
+}
+
+impl RegistryDiff {
+    /// Returns true if both collections register the same keys with the same lifetimes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.lifetime_changed.is_empty()
+    }
+}
@@ -49,6 +49,73 @@ pub enum DiError {
     WrongLifetime(&'static str),
     /// Maximum recursion depth exceeded
     DepthExceeded(usize),
+    /// Transient service resolves a dependency marked as expensive (lint finding)
+    SuspectTransient {
+        /// The transient service
+        service: &'static str,
+        /// The expensive dependency it creates or holds
+        expensive_dep: &'static str,
+    },
+    /// Service is gated behind a runtime feature flag that is currently off
+    FeatureDisabled(&'static str),
+    /// Service is never depended upon, prewarmed, or marked as a root (lint finding)
+    UnusedService(&'static str),
+    /// Label-routed service resolved from a scope without the routing label
+    MissingScopeLabel(&'static str),
+    /// Unnamed trait lookup matches several named or multi-bound registrations
+    AmbiguousRegistration {
+        /// The trait that was requested
+        trait_name: &'static str,
+        /// Descriptions of the registrations that could have been meant
+        candidates: Vec<String>,
+    },
+    /// Scope deadline passed before the service finished resolving
+    DeadlineExceeded(&'static str),
+    /// Service lifetime is outside what a restricted resolver allows
+    LifetimeNotPermitted {
+        /// The service that was requested
+        service: &'static str,
+        /// Its registered lifetime
+        lifetime: crate::Lifetime,
+    },
+    /// Singleton was not built before disposal started, so teardown code may not create it
+    NotBuiltDuringDisposal(&'static str),
+    /// Singleton was already disposed, so teardown code may not use it
+    AlreadyDisposed(&'static str),
+    /// Trait expected to have one implementation has several registrations
+    ExpectedSingleImplementation {
+        /// The trait that was requested
+        trait_name: &'static str,
+        /// Number of registrations found
+        found: usize,
+    },
+    /// Key rewriters redirect a key back to one already visited (includes path)
+    KeyRewriteLoop(Vec<crate::Key>),
+    /// Async factory panicked while building the service
+    FactoryPanicked {
+        /// The service being built
+        key: crate::Key,
+        /// The panic message
+        message: String,
+    },
+    /// Scope was disposed before the service was requested
+    ScopeDisposed(&'static str),
+    /// Factory returned an error on every attempt allowed by its retry policy
+    FactoryFailed {
+        /// The service being built
+        key: crate::Key,
+        /// The error from the last attempt, also returned by `source()`
+        source: std::sync::Arc<dyn std::error::Error + Send + Sync>,
+    },
+    /// Decorator on a singleton resolves a scoped service (lint finding)
+    DecoratorLifetimeConflict {
+        /// The decorated singleton
+        service: &'static str,
+        /// The scoped service the decorator resolves
+        scoped_dep: &'static str,
+    },
+    /// Sync resolution nested more async factories than the configured limit allows
+    MaxAsyncDepthExceeded(usize),
 }
 
 impl fmt::Display for DiError {
@@ -61,11 +128,94 @@ impl fmt::Display for DiError {
             }
             DiError::WrongLifetime(msg) => write!(f, "Lifetime error: {}", msg),
             DiError::DepthExceeded(depth) => write!(f, "Max depth {} exceeded", depth),
+            DiError::SuspectTransient { service, expensive_dep } => write!(
+                f,
+                "Transient {} depends on expensive service {}; consider registering it as a singleton",
+                service, expensive_dep
+            ),
+            DiError::FeatureDisabled(flag) => write!(f, "Feature disabled: {}", flag),
+            DiError::UnusedService(name) => write!(f, "Service {} is registered but never used", name),
+            DiError::MissingScopeLabel(key) => write!(f, "Scope has no '{}' label to route on", key),
+            DiError::AmbiguousRegistration { trait_name, candidates } => write!(
+                f,
+                "Ambiguous registration for {}: candidates are {}; resolve by name or with get_all_trait",
+                trait_name,
+                candidates.join(", ")
+            ),
+            DiError::DeadlineExceeded(name) => write!(f, "Scope deadline exceeded while resolving {}", name),
+            DiError::LifetimeNotPermitted { service, lifetime } => write!(
+                f,
+                "{:?} service {} is not permitted by this resolver",
+                lifetime, service
+            ),
+            DiError::NotBuiltDuringDisposal(name) => write!(
+                f,
+                "Singleton {} was never built and cannot be created during disposal",
+                name
+            ),
+            DiError::AlreadyDisposed(name) => write!(f, "Singleton {} was already disposed", name),
+            DiError::ExpectedSingleImplementation { trait_name, found } => write!(
+                f,
+                "Expected a single implementation of {}, found {}",
+                trait_name, found
+            ),
+            DiError::KeyRewriteLoop(path) => {
+                let path: Vec<String> = path.iter()
+                    .map(|key| match key.service_name() {
+                        Some(name) => format!("{} (named \"{}\")", key.display_name(), name),
+                        None => key.display_name().to_string(),
+                    })
+                    .collect();
+                write!(f, "Key rewrite loop: {}", path.join(" -> "))
+            }
+            DiError::FactoryPanicked { key, message } => write!(
+                f,
+                "Factory for {} panicked: {}",
+                key.display_name(),
+                message
+            ),
+            DiError::ScopeDisposed(name) => write!(f, "Cannot resolve {} from a disposed scope", name),
+            DiError::FactoryFailed { key, source } => write!(
+                f,
+                "Factory for {} failed: {}",
+                key.display_name(),
+                source
+            ),
+            DiError::DecoratorLifetimeConflict { service, scoped_dep } => write!(
+                f,
+                "Decorator on singleton {} resolves scoped service {}; it will fail outside a scope",
+                service, scoped_dep
+            ),
+            DiError::MaxAsyncDepthExceeded(depth) => write!(f, "Max async resolution depth {} exceeded", depth),
         }
     }
 }
 
-impl std::error::Error for DiError {}
+/// Only [`DiError::FactoryFailed`] wraps another error; every other variant
+/// is the root of its error chain.
+impl std::error::Error for DiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DiError::FactoryFailed { source, .. } => Some(&**source),
+            _ => None,
+        }
+    }
+}
+
+/// Lets `?` propagate resolution failures from functions returning `io::Result`.
+///
+/// [`DiError::NotFound`] maps to [`std::io::ErrorKind::NotFound`]; every other
+/// variant maps to [`std::io::ErrorKind::Other`]. The original `DiError` is kept
+/// as the inner error and can be recovered with `get_ref()` and `downcast_ref`.
+impl From<DiError> for std::io::Error {
+    fn from(error: DiError) -> Self {
+        let kind = match error {
+            DiError::NotFound(_) => std::io::ErrorKind::NotFound,
+            _ => std::io::ErrorKind::Other,
+        };
+        std::io::Error::new(kind, error)
+    }
+}
 
 /// Result type for DI operations
 ///
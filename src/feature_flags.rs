@@ -0,0 +1,45 @@
+//! Runtime feature flags for gating service resolution.
+//!
+//! Services registered with
+//! [`ServiceCollection::add_singleton_gated`](crate::ServiceCollection::add_singleton_gated)
+//! are only resolvable while their flag is on. The flag is checked on every
+//! resolution against the registered `dyn FeatureFlags`, so a service can be
+//! dark-launched and switched on or off without rebuilding the provider.
+
+/// Source of runtime feature flag values.
+///
+/// Register an implementation as `dyn FeatureFlags` to enable gated services.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{FeatureFlags, ServiceCollection, Resolver, DiError};
+/// use std::collections::HashSet;
+/// use std::sync::{Arc, RwLock};
+///
+/// #[derive(Default)]
+/// struct InMemoryFlags(RwLock<HashSet<String>>);
+///
+/// impl FeatureFlags for InMemoryFlags {
+///     fn is_enabled(&self, flag: &str) -> bool {
+///         self.0.read().unwrap().contains(flag)
+///     }
+/// }
+///
+/// struct NewPlanner;
+///
+/// let flags = Arc::new(InMemoryFlags::default());
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton_trait::<dyn FeatureFlags>(flags.clone());
+/// services.add_singleton_gated::<NewPlanner, _>("new-planner", |_| NewPlanner);
+///
+/// let provider = services.build();
+/// assert!(matches!(provider.get::<NewPlanner>(), Err(DiError::FeatureDisabled("new-planner"))));
+///
+/// flags.0.write().unwrap().insert("new-planner".to_string());
+/// assert!(provider.get::<NewPlanner>().is_ok());
+/// ```
+pub trait FeatureFlags: Send + Sync {
+    /// Returns true if `flag` is currently enabled.
+    fn is_enabled(&self, flag: &str) -> bool;
+}
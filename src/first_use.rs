@@ -0,0 +1,276 @@
+//! First-use instrumentation for trait services.
+//!
+//! Serverless agents pay for every service they build during cold start, so a
+//! service that is resolved eagerly but not called until much later is a
+//! candidate for lazy resolution. [`ServiceCollection::instrument_first_use_trait`]
+//! wraps a trait's implementations in a proxy generated by
+//! [`first_use_proxy!`](crate::first_use_proxy) that notes when each instance
+//! was built and when it was first called;
+//! [`ServiceProvider::first_use_report`] lists the gaps.
+
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::{Duration, Instant};
+
+use crate::registration::AnyArc;
+use crate::traits::Resolver;
+use crate::{DiError, RegistrationKind, ServiceCollection, ServiceProvider};
+
+/// Resolution and first-call times of one instrumented instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstUseRecord {
+    /// Name of the instrumented trait
+    pub service: &'static str,
+    /// When the instance was constructed
+    pub resolved_at: Instant,
+    /// When a method was first called on it, if ever
+    pub first_used_at: Option<Instant>,
+}
+
+impl FirstUseRecord {
+    /// Time between resolution and the first call, or `None` if the instance
+    /// has not been used yet.
+    pub fn gap(&self) -> Option<Duration> {
+        self.first_used_at.map(|used| used.duration_since(self.resolved_at))
+    }
+}
+
+struct Entry {
+    service: &'static str,
+    resolved_at: Instant,
+    first_used_at: OnceLock<Instant>,
+}
+
+/// Handle a proxy calls on every method to record the first use.
+#[derive(Clone)]
+pub struct FirstUseProbe {
+    entry: Arc<Entry>,
+}
+
+impl FirstUseProbe {
+    /// Records the current time as the first use; later calls do nothing.
+    #[inline]
+    pub fn touch(&self) {
+        self.entry.first_used_at.get_or_init(Instant::now);
+    }
+}
+
+/// Collected records for every live instrumented instance, registered as a
+/// singleton by the first call to
+/// [`instrument_first_use_trait`](ServiceCollection::instrument_first_use_trait).
+///
+/// Entries are held weakly: an instance's record goes away with the
+/// instance, so resolving transients in a loop doesn't grow the report.
+#[derive(Default)]
+pub struct FirstUseReport {
+    entries: Mutex<Vec<Weak<Entry>>>,
+}
+
+impl FirstUseReport {
+    fn probe(&self, service: &'static str) -> FirstUseProbe {
+        let entry = Arc::new(Entry {
+            service,
+            resolved_at: Instant::now(),
+            first_used_at: OnceLock::new(),
+        });
+        let mut entries = self.entries.lock().unwrap();
+        // Prune only when the buffer is full, so pushes stay amortized O(1)
+        if entries.len() == entries.capacity() {
+            entries.retain(|entry| entry.strong_count() > 0);
+        }
+        entries.push(Arc::downgrade(&entry));
+        FirstUseProbe { entry }
+    }
+
+    /// Records of the instances still alive, in resolution order.
+    pub fn records(&self) -> Vec<FirstUseRecord> {
+        self.entries.lock().unwrap().iter()
+            .filter_map(Weak::upgrade)
+            .map(|entry| FirstUseRecord {
+                service: entry.service,
+                resolved_at: entry.resolved_at,
+                first_used_at: entry.first_used_at.get().copied(),
+            })
+            .collect()
+    }
+}
+
+/// Proxy type that forwards a trait to an inner instance while touching a
+/// [`FirstUseProbe`]. Implemented by [`first_use_proxy!`](crate::first_use_proxy).
+pub trait FirstUseProxy<T: ?Sized>: 'static {
+    /// Wraps `inner` so that calls through the result touch `probe`.
+    fn wrap(inner: Arc<T>, probe: FirstUseProbe) -> Arc<T>;
+}
+
+/// Generates a [`FirstUseProxy`] for an object-safe trait.
+///
+/// List the trait's methods; each must take `&self`. The proxy touches its
+/// probe and then forwards the call.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{first_use_proxy, Resolver, ServiceCollection};
+/// use std::sync::Arc;
+///
+/// trait Embedder: Send + Sync {
+///     fn embed(&self, text: &str) -> Vec<f32>;
+/// }
+///
+/// struct LocalEmbedder;
+/// impl Embedder for LocalEmbedder {
+///     fn embed(&self, text: &str) -> Vec<f32> { vec![text.len() as f32] }
+/// }
+///
+/// first_use_proxy! {
+///     struct EmbedderProbe for dyn Embedder {
+///         fn embed(&self, text: &str) -> Vec<f32>;
+///     }
+/// }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton_trait::<dyn Embedder>(Arc::new(LocalEmbedder));
+/// services.instrument_first_use_trait::<dyn Embedder, EmbedderProbe>();
+///
+/// let provider = services.build();
+/// let embedder = provider.get_required_trait::<dyn Embedder>();
+/// assert!(provider.first_use_report()[0].first_used_at.is_none());
+///
+/// embedder.embed("hello");
+/// assert!(provider.first_use_report()[0].gap().is_some());
+/// ```
+#[macro_export]
+macro_rules! first_use_proxy {
+    (
+        $vis:vis struct $proxy:ident for dyn $trait:path {
+            $( fn $method:ident(&self $(, $arg:ident : $ty:ty)* $(,)?) $(-> $ret:ty)?; )*
+        }
+    ) => {
+        $vis struct $proxy {
+            inner: ::std::sync::Arc<dyn $trait>,
+            probe: $crate::FirstUseProbe,
+        }
+
+        impl $trait for $proxy {
+            $(
+                fn $method(&self $(, $arg: $ty)*) $(-> $ret)? {
+                    self.probe.touch();
+                    self.inner.$method($($arg),*)
+                }
+            )*
+        }
+
+        impl $crate::FirstUseProxy<dyn $trait> for $proxy {
+            fn wrap(
+                inner: ::std::sync::Arc<dyn $trait>,
+                probe: $crate::FirstUseProbe,
+            ) -> ::std::sync::Arc<dyn $trait> {
+                ::std::sync::Arc::new($proxy { inner, probe })
+            }
+        }
+    };
+}
+
+impl ServiceCollection {
+    /// Wraps every registration of trait `T` in the proxy `P` so the time
+    /// between resolution and first call is recorded.
+    ///
+    /// Covers the single binding and all multi-bindings registered so far.
+    /// Records are read with [`ServiceProvider::first_use_report`]. See
+    /// [`first_use_proxy!`](crate::first_use_proxy) for generating `P`.
+    pub fn instrument_first_use_trait<T, P>(&mut self) -> &mut Self
+    where
+        T: ?Sized + 'static + Send + Sync,
+        P: FirstUseProxy<T>,
+    {
+        self.try_add_singleton(FirstUseReport::default());
+
+        let name = std::any::type_name::<T>();
+        self.for_each_trait_registration(name, |registration| {
+            let old_ctor = registration.ctor.clone();
+            registration.kind = RegistrationKind::Decorated;
+            registration.ctor = Arc::new(move |resolver| {
+                let original = old_ctor(resolver)?
+                    .downcast::<Arc<T>>()
+                    .map_err(|_| DiError::TypeMismatch(name))?;
+                let probe = resolver.get_required::<FirstUseReport>().probe(name);
+                Ok(Arc::new(P::wrap((*original).clone(), probe)) as AnyArc)
+            });
+        });
+        self
+    }
+}
+
+impl ServiceProvider {
+    /// Records for every live instance built from a trait instrumented with
+    /// [`ServiceCollection::instrument_first_use_trait`], in resolution order.
+    ///
+    /// Empty when nothing was instrumented.
+    pub fn first_use_report(&self) -> Vec<FirstUseRecord> {
+        self.get::<FirstUseReport>()
+            .map(|report| report.records())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lifetime;
+
+    trait Search: Send + Sync {
+        fn query(&self, text: &str) -> usize;
+    }
+
+    struct IndexSearch;
+    impl Search for IndexSearch {
+        fn query(&self, text: &str) -> usize {
+            text.len()
+        }
+    }
+
+    first_use_proxy! {
+        struct SearchProbe for dyn Search {
+            fn query(&self, text: &str) -> usize;
+        }
+    }
+
+    #[test]
+    fn test_first_use_gap_is_recorded() {
+        let mut services = ServiceCollection::new();
+        services.add_singleton_trait::<dyn Search>(Arc::new(IndexSearch));
+        services.add_trait_factory::<dyn Search, _>(Lifetime::Transient, |_| Arc::new(IndexSearch) as Arc<dyn Search>);
+        services.instrument_first_use_trait::<dyn Search, SearchProbe>();
+        let provider = services.build();
+
+        let search = provider.get_required_trait::<dyn Search>();
+        let _multi = provider.get_all_trait::<dyn Search>().unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(search.query("hello"), 5);
+        search.query("again");
+
+        let report = provider.first_use_report();
+        assert_eq!(report.len(), 2);
+        assert!(report.iter().all(|r| r.service == std::any::type_name::<dyn Search>()));
+        assert!(report[0].gap().unwrap() >= Duration::from_millis(20));
+        assert_eq!(report[1].gap(), None);
+    }
+
+    #[test]
+    fn test_first_use_report_drops_records_of_dropped_instances() {
+        let mut services = ServiceCollection::new();
+        services.add_trait_factory::<dyn Search, _>(Lifetime::Transient, |_| Arc::new(IndexSearch) as Arc<dyn Search>);
+        services.instrument_first_use_trait::<dyn Search, SearchProbe>();
+        let provider = services.build();
+
+        let kept = provider.get_all_trait::<dyn Search>().unwrap();
+        for _ in 0..1000 {
+            provider.get_all_trait::<dyn Search>().unwrap();
+        }
+
+        assert_eq!(provider.first_use_report().len(), 1);
+        let report = provider.get_required::<FirstUseReport>();
+        assert!(report.entries.lock().unwrap().len() < 100);
+        drop(kept);
+        assert!(provider.first_use_report().is_empty());
+    }
+}
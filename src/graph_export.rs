@@ -79,6 +79,59 @@ pub enum DependencyType {
     Decorated,
 }
 
+/// A dependency declared when registering a service.
+///
+/// Passed to [`ServiceCollection::add_singleton_factory_declared`](crate::ServiceCollection::add_singleton_factory_declared)
+/// so the exported graph can show the service's edges without running its
+/// factory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyDecl {
+    key: crate::Key,
+    dependency_type: DependencyType,
+}
+
+impl DependencyDecl {
+    /// Depends on the concrete service `T`.
+    pub fn of<T: 'static>() -> Self {
+        Self {
+            key: crate::key::key_of_type::<T>(),
+            dependency_type: DependencyType::Required,
+        }
+    }
+
+    /// Depends on the single binding of trait `T`.
+    pub fn of_trait<T: ?Sized + 'static>() -> Self {
+        Self {
+            key: crate::Key::Trait(std::any::type_name::<T>()),
+            dependency_type: DependencyType::Trait,
+        }
+    }
+
+    /// Depends on every multi-binding of trait `T`.
+    pub fn all_of<T: ?Sized + 'static>() -> Self {
+        Self {
+            key: crate::Key::Trait(std::any::type_name::<T>()),
+            dependency_type: DependencyType::Multiple,
+        }
+    }
+
+    /// Marks the dependency as optional.
+    pub fn optional(mut self) -> Self {
+        self.dependency_type = DependencyType::Optional;
+        self
+    }
+
+    /// The key the dependency resolves.
+    pub fn key(&self) -> &crate::Key {
+        &self.key
+    }
+
+    /// How the dependency is used.
+    pub fn dependency_type(&self) -> &DependencyType {
+        &self.dependency_type
+    }
+}
+
 /// Complete dependency graph export containing all nodes and relationships.
 ///
 /// This structure can be serialized to JSON, YAML, or other formats for
@@ -187,6 +240,8 @@ pub enum ExportFormat {
     Dot,
     /// Mermaid format for documentation
     Mermaid,
+    /// Self-contained interactive HTML page for viewing in a browser
+    Html,
     /// Custom format for specific workflow engines
     Custom(&'static str),
 }
@@ -224,6 +279,7 @@ impl GraphExporter for DefaultGraphExporter {
             ExportFormat::Yaml => self.export_yaml(graph, options),
             ExportFormat::Dot => self.export_dot(graph, options),
             ExportFormat::Mermaid => self.export_mermaid(graph, options),
+            ExportFormat::Html => self.export_html(graph, options),
             ExportFormat::Custom(name) => Err(crate::DiError::NotFound(
                 Box::leak(format!("Unsupported custom format: {}", name).into_boxed_str())
             )),
@@ -403,8 +459,117 @@ impl DefaultGraphExporter {
 
         Ok(output)
     }
+
+    /// Exports graph as a self-contained interactive HTML page.
+    ///
+    /// The JSON graph is embedded as data and drawn by a small inline
+    /// renderer, so the page works offline without external scripts.
+    fn export_html(&self, graph: &DependencyGraph, options: &ExportOptions) -> crate::DiResult<String> {
+        // `</` would terminate the data block early
+        let json = self.export_json(graph, options)?.replace("</", "<\\/");
+
+        let mut output = String::new();
+        output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        output.push_str("<meta charset=\"utf-8\">\n");
+        output.push_str("<title>ferrous-di dependency graph</title>\n");
+        output.push_str(HTML_STYLE);
+        output.push_str("</head>\n<body>\n");
+        output.push_str(&format!(
+            "<h1>Dependency graph <small>{} services</small></h1>\n",
+            graph.nodes.len()
+        ));
+        output.push_str(HTML_CONTROLS);
+        output.push_str("<svg id=\"graph\" xmlns=\"http://www.w3.org/2000/svg\"></svg>\n");
+        output.push_str("<script type=\"application/json\" id=\"graph-data\">\n");
+        output.push_str(&json);
+        output.push_str("\n</script>\n");
+        output.push_str(HTML_RENDERER);
+        output.push_str("</body>\n</html>\n");
+        Ok(output)
+    }
 }
 
+/// Inline stylesheet for the HTML export.
+const HTML_STYLE: &str = r#"<style>
+body { font-family: sans-serif; margin: 1rem; }
+#controls { margin-bottom: 0.5rem; }
+#graph { width: 100%; height: 80vh; border: 1px solid #ccc; }
+.node circle { stroke: #333; }
+.node.Singleton circle { fill: #e1f5fe; }
+.node.Scoped circle { fill: #e8f5e8; }
+.node.Transient circle { fill: #fff3e0; }
+.node text { font-size: 11px; }
+.edge { stroke: #888; }
+</style>
+"#;
+
+/// Filter controls for the HTML export.
+const HTML_CONTROLS: &str = r#"<div id="controls">
+<input id="filter" type="search" placeholder="Filter by type name">
+<label><input type="checkbox" class="lifetime" value="Singleton" checked> Singleton</label>
+<label><input type="checkbox" class="lifetime" value="Scoped" checked> Scoped</label>
+<label><input type="checkbox" class="lifetime" value="Transient" checked> Transient</label>
+</div>
+"#;
+
+/// Inline renderer for the HTML export: lays nodes out on a circle and
+/// redraws whenever the filter controls change.
+const HTML_RENDERER: &str = r#"<script id="graph-renderer">
+(function () {
+  var graph = JSON.parse(document.getElementById("graph-data").textContent);
+  var svg = document.getElementById("graph");
+  var ns = "http://www.w3.org/2000/svg";
+
+  function el(name, attrs) {
+    var e = document.createElementNS(ns, name);
+    for (var k in attrs) { e.setAttribute(k, attrs[k]); }
+    return e;
+  }
+
+  function renderGraph() {
+    var text = document.getElementById("filter").value.toLowerCase();
+    var lifetimes = Array.prototype.filter
+      .call(document.querySelectorAll(".lifetime"), function (c) { return c.checked; })
+      .map(function (c) { return c.value; });
+    var nodes = graph.nodes.filter(function (n) {
+      return n.type_name.toLowerCase().indexOf(text) !== -1 &&
+        lifetimes.indexOf(n.lifetime) !== -1;
+    });
+
+    while (svg.firstChild) { svg.removeChild(svg.firstChild); }
+    var w = svg.clientWidth || 800, h = svg.clientHeight || 600;
+    var r = Math.max(Math.min(w, h) / 2 - 80, 40);
+    var pos = {};
+    nodes.forEach(function (n, i) {
+      var a = (2 * Math.PI * i) / Math.max(nodes.length, 1);
+      pos[n.id] = { x: w / 2 + r * Math.cos(a), y: h / 2 + r * Math.sin(a) };
+    });
+
+    graph.edges.forEach(function (e) {
+      if (!pos[e.from] || !pos[e.to]) { return; }
+      svg.appendChild(el("line", {
+        "class": "edge", x1: pos[e.from].x, y1: pos[e.from].y, x2: pos[e.to].x, y2: pos[e.to].y
+      }));
+    });
+    nodes.forEach(function (n) {
+      var g = el("g", { "class": "node " + n.lifetime });
+      g.appendChild(el("circle", { cx: pos[n.id].x, cy: pos[n.id].y, r: 8 }));
+      var label = el("text", { x: pos[n.id].x + 10, y: pos[n.id].y + 4 });
+      label.textContent = n.type_name;
+      g.appendChild(label);
+      svg.appendChild(g);
+    });
+  }
+
+  document.getElementById("filter").addEventListener("input", renderGraph);
+  document.querySelectorAll(".lifetime").forEach(function (c) {
+    c.addEventListener("change", renderGraph);
+  });
+  renderGraph();
+})();
+</script>
+"#;
+
 /// Builder for creating dependency graphs from service collections.
 ///
 /// Analyzes the registered services and their dependencies to build
@@ -463,6 +628,10 @@ impl GraphBuilder {
                     let mut meta = HashMap::new();
                     meta.insert("key".to_string(), service_name.to_string());
                     meta.insert("lifetime".to_string(), format!("{:?}", registration.lifetime));
+                    if let Some(options) = options_registration(registration) {
+                        meta.insert("kind".to_string(), "options".to_string());
+                        meta.insert("configures".to_string(), options.configures.to_string());
+                    }
                     meta
                 },
                 position: None,
@@ -488,6 +657,10 @@ impl GraphBuilder {
                     let mut meta = HashMap::new();
                     meta.insert("key".to_string(), service_name.to_string());
                     meta.insert("lifetime".to_string(), format!("{:?}", registration.lifetime));
+                    if let Some(options) = options_registration(registration) {
+                        meta.insert("kind".to_string(), "options".to_string());
+                        meta.insert("configures".to_string(), options.configures.to_string());
+                    }
                     meta
                 },
                 position: None,
@@ -654,6 +827,11 @@ impl GraphBuilder {
         for (key, registration) in &registry.one_small {
             let service_name = key.display_name();
             if let Some(from_node_id) = node_ids.get(service_name) {
+                if let Some(declared) = &registration.declared_deps {
+                    add_declared_edges(from_node_id, declared, nodes, edges, node_ids);
+                    continue;
+                }
+
                 // Create dependency tracking wrapper
                 let dependencies = Arc::new(Mutex::new(Vec::new()));
                 let tracker = DependencyTracker {
@@ -663,7 +841,13 @@ impl GraphBuilder {
                 
                 // Execute factory with dependency tracking (ignore result, we just want dependencies)
                 let ctx = ResolverContext::new(&tracker);
-                let _ = (registration.ctor)(&ctx); // Ignore errors during analysis
+                let is_options = options_registration(registration).is_some();
+                if is_options {
+                    // Options validation panics on bad config; keep the edges recorded so far
+                    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (registration.ctor)(&ctx)));
+                } else {
+                    let _ = (registration.ctor)(&ctx); // Ignore errors during analysis
+                }
                 
                 // Extract captured dependencies
                 let captured_deps = {
@@ -683,7 +867,8 @@ impl GraphBuilder {
                             dependency_type: DependencyType::Required,
                             metadata: {
                                 let mut meta = HashMap::new();
-                                meta.insert("source".to_string(), "factory_analysis".to_string());
+                                let source = if is_options { "options_configure" } else { "factory_analysis" };
+                                meta.insert("source".to_string(), source.to_string());
                                 meta.insert("dependency_name".to_string(), dep_name.clone());
                                 meta
                             },
@@ -704,6 +889,11 @@ impl GraphBuilder {
         for (key, registration) in &registry.one_large {
             let service_name = key.display_name();
             if let Some(from_node_id) = node_ids.get(service_name) {
+                if let Some(declared) = &registration.declared_deps {
+                    add_declared_edges(from_node_id, declared, nodes, edges, node_ids);
+                    continue;
+                }
+
                 // Create dependency tracking wrapper
                 let dependencies = Arc::new(Mutex::new(Vec::new()));
                 let tracker = DependencyTracker {
@@ -713,7 +903,13 @@ impl GraphBuilder {
                 
                 // Execute factory with dependency tracking (ignore result, we just want dependencies)
                 let ctx = ResolverContext::new(&tracker);
-                let _ = (registration.ctor)(&ctx); // Ignore errors during analysis
+                let is_options = options_registration(registration).is_some();
+                if is_options {
+                    // Options validation panics on bad config; keep the edges recorded so far
+                    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (registration.ctor)(&ctx)));
+                } else {
+                    let _ = (registration.ctor)(&ctx); // Ignore errors during analysis
+                }
                 
                 // Extract captured dependencies
                 let captured_deps = {
@@ -733,7 +929,8 @@ impl GraphBuilder {
                             dependency_type: DependencyType::Required,
                             metadata: {
                                 let mut meta = HashMap::new();
-                                meta.insert("source".to_string(), "factory_analysis".to_string());
+                                let source = if is_options { "options_configure" } else { "factory_analysis" };
+                                meta.insert("source".to_string(), source.to_string());
                                 meta.insert("dependency_name".to_string(), dep_name.clone());
                                 meta
                             },
@@ -818,6 +1015,53 @@ impl GraphBuilder {
     }
 }
 
+/// Adds edges for dependencies declared at registration.
+///
+/// Multi-binding declarations link to every implementation of the trait.
+fn add_declared_edges(
+    from_node_id: &str,
+    declared: &[DependencyDecl],
+    nodes: &mut [GraphNode],
+    edges: &mut Vec<GraphEdge>,
+    node_ids: &HashMap<String, String>,
+) {
+    for decl in declared {
+        let dep_name = decl.key.display_name().to_string();
+        let targets: Vec<&String> = if decl.dependency_type == DependencyType::Multiple {
+            (0..)
+                .map_while(|idx| node_ids.get(&format!("{}[{}]", dep_name, idx)))
+                .collect()
+        } else {
+            node_ids.get(&dep_name).into_iter().collect()
+        };
+
+        for to_node_id in targets {
+            edges.push(GraphEdge {
+                from: from_node_id.to_string(),
+                to: to_node_id.clone(),
+                dependency_type: decl.dependency_type.clone(),
+                metadata: {
+                    let mut meta = HashMap::new();
+                    meta.insert("source".to_string(), "declared".to_string());
+                    meta.insert("dependency_name".to_string(), dep_name.clone());
+                    meta
+                },
+            });
+        }
+
+        if let Some(node) = nodes.iter_mut().find(|n| n.id == from_node_id) {
+            if !node.dependencies.contains(&dep_name) {
+                node.dependencies.push(dep_name);
+            }
+        }
+    }
+}
+
+/// Returns the options marker if `registration` is an `Options<T>` built by `OptionsBuilder`.
+fn options_registration(registration: &crate::registration::Registration) -> Option<&crate::OptionsRegistration> {
+    registration.metadata.as_ref()?.downcast_ref::<crate::OptionsRegistration>()
+}
+
 impl Default for GraphBuilder {
     fn default() -> Self {
         Self::new()
@@ -848,6 +1092,14 @@ pub mod exports {
         GraphBuilder::new().build_and_export(provider, ExportFormat::Mermaid)
     }
 
+    /// Exports a service provider's dependency graph as a self-contained HTML page.
+    ///
+    /// The page embeds the JSON graph and a small renderer with filtering
+    /// controls, so it can be opened directly in a browser.
+    pub fn to_html(provider: &crate::ServiceProvider) -> crate::DiResult<String> {
+        GraphBuilder::new().build_and_export(provider, ExportFormat::Html)
+    }
+
     /// Exports with custom options.
     pub fn with_options(provider: &crate::ServiceProvider, format: ExportFormat, options: ExportOptions) -> crate::DiResult<String> {
         GraphBuilder::new()
@@ -1081,6 +1333,103 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_html_export_embeds_graph_and_renderer() {
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton(42usize);
+        services.add_transient_factory::<String, _>(|_| "hello".to_string());
+        let provider = services.build();
+
+        let html = exports::to_html(&provider).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(r#"<script type="application/json" id="graph-data">"#));
+        assert!(html.contains(r#""nodes""#));
+        assert!(html.contains("usize"));
+        assert!(html.contains(r#"<script id="graph-renderer">"#));
+        assert!(html.contains("renderGraph();"));
+    }
+
+    #[test]
+    fn test_options_node_links_to_services_used_in_configure() {
+        use crate::Resolver;
+        use std::sync::Arc;
+
+        trait ConfigSource: Send + Sync {
+            fn get(&self, key: &str) -> Option<String>;
+        }
+        struct StaticConfig;
+        impl ConfigSource for StaticConfig {
+            fn get(&self, _key: &str) -> Option<String> { Some("https://example.com".to_string()) }
+        }
+
+        #[derive(Default)]
+        struct ApiConfig { base_url: String }
+
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton_trait::<dyn ConfigSource>(Arc::new(StaticConfig));
+        services.add_options::<ApiConfig>()
+            .configure(|r, cfg| {
+                cfg.base_url = r.get_required_trait::<dyn ConfigSource>().get("url").unwrap();
+            })
+            .register();
+        let provider = services.build();
+
+        let graph = GraphBuilder::new().build_graph(&provider).unwrap();
+        let options = graph.nodes.iter()
+            .find(|n| n.metadata.get("kind").map(String::as_str) == Some("options"))
+            .expect("options node");
+        assert_eq!(options.metadata["configures"], std::any::type_name::<ApiConfig>());
+
+        let source = graph.nodes.iter()
+            .find(|n| n.type_name == std::any::type_name::<dyn ConfigSource>())
+            .expect("config source node");
+        let edge = graph.edges.iter()
+            .find(|e| e.from == options.id && e.to == source.id)
+            .expect("options -> config source edge");
+        assert_eq!(edge.metadata["source"], "options_configure");
+    }
+
+    #[test]
+    fn test_declared_dependencies_replace_factory_analysis() {
+        use crate::Resolver;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct Database;
+        struct Cache;
+        struct Reports;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut services = crate::ServiceCollection::new();
+        services.add_singleton(Database);
+        services.add_singleton(Cache);
+        let counter = calls.clone();
+        services.add_singleton_factory_declared::<Reports, _>(
+            &[DependencyDecl::of::<Database>(), DependencyDecl::of::<Cache>().optional()],
+            move |r| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                // Tracing this factory would only see the database
+                r.get_required::<Database>();
+                Reports
+            },
+        );
+        let provider = services.build();
+
+        let graph = GraphBuilder::new().build_graph(&provider).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        let node_id = |name: &str| graph.nodes.iter().find(|n| n.type_name == name).unwrap().id.clone();
+        let reports = node_id(std::any::type_name::<Reports>());
+        let edges: Vec<_> = graph.edges.iter().filter(|e| e.from == reports).collect();
+        assert_eq!(edges.len(), 2);
+        assert!(edges.iter().all(|e| e.metadata["source"] == "declared"));
+
+        let to_db = edges.iter().find(|e| e.to == node_id(std::any::type_name::<Database>())).unwrap();
+        assert_eq!(to_db.dependency_type, DependencyType::Required);
+        let to_cache = edges.iter().find(|e| e.to == node_id(std::any::type_name::<Cache>())).unwrap();
+        assert_eq!(to_cache.dependency_type, DependencyType::Optional);
+    }
+
     #[test]
     fn test_workflow_status() {
         assert_eq!(workflow_integration::ExecutionStatus::Running, workflow_integration::ExecutionStatus::Running);
@@ -15,6 +15,8 @@ struct ResolutionTls {
     stack: Vec<&'static str>,
     frozen: bool,
     depth: usize,
+    /// `(dependent, dependency)` edges, collected only while recording is active
+    recorder: Option<Vec<(&'static str, &'static str)>>,
 }
 
 /// Panic payload for circular dependency detection.
@@ -58,6 +60,11 @@ impl StackGuard {
                 panic::panic_any(crate::error::DiError::DepthExceeded(tls.depth));
             }
 
+            let ResolutionTls { stack, recorder, .. } = &mut *tls;
+            if let (Some(edges), Some(&parent)) = (recorder.as_mut(), stack.last()) {
+                edges.push((parent, name));
+            }
+
             tls.stack.push(name);
             tls.depth += 1;
         });
@@ -80,6 +87,49 @@ impl Drop for StackGuard {
     }
 }
 
+/// Runs `f` while recording every `(dependent, dependency)` resolution edge on this thread.
+///
+/// Edges are keyed by display name and only captured for resolutions that
+/// happen inside a factory, i.e. when a dependent is on the stack.
+pub(crate) fn record_dependency_edges<T>(f: impl FnOnce() -> T) -> (T, Vec<(&'static str, &'static str)>) {
+    let previous = RESOLUTION_TLS.with(|tls| tls.borrow_mut().recorder.replace(Vec::new()));
+    let result = f();
+    let edges = RESOLUTION_TLS.with(|tls| {
+        let mut tls = tls.borrow_mut();
+        let edges = tls.recorder.take().unwrap_or_default();
+        // Nested recordings also report to the outer recorder
+        tls.recorder = previous.map(|mut outer| {
+            outer.extend_from_slice(&edges);
+            outer
+        });
+        edges
+    });
+    (result, edges)
+}
+
+/// Number of resolutions in progress on this thread.
+pub(crate) fn resolution_depth() -> usize {
+    RESOLUTION_TLS.with(|tls| tls.borrow().depth)
+}
+
+/// Names on this thread's resolution stack, outermost first.
+#[cfg(feature = "async")]
+pub(crate) fn resolution_stack() -> Vec<&'static str> {
+    RESOLUTION_TLS.with(|tls| tls.borrow().stack.clone())
+}
+
+/// Makes `stack` the resolution stack of a freshly spawned thread that
+/// continues another thread's resolution, so cycles through the services
+/// still being built there are detected instead of waiting on them forever.
+#[cfg(feature = "async")]
+pub(crate) fn seed_resolution_stack(stack: Vec<&'static str>) {
+    RESOLUTION_TLS.with(|tls| {
+        let mut tls = tls.borrow_mut();
+        tls.depth = stack.len();
+        tls.stack = stack;
+    });
+}
+
 /// Execute a closure with circular dependency detection
 pub(crate) fn with_circular_catch<T, F>(name: &'static str, f: F) -> crate::error::DiResult<T>
 where
@@ -1,25 +1,50 @@
 //! Internal disposal bag for managing cleanup hooks.
 
 use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::key::Key;
+use crate::traits::{DisposalSummary, DisposeError};
 
 /// Future type for disposal operations.
 pub(crate) type BoxFutureUnit = Pin<Box<dyn Future<Output = ()> + Send>>;
 
+/// A sync hook with its priority and owning scoped service, if any.
+type SyncHook = (i32, Option<Key>, Box<dyn FnOnce() + Send>);
+/// An async hook with its owning scoped service, if any.
+type AsyncHook = (Option<Key>, Box<dyn FnOnce() -> BoxFutureUnit + Send>);
+
 /// Container for disposal hooks with LIFO execution order.
 ///
 /// This internal structure manages both synchronous and asynchronous disposal hooks.
 /// Async hooks are executed first (in reverse order), followed by sync hooks.
+/// Sync hooks with a higher priority run before lower ones; hooks of equal
+/// priority run in reverse registration order.
+///
+/// Hooks may be tagged with the scoped service whose factory registered them,
+/// so they can be detached when that service is handed off.
 #[derive(Default)]
 pub(crate) struct DisposeBag {
-    sync: Vec<Box<dyn FnOnce() + Send>>,
-    asyncs: Vec<Box<dyn FnOnce() -> BoxFutureUnit + Send>>,
+    sync: Vec<SyncHook>,
+    asyncs: Vec<AsyncHook>,
 }
 
 impl DisposeBag {
     /// Add a synchronous disposal hook.
     pub(crate) fn push_sync(&mut self, f: Box<dyn FnOnce() + Send>) {
-        self.sync.push(f);
+        self.push_sync_with_priority(0, f);
+    }
+
+    /// Add a synchronous disposal hook that runs before hooks of lower priority.
+    pub(crate) fn push_sync_with_priority(&mut self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.sync.push((priority, None, f));
+    }
+
+    /// Add a synchronous disposal hook owned by the scoped service `owner`.
+    pub(crate) fn push_sync_owned(&mut self, owner: Key, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.sync.push((priority, Some(owner), f));
     }
     
     /// Add an asynchronous disposal hook.
@@ -28,25 +53,132 @@ impl DisposeBag {
         Fut: Future<Output = ()> + Send + 'static,
         F: FnOnce() -> Fut + Send + 'static,
     {
-        self.asyncs.push(Box::new(move || Box::pin(f())));
+        self.asyncs.push((None, Box::new(move || Box::pin(f()))));
+    }
+
+    /// Add an asynchronous disposal hook owned by the scoped service `owner`.
+    pub(crate) fn push_async_owned(&mut self, owner: Key, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>) {
+        self.asyncs.push((Some(owner), f));
     }
 
-    /// Execute all sync hooks in reverse order (LIFO).
-    pub(crate) fn run_all_sync_reverse(&mut self) {
-        while let Some(f) = self.sync.pop() {
-            (f)();
+    /// Removes the hooks owned by `owner`, keeping their order, and returns them.
+    pub(crate) fn detach_owned(&mut self, owner: &Key) -> DisposeBag {
+        let (detached, kept) = std::mem::take(&mut self.sync)
+            .into_iter()
+            .partition(|(_, hook_owner, _)| hook_owner.as_ref() == Some(owner));
+        self.sync = kept;
+        let (detached_async, kept) = std::mem::take(&mut self.asyncs)
+            .into_iter()
+            .partition(|(hook_owner, _)| hook_owner.as_ref() == Some(owner));
+        self.asyncs = kept;
+        DisposeBag { sync: detached, asyncs: detached_async }
+    }
+
+    /// Execute all sync hooks by descending priority, each priority in
+    /// reverse order (LIFO).
+    ///
+    /// A panicking hook is recorded as a [`DisposeError`] and does not stop
+    /// the remaining hooks from running.
+    pub(crate) fn run_all_sync_reverse(&mut self) -> DisposalSummary {
+        // Stable, so popping from the end keeps LIFO within a priority
+        self.sync.sort_by_key(|(priority, _, _)| *priority);
+        let mut summary = DisposalSummary::default();
+        while let Some((_, _, f)) = self.sync.pop() {
+            summary.sync_run += 1;
+            if let Err(payload) = catch_unwind(AssertUnwindSafe(f)) {
+                summary.errors.push(DisposeError::from_panic(payload));
+            }
         }
+        summary
     }
 
     /// Execute all async hooks in reverse order (LIFO).
-    pub(crate) async fn run_all_async_reverse(&mut self) {
-        while let Some(f) = self.asyncs.pop() {
-            (f)().await;
+    ///
+    /// A panicking hook is recorded as a [`DisposeError`] and does not stop
+    /// the remaining hooks from running.
+    pub(crate) async fn run_all_async_reverse(&mut self) -> DisposalSummary {
+        let mut summary = DisposalSummary::default();
+        while let Some((_, f)) = self.asyncs.pop() {
+            summary.async_run += 1;
+            if let Err(payload) = (CatchUnwind { inner: (f)() }).await {
+                summary.errors.push(DisposeError::from_panic(payload));
+            }
+        }
+        summary
+    }
+
+    /// Runs every hook of a bag that no caller will await.
+    ///
+    /// Inside a tokio runtime the async hooks are spawned onto it, since
+    /// blocking could starve the runtime they need; otherwise they are driven
+    /// to completion on this thread before the sync hooks run.
+    pub(crate) fn run_all_detached(mut self) -> DisposalSummary {
+        #[cfg(feature = "async")]
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let mut asyncs = DisposeBag { sync: Vec::new(), asyncs: std::mem::take(&mut self.asyncs) };
+            if !asyncs.is_empty() {
+                handle.spawn(async move {
+                    asyncs.run_all_async_reverse().await;
+                });
+            }
+            return self.run_all_sync_reverse();
         }
+        let mut summary = block_on(self.run_all_async_reverse());
+        summary.merge(self.run_all_sync_reverse());
+        summary
+    }
+
+    /// Move all async hooks into `other`, preserving their order after its own hooks.
+    pub(crate) fn drain_async_into(&mut self, other: &mut DisposeBag) {
+        other.asyncs.append(&mut self.asyncs);
+    }
+
+    /// Check if the bag holds async hooks, which only an awaiting caller can run.
+    pub(crate) fn has_async(&self) -> bool {
+        !self.asyncs.is_empty()
     }
 
     /// Check if the bag is empty (no disposers registered).
     pub(crate) fn is_empty(&self) -> bool {
         self.sync.is_empty() && self.asyncs.is_empty()
     }
-}
\ No newline at end of file
+}
+
+/// Drives `future` to completion on the current thread, parking it between polls.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = std::task::Waker::from(std::sync::Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
+/// Future adapter that turns a panic during `poll` into an `Err` payload.
+struct CatchUnwind {
+    inner: BoxFutureUnit,
+}
+
+impl Future for CatchUnwind {
+    type Output = Result<(), Box<dyn std::any::Any + Send>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = &mut self.inner;
+        match catch_unwind(AssertUnwindSafe(|| inner.as_mut().poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(())) => Poll::Ready(Ok(())),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
@@ -4,5 +4,7 @@ pub(crate) mod circular;
 pub(crate) mod dispose_bag;
 
 pub use circular::CircularPanic;
-pub(crate) use circular::with_circular_catch;
+pub(crate) use circular::{record_dependency_edges, resolution_depth, with_circular_catch};
+#[cfg(feature = "async")]
+pub(crate) use circular::{resolution_stack, seed_resolution_stack};
 pub(crate) use dispose_bag::{DisposeBag, BoxFutureUnit};
\ No newline at end of file
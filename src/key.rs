@@ -1,6 +1,10 @@
 //! Service key types for the dependency injection container.
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
+use std::fmt;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
 
 /// Key for service storage and lookup.
 ///
@@ -86,6 +90,120 @@ pub enum Key {
     /// Combination of `MultiTrait` and naming for complex scenarios with
     /// multiple named implementations of the same trait.
     MultiTraitNamed(&'static str, &'static str, usize),
+    /// Single trait binding selected by a typed discriminant
+    ///
+    /// Like `TraitNamed` but keyed by a user-defined value (usually an enum
+    /// variant) instead of a string, so lookups are checked by the compiler.
+    TraitDiscriminated(&'static str, Discriminant),
+}
+
+/// The variant of a [`Key`], without its data.
+///
+/// Returned by [`Key::kind`]. Variants are declared in the order keys sort in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum KeyKind {
+    /// [`Key::Type`]
+    Type,
+    /// [`Key::TypeNamed`]
+    TypeNamed,
+    /// [`Key::Trait`]
+    Trait,
+    /// [`Key::TraitNamed`]
+    TraitNamed,
+    /// [`Key::MultiTrait`]
+    MultiTrait,
+    /// [`Key::MultiTraitNamed`]
+    MultiTraitNamed,
+    /// [`Key::TraitDiscriminated`]
+    TraitDiscriminated,
+}
+
+/// Type-erased discriminant value stored in [`Key::TraitDiscriminated`].
+///
+/// Two discriminants are equal when they have the same type and compare
+/// equal as that type.
+///
+/// # Examples
+///
+/// ```rust
+/// use ferrous_di::Discriminant;
+///
+/// #[derive(Hash, PartialEq, Eq)]
+/// enum Backend { Gpu, Cpu }
+///
+/// assert_eq!(Discriminant::new(Backend::Gpu), Discriminant::new(Backend::Gpu));
+/// assert_ne!(Discriminant::new(Backend::Gpu), Discriminant::new(Backend::Cpu));
+/// ```
+#[derive(Clone)]
+pub struct Discriminant {
+    value: Arc<dyn Any + Send + Sync>,
+    type_name: &'static str,
+    hash: u64,
+    eq: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl Discriminant {
+    /// Wraps a discriminant value.
+    pub fn new<D: Hash + Eq + Send + Sync + 'static>(value: D) -> Self {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        Self {
+            value: Arc::new(value),
+            type_name: std::any::type_name::<D>(),
+            hash: hasher.finish(),
+            eq: |a, b| match (a.downcast_ref::<D>(), b.downcast_ref::<D>()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+
+    /// Returns the wrapped value if it is of type `D`.
+    pub fn downcast_ref<D: 'static>(&self) -> Option<&D> {
+        self.value.downcast_ref::<D>()
+    }
+
+    /// Name of the discriminant type, for diagnostics.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Position of this value among the distinct values seen so far that
+    /// share its type and hash, used to order hash collisions consistently.
+    fn collision_rank(&self) -> usize {
+        static SEEN: Mutex<Vec<Discriminant>> = Mutex::new(Vec::new());
+
+        let mut seen = SEEN.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut rank = 0;
+        for other in seen.iter().filter(|d| d.type_name == self.type_name && d.hash == self.hash) {
+            if other == self {
+                return rank;
+            }
+            rank += 1;
+        }
+        seen.push(self.clone());
+        rank
+    }
+}
+
+impl PartialEq for Discriminant {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && (self.eq)(&*self.value, &*other.value)
+    }
+}
+
+impl Eq for Discriminant {}
+
+impl Hash for Discriminant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+impl fmt::Debug for Discriminant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Discriminant({}#{:016x})", self.type_name, self.hash)
+    }
 }
 
 impl Key {
@@ -117,6 +235,7 @@ impl Key {
             Key::TypeNamed(_, name, _) => name,
             Key::TraitNamed(name, _) => name,
             Key::MultiTraitNamed(name, _, _) => name,
+            Key::TraitDiscriminated(name, _) => name,
         }
     }
     
@@ -147,12 +266,34 @@ impl Key {
     /// ```
     pub fn service_name(&self) -> Option<&'static str> {
         match self {
-            Key::Type(_, _) | Key::Trait(_) | Key::MultiTrait(_, _) => None,
+            Key::Type(_, _) | Key::Trait(_) | Key::MultiTrait(_, _) | Key::TraitDiscriminated(_, _) => None,
             Key::TypeNamed(_, _, name) => Some(name),
             Key::TraitNamed(_, name) => Some(name),
             Key::MultiTraitNamed(_, name, _) => Some(name),
         }
     }
+
+    /// The variant of this key, for grouping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ferrous_di::{Key, KeyKind};
+    ///
+    /// assert_eq!(Key::Trait("dyn app::Logger").kind(), KeyKind::Trait);
+    /// assert_eq!(Key::MultiTrait("dyn app::Tool", 2).kind(), KeyKind::MultiTrait);
+    /// ```
+    pub fn kind(&self) -> KeyKind {
+        match self {
+            Key::Type(_, _) => KeyKind::Type,
+            Key::TypeNamed(_, _, _) => KeyKind::TypeNamed,
+            Key::Trait(_) => KeyKind::Trait,
+            Key::TraitNamed(_, _) => KeyKind::TraitNamed,
+            Key::MultiTrait(_, _) => KeyKind::MultiTrait,
+            Key::MultiTraitNamed(_, _, _) => KeyKind::MultiTraitNamed,
+            Key::TraitDiscriminated(_, _) => KeyKind::TraitDiscriminated,
+        }
+    }
 }
 
 // Ultra-optimized equality for hot path: TypeId-only comparison for concrete types
@@ -171,6 +312,7 @@ impl PartialEq for Key {
             (Key::MultiTraitNamed(a, name_a, idx_a), Key::MultiTraitNamed(b, name_b, idx_b)) => {
                 a == b && name_a == name_b && idx_a == idx_b
             }
+            (Key::TraitDiscriminated(a, d_a), Key::TraitDiscriminated(b, d_b)) => a == b && d_a == d_b,
             
             // Different variants never equal
             _ => false
@@ -180,7 +322,6 @@ impl PartialEq for Key {
 
 impl Eq for Key {}
 
-// Ordering for sorting in hybrid registry
 impl PartialOrd for Key {
     #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
@@ -188,25 +329,43 @@ impl PartialOrd for Key {
     }
 }
 
+/// Total order for deterministic listings.
+///
+/// Keys sort by [`KeyKind`] (in declaration order: `Type`, `TypeNamed`,
+/// `Trait`, `TraitNamed`, `MultiTrait`, `MultiTraitNamed`,
+/// `TraitDiscriminated`) and then by name: type or trait name, service
+/// name, then multi-binding index or discriminant. Concrete types fall back
+/// to their `TypeId` only when two distinct types share a name, so listings
+/// are stable across builds. This agrees with equality as long as a key
+/// carries the real `type_name` of its `TypeId`, which every key the
+/// container builds does. Discriminants sort by type name and hash;
+/// distinct values whose hashes collide are told apart by the order in
+/// which they were first compared.
+///
+/// # Examples
+///
+/// ```rust
+/// use ferrous_di::Key;
+///
+/// let mut keys = vec![
+///     Key::MultiTrait("dyn app::Tool", 1),
+///     Key::Trait("dyn app::Logger"),
+///     Key::MultiTrait("dyn app::Tool", 0),
+/// ];
+/// keys.sort();
+/// assert_eq!(keys, [
+///     Key::Trait("dyn app::Logger"),
+///     Key::MultiTrait("dyn app::Tool", 0),
+///     Key::MultiTrait("dyn app::Tool", 1),
+/// ]);
+/// ```
 impl Ord for Key {
-    #[inline(always)]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        use std::cmp::Ordering;
-        
-        match (self, other) {
-            // Compare by TypeId for concrete types (most common)
-            (Key::Type(a, _), Key::Type(b, _)) => a.cmp(b),
-            (Key::TypeNamed(a, _, name_a), Key::TypeNamed(b, _, name_b)) => {
-                a.cmp(b).then_with(|| name_a.cmp(name_b))
+        self.kind().cmp(&other.kind()).then_with(|| match (self, other) {
+            (Key::Type(a, type_a), Key::Type(b, type_b)) => type_a.cmp(type_b).then_with(|| a.cmp(b)),
+            (Key::TypeNamed(a, type_a, name_a), Key::TypeNamed(b, type_b, name_b)) => {
+                type_a.cmp(type_b).then_with(|| a.cmp(b)).then_with(|| name_a.cmp(name_b))
             }
-            
-            // Different variants - order by variant type
-            (Key::Type(_, _), _) => Ordering::Less,
-            (_, Key::Type(_, _)) => Ordering::Greater,
-            (Key::TypeNamed(_, _, _), _) => Ordering::Less,
-            (_, Key::TypeNamed(_, _, _)) => Ordering::Greater,
-            
-            // Handle remaining variants
             (Key::Trait(a), Key::Trait(b)) => a.cmp(b),
             (Key::TraitNamed(a, name_a), Key::TraitNamed(b, name_b)) => {
                 a.cmp(b).then_with(|| name_a.cmp(name_b))
@@ -217,10 +376,13 @@ impl Ord for Key {
             (Key::MultiTraitNamed(a, name_a, idx_a), Key::MultiTraitNamed(b, name_b, idx_b)) => {
                 a.cmp(b).then_with(|| name_a.cmp(name_b)).then_with(|| idx_a.cmp(idx_b))
             }
-            
-            // All other cases use ordering based on variant position
-            _ => Ordering::Equal, // Should not reach here with exhaustive match
-        }
+            (Key::TraitDiscriminated(a, d_a), Key::TraitDiscriminated(b, d_b)) => a.cmp(b)
+                .then_with(|| d_a.type_name.cmp(d_b.type_name))
+                .then_with(|| d_a.hash.cmp(&d_b.hash))
+                .then_with(|| if d_a == d_b { std::cmp::Ordering::Equal } else { d_a.collision_rank().cmp(&d_b.collision_rank()) }),
+            // Same kind implies same variant
+            _ => std::cmp::Ordering::Equal,
+        })
     }
 }
 
@@ -261,10 +423,65 @@ impl std::hash::Hash for Key {
                 named.hash(state);
                 idx.hash(state);
             }
+            Key::TraitDiscriminated(name, discriminant) => {
+                6u8.hash(state);
+                name.hash(state);
+                discriminant.hash(state);
+            }
         }
     }
 }
 
+/// Policy that redirects resolutions of one key to another.
+///
+/// Rewriters registered with
+/// [`ServiceCollection::add_key_rewriter`](crate::ServiceCollection::add_key_rewriter)
+/// are consulted before every single-service resolution, which supports
+/// A/B tests and canary rollouts of alternative implementations. The first
+/// rewriter to return `Some` wins, and its result is offered to the
+/// rewriters again until none applies.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{Key, KeyRewriter, Resolver, ServiceCollection};
+/// use std::sync::Arc;
+///
+/// trait Recommender: Send + Sync {
+///     fn model(&self) -> &str;
+/// }
+/// struct Stable;
+/// impl Recommender for Stable {
+///     fn model(&self) -> &str { "stable" }
+/// }
+/// struct Canary;
+/// impl Recommender for Canary {
+///     fn model(&self) -> &str { "canary" }
+/// }
+///
+/// struct AlwaysCanary;
+/// impl KeyRewriter for AlwaysCanary {
+///     fn rewrite(&self, key: &Key) -> Option<Key> {
+///         match key {
+///             Key::Trait(name) if name.contains("Recommender") => Some(Key::TraitNamed(name, "canary")),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton_trait::<dyn Recommender>(Arc::new(Stable));
+/// services.add_named_singleton_trait::<dyn Recommender>("canary", Arc::new(Canary));
+/// services.add_key_rewriter(Arc::new(AlwaysCanary));
+///
+/// let provider = services.build();
+/// assert_eq!(provider.get_required_trait::<dyn Recommender>().model(), "canary");
+/// ```
+pub trait KeyRewriter: Send + Sync {
+    /// Returns the key to resolve instead of `key`, or `None` to leave it alone.
+    fn rewrite(&self, key: &Key) -> Option<Key>;
+}
+
 // Helper function for creating type keys - add aggressive inlining
 #[inline(always)]
 pub fn key_of_type<T: 'static>() -> Key {
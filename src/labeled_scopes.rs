@@ -4,9 +4,60 @@
 //! engines where you need nested contexts (workflow → run → node) with proper
 //! hierarchical organization and cleanup.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
 use crate::ServiceProvider;
+use crate::registration::AnyArc;
+
+/// Routed instances keyed by `(service, label value)`
+type RoutedInstances = HashMap<(&'static str, Arc<str>), AnyArc>;
+
+/// Key/value labels attached to a scope and inherited by its children.
+///
+/// Set through [`LabeledScopeExt::create_labeled_scope_with`] or
+/// [`LabeledScope::fork_with`], and read by label-routed registrations such as
+/// [`ServiceCollection::add_label_routed_trait`](crate::ServiceCollection::add_label_routed_trait).
+/// Routed instances are cached per label value and shared by the scopes
+/// descending from the same top-level labeled scope.
+#[derive(Clone, Default)]
+pub struct ScopeLabels {
+    values: HashMap<&'static str, Arc<str>>,
+    routed: Arc<Mutex<RoutedInstances>>,
+}
+
+impl ScopeLabels {
+    /// Returns the value of label `key`, if set on this scope or an ancestor.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|v| &**v)
+    }
+
+    /// Returns labels for a child scope: these values with `overrides` applied.
+    pub(crate) fn child(&self, overrides: &[(&'static str, &str)]) -> Self {
+        let mut values = self.values.clone();
+        values.extend(overrides.iter().map(|(k, v)| (*k, Arc::from(*v))));
+        Self { values, routed: self.routed.clone() }
+    }
+
+    pub(crate) fn value(&self, key: &str) -> Option<Arc<str>> {
+        self.values.get(key).cloned()
+    }
+
+    /// Returns the instance routed to `value` for `service`, creating it on first use.
+    pub(crate) fn routed_or_insert_with(
+        &self,
+        service: &'static str,
+        value: Arc<str>,
+        create: impl FnOnce(&str) -> AnyArc,
+    ) -> AnyArc {
+        let key = (service, value);
+        if let Some(existing) = self.routed.lock().unwrap().get(&key) {
+            return existing.clone();
+        }
+        // Create without holding the lock; first insert wins
+        let created = create(&key.1);
+        self.routed.lock().unwrap().entry(key).or_insert(created).clone()
+    }
+}
 
 /// A hierarchical scope with a label for context and tracing.
 ///
@@ -89,7 +140,29 @@ impl LabeledScope {
     /// # }
     /// ```
     pub fn fork(&self, label: &'static str) -> Self {
-        let child_scope = self.inner.scope.create_child();
+        self.fork_with(label, &[])
+    }
+
+    /// Creates a child scope with the given label and additional key/value labels.
+    ///
+    /// The child inherits its parent's labels; `labels` add to or override them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, LabeledScopeExt};
+    ///
+    /// let provider = ServiceCollection::new().build();
+    /// let run = provider.create_labeled_scope_with("run", &[("tenant", "acme")]);
+    /// let node = run.fork_with("node", &[("region", "eu")]);
+    ///
+    /// assert_eq!(node.label_value("tenant"), Some("acme"));
+    /// assert_eq!(node.label_value("region"), Some("eu"));
+    /// assert_eq!(run.label_value("region"), None);
+    /// ```
+    pub fn fork_with(&self, label: &'static str, labels: &[(&'static str, &str)]) -> Self {
+        let mut child_scope = self.inner.scope.create_child();
+        child_scope.labels = self.inner.scope.labels.child(labels);
         
         Self {
             inner: Arc::new(LabeledScopeInner {
@@ -101,6 +174,11 @@ impl LabeledScope {
         }
     }
 
+    /// Returns the value of key/value label `key` on this scope, if any.
+    pub fn label_value(&self, key: &str) -> Option<&str> {
+        self.inner.scope.labels.get(key)
+    }
+
     /// Returns the label of this scope.
     pub fn label(&self) -> &'static str {
         self.inner.label
@@ -336,6 +414,22 @@ pub trait LabeledScopeExt {
     /// # }
     /// ```
     fn create_labeled_scope(&self, label: &'static str) -> LabeledScope;
+
+    /// Creates a new labeled scope carrying key/value labels.
+    ///
+    /// Labels are inherited by forked children and drive label-routed
+    /// registrations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, LabeledScopeExt};
+    ///
+    /// let provider = ServiceCollection::new().build();
+    /// let scope = provider.create_labeled_scope_with("request", &[("tenant", "acme")]);
+    /// assert_eq!(scope.label_value("tenant"), Some("acme"));
+    /// ```
+    fn create_labeled_scope_with(&self, label: &'static str, labels: &[(&'static str, &str)]) -> LabeledScope;
 }
 
 impl LabeledScopeExt for ServiceProvider {
@@ -343,6 +437,12 @@ impl LabeledScopeExt for ServiceProvider {
         let scope = self.create_scope();
         LabeledScope::new(scope, label)
     }
+
+    fn create_labeled_scope_with(&self, label: &'static str, labels: &[(&'static str, &str)]) -> LabeledScope {
+        let mut scope = self.create_scope();
+        scope.labels = ScopeLabels::default().child(labels);
+        LabeledScope::new(scope, label)
+    }
 }
 
 /// Helper for creating scoped context in workflow engines.
@@ -510,7 +610,7 @@ impl LabeledScopeRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ServiceCollection;
+    use crate::{DiError, Resolver, ServiceCollection};
 
     #[test]
     fn test_labeled_scope_creation() {
@@ -667,4 +767,45 @@ mod tests {
         let depth_1 = registry.find_by_depth(1);
         assert_eq!(depth_1.len(), 1); // One run
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_label_routed_trait_resolves_per_tenant() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        trait Database: Send + Sync {
+            fn tenant(&self) -> &str;
+        }
+        struct TenantDb(String);
+        impl Database for TenantDb {
+            fn tenant(&self) -> &str { &self.0 }
+        }
+
+        let created = Arc::new(AtomicUsize::new(0));
+        let counter = created.clone();
+        let mut services = ServiceCollection::new();
+        services.add_label_routed_trait::<dyn Database, _>("tenant", move |tenant| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Arc::new(TenantDb(tenant.to_string()))
+        });
+        let provider = services.build();
+
+        let acme = provider.create_labeled_scope_with("request", &[("tenant", "acme")]);
+        let globex = provider.create_labeled_scope_with("request", &[("tenant", "globex")]);
+        let acme_node = acme.fork("node");
+
+        let acme_db = acme.as_scope().get_required_trait::<dyn Database>();
+        let globex_db = globex.as_scope().get_required_trait::<dyn Database>();
+        let inherited_db = acme_node.as_scope().get_required_trait::<dyn Database>();
+
+        assert_eq!(acme_db.tenant(), "acme");
+        assert_eq!(globex_db.tenant(), "globex");
+        assert!(Arc::ptr_eq(&acme_db, &inherited_db));
+        assert_eq!(created.load(Ordering::SeqCst), 2);
+
+        let unlabeled = provider.create_labeled_scope("request");
+        assert!(matches!(
+            unlabeled.as_scope().get_trait::<dyn Database>(),
+            Err(DiError::MissingScopeLabel("tenant"))
+        ));
+    }
+}
@@ -134,10 +134,24 @@ pub mod cancellation;
 pub mod labeled_scopes;
 pub mod decoration;
 pub mod graph_export;
+pub mod feature_flags;
+pub mod resolution_args;
+pub mod pool;
+pub mod first_use;
+pub mod overrides;
+pub mod static_table;
+pub mod trait_fn;
+#[cfg(feature = "checkpoint")]
+pub mod checkpoint;
+#[cfg(feature = "opentelemetry")]
+pub mod otel;
+#[cfg(feature = "diagnostics")]
+pub mod state_dump;
 
 // Internal modules
 mod internal;
 mod registration;
+mod service_labels;
 
 // Standard library imports for Options pattern
 use std::sync::Arc;
@@ -147,28 +161,47 @@ use self::provider::ResolverContext as InternalResolverContext;
 
 // Re-export core types
 pub use collection::{ServiceCollection, ServiceModule, ServiceCollectionExt, ServiceCollectionModuleExt};
-pub use provider::{ServiceProvider, Scope, ScopedResolver, ResolverContext};
-pub use descriptors::ServiceDescriptor;
+pub use collection::{Dependencies, Proof, Registered, RegisteredTrait, TypedModule};
+pub use provider::{ServiceProvider, Scope, ScopedResolver, ResolverContext, RestrictedScope, ScopedSlotStats, ScopedFromRootPolicy};
+pub use descriptors::{ServiceDescriptor, RegistrationKind, RegistryDiff};
 pub use error::{DiError, DiResult};
 pub use internal::CircularPanic;
-pub use key::{Key, key_of_type};
+pub use key::{Discriminant, Key, KeyKind, KeyRewriter, key_of_type};
+pub use feature_flags::FeatureFlags;
+pub use resolution_args::ResolutionArgs;
+pub use pool::ServicePool;
+pub use first_use::{FirstUseProbe, FirstUseProxy, FirstUseRecord, FirstUseReport};
+pub use overrides::{OverrideSet, ServiceFactory};
+pub use static_table::{StaticService, StaticServiceTable};
+pub use trait_fn::TraitFn;
+#[cfg(feature = "checkpoint")]
+pub use checkpoint::CheckpointableScoped;
 pub use lifetime::Lifetime;
-pub use observer::{DiObserver, LoggingObserver, ObservationContext, WorkflowObserver, WorkflowContextProvider, MetricsObserver};
+pub use observer::{DiObserver, LoggingObserver, ObservationContext, TracedResolver, CorrelationId, RunContextInfo, WorkflowObserver, WorkflowContextProvider, MetricsObserver, ResolutionProfiler, RingBufferObserver, ResolutionOutcome};
+#[cfg(feature = "opentelemetry")]
+pub use otel::OtelObserver;
+#[cfg(feature = "diagnostics")]
+pub use state_dump::{ContainerStateDump, DescriptorDump, EventDump};
+#[cfg(feature = "derive")]
+pub use ferrous_di_derive::ResolveInto;
 pub use prewarm::{ReadyCheck, ReadinessResult, ReadinessReport};
 pub use scope_local::{ScopeLocal, WorkflowContext, ScopeLocalBuilder, workflow};
 pub use capabilities::{ToolCapability, CapabilityRequirement, ToolSelectionCriteria, ToolInfo, ToolDiscoveryResult};
+#[cfg(feature = "cost-estimation")]
+pub use capabilities::CostModel;
 pub use validation::{ValidationBuilder, ValidationResult, ValidationError, ValidationWarning};
 pub use fast_singletons::{FastSingletonCache, FastSingletonMetrics};
-pub use traits::{Dispose, AsyncDispose, Resolver, ResolverCore};
+pub use metrics::SizeHint;
+pub use traits::{Dispose, AsyncDispose, DisposeWithContext, DisposeContext, DisposeObserver, DisposalSummary, DisposeError, Resolver, ResolverCore, ResolutionInfo};
 
 #[cfg(feature = "async")]
-pub use async_factories::AsyncFactory;
+pub use async_factories::{AsyncDepthPolicy, AsyncFactory, RetryPolicy};
 pub use cancellation::{CancellationToken, CancellationError, ScopeCancellationExt};
-pub use labeled_scopes::{LabeledScope, LabeledScopeExt, LabeledScopeContext, LabeledScopeRegistry, ScopeMetadata};
+pub use labeled_scopes::{LabeledScope, LabeledScopeExt, LabeledScopeContext, LabeledScopeRegistry, ScopeLabels, ScopeMetadata};
 pub use decoration::{ServiceDecorator, TraitDecorator, DecorationPipeline, decorators};
 pub use graph_export::{
     DependencyGraph, GraphNode, GraphEdge, GraphMetadata, GraphLayout, NodePosition, LayoutBounds,
-    DependencyType, ExportOptions, ExportFormat, GraphBuilder, GraphExporter, DefaultGraphExporter,
+    DependencyDecl, DependencyType, ExportOptions, ExportFormat, GraphBuilder, GraphExporter, DefaultGraphExporter,
     exports, workflow_integration
 };
 
@@ -268,6 +301,77 @@ impl<T> Options<T> {
     pub fn get(&self) -> Arc<T> {
         self.inner.clone()
     }
+
+    /// Borrows the configured options without touching the `Arc`'s reference
+    /// count.
+    ///
+    /// The options are immutable once built, so a plain borrow is enough for
+    /// reads. Prefer this over [`get`](Self::get) in hot loops, where the
+    /// atomic increment and decrement of each clone add up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::Options;
+    ///
+    /// struct Limits { max_items: usize }
+    ///
+    /// let options = Options::new(Limits { max_items: 3 });
+    /// let limits = options.borrow();
+    /// let kept: Vec<u32> = (0..10).take_while(|&i| (i as usize) < limits.max_items).collect();
+    /// assert_eq!(kept, [0, 1, 2]);
+    /// ```
+    pub fn borrow(&self) -> OptionsGuard<'_, T> {
+        OptionsGuard { value: &self.inner }
+    }
+}
+
+/// Borrowed view of configured options, returned by [`Options::borrow`].
+///
+/// Dereferences to `T` for as long as the `Options<T>` it came from is
+/// borrowed.
+pub struct OptionsGuard<'a, T> {
+    value: &'a T,
+}
+
+impl<T> std::ops::Deref for OptionsGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> AsRef<T> for OptionsGuard<'_, T> {
+    fn as_ref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: Clone> Options<T> {
+    /// Returns a modified copy of the options, leaving the shared snapshot
+    /// untouched.
+    ///
+    /// Useful for one-off variations such as doubling a timeout for a retry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::Options;
+    ///
+    /// #[derive(Clone)]
+    /// struct RetryConfig { timeout_ms: u64 }
+    ///
+    /// let options = Options::new(RetryConfig { timeout_ms: 500 });
+    /// let retry = options.with_overrides(|cfg| cfg.timeout_ms *= 2);
+    /// assert_eq!(retry.timeout_ms, 1000);
+    /// assert_eq!(options.get().timeout_ms, 500);
+    /// ```
+    pub fn with_overrides(&self, configure: impl FnOnce(&mut T)) -> Arc<T> {
+        let mut copy = T::clone(&self.inner);
+        configure(&mut copy);
+        Arc::new(copy)
+    }
 }
 
 impl<T> IOptions<T> for Options<T>
@@ -279,6 +383,12 @@ where
     }
 }
 
+/// Registration metadata marking an `Options<T>` singleton built by [`OptionsBuilder`].
+pub(crate) struct OptionsRegistration {
+    /// Type name of the configured `T`
+    pub(crate) configures: &'static str,
+}
+
 // Type aliases for common options patterns
 type ConfigureFn<T> = Arc<dyn Fn(&InternalResolverContext, &mut T) + Send + Sync>;
 type PostConfigureFn<T> = Arc<dyn Fn(&InternalResolverContext, &mut T) + Send + Sync>;
@@ -366,6 +476,7 @@ where
 {
     sc: *mut ServiceCollection, // raw ptr to allow builder-style API before build()
     default_maker: Option<Arc<dyn Fn() -> T + Send + Sync>>,
+    inherit: Option<ConfigureFn<T>>,
     configures: Vec<ConfigureFn<T>>,
     post_configures: Vec<PostConfigureFn<T>>,
     validates: Vec<ValidateFn<T>>,
@@ -381,6 +492,7 @@ where
         Self {
             sc,
             default_maker: None,
+            inherit: None,
             configures: Vec::new(),
             post_configures: Vec::new(),
             validates: Vec::new(),
@@ -425,6 +537,62 @@ where
         self
     }
 
+    /// Start from the resolved `Options<Base>` before this builder's own configure steps.
+    ///
+    /// `merge` copies whatever it needs from the base snapshot into the initial value
+    /// (from `default_with` or `T::default()`). This layers environment-specific options
+    /// over a shared base. `Options<Base>` must be registered; calling this again replaces
+    /// the previous base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Options, Resolver};
+    ///
+    /// #[derive(Default)]
+    /// struct BaseConfig {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct ProdConfig {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_options::<BaseConfig>()
+    ///     .configure(|_resolver, config| {
+    ///         config.host = "localhost".to_string();
+    ///         config.port = 8080;
+    ///     })
+    ///     .register();
+    /// services.add_options::<ProdConfig>()
+    ///     .inherit_from::<BaseConfig, _>(|base, config| {
+    ///         config.host = base.host.clone();
+    ///         config.port = base.port;
+    ///     })
+    ///     .configure(|_resolver, config| config.host = "prod.example.com".to_string())
+    ///     .register();
+    ///
+    /// let provider = services.build();
+    /// let config = provider.get_required::<Options<ProdConfig>>().get();
+    /// assert_eq!(config.host, "prod.example.com");
+    /// assert_eq!(config.port, 8080);
+    /// ```
+    pub fn inherit_from<Base, F>(mut self, merge: F) -> Self
+    where
+        Base: Send + Sync + 'static,
+        F: Fn(&Base, &mut T) + Send + Sync + 'static,
+    {
+        self.inherit = Some(Arc::new(move |resolver: &InternalResolverContext, value: &mut T| {
+            let base = resolver.get_required::<Options<Base>>();
+            merge(&base.get(), value);
+        }));
+        self
+    }
+
     /// Configure options by providing a callback that can resolve other services from the container.
     ///
     /// Configure callbacks are executed in the order they were added. The callback receives
@@ -560,10 +728,11 @@ where
     ///
     /// The configuration process follows this order:
     /// 1. Create initial value (default_with or T::default())
-    /// 2. Run all configure callbacks in order
-    /// 3. Run all post_configure callbacks in order  
-    /// 4. Run all validate callbacks - panic on any failure
-    /// 5. Wrap in `Options<T>` and register as singleton
+    /// 2. Merge in the `inherit_from` base, if any
+    /// 3. Run all configure callbacks in order
+    /// 4. Run all post_configure callbacks in order  
+    /// 5. Run all validate callbacks - panic on any failure
+    /// 6. Wrap in `Options<T>` and register as singleton
     ///
     /// # Panics
     ///
@@ -575,6 +744,7 @@ where
 
         // Register Options<T> as singleton (factory)
         let default_maker = self.default_maker.clone();
+        let inherit = self.inherit.clone();
         let configures = self.configures.clone();
         let post_configures = self.post_configures.clone();
         let validates = self.validates.clone();
@@ -587,6 +757,10 @@ where
                 T::default()
             };
 
+            // Merge the base options
+            if let Some(merge) = &inherit {
+                merge(resolver, &mut value);
+            }
             // Run configure steps
             for c in &configures {
                 c(resolver, &mut value);
@@ -605,6 +779,10 @@ where
 
             Options::new(value)
         });
+        sc.set_metadata(
+            &key_of_type::<Options<T>>(),
+            OptionsRegistration { configures: std::any::type_name::<T>() },
+        );
 
         // Note: We don't register T directly as a singleton because that would require
         // cloning the T value from the Arc<T> inside Options<T>. Instead, users should
@@ -768,4 +946,96 @@ mod tests {
         let config = options.get();
         assert_eq!(config.value, 42);
     }
+
+    #[test]
+    fn test_options_with_overrides_leaves_snapshot_unchanged() {
+        #[derive(Clone, Default)]
+        struct HttpConfig {
+            timeout_ms: u64,
+            retries: u32,
+        }
+
+        let mut sc = ServiceCollection::new();
+        sc.add_options::<HttpConfig>()
+            .configure(|_resolver, config| {
+                config.timeout_ms = 250;
+                config.retries = 3;
+            })
+            .register();
+
+        let sp = sc.build();
+        let options = sp.get_required::<Options<HttpConfig>>();
+        let retry = options.with_overrides(|config| config.timeout_ms *= 2);
+
+        assert_eq!(retry.timeout_ms, 500);
+        assert_eq!(retry.retries, 3);
+        assert_eq!(options.get().timeout_ms, 250);
+        assert_eq!(sp.get_required::<Options<HttpConfig>>().get().timeout_ms, 250);
+    }
+
+    #[test]
+    fn test_options_inherit_from_base() {
+        #[derive(Default)]
+        struct BaseConfig {
+            endpoint: String,
+            timeout_ms: u64,
+            retries: u32,
+        }
+
+        #[derive(Default)]
+        struct StagingConfig {
+            endpoint: String,
+            timeout_ms: u64,
+            retries: u32,
+        }
+
+        let mut sc = ServiceCollection::new();
+        sc.add_options::<BaseConfig>()
+            .configure(|_resolver, config| {
+                config.endpoint = "https://api.internal".to_string();
+                config.timeout_ms = 1000;
+                config.retries = 3;
+            })
+            .register();
+        sc.add_options::<StagingConfig>()
+            .inherit_from::<BaseConfig, _>(|base, config| {
+                config.endpoint = base.endpoint.clone();
+                config.timeout_ms = base.timeout_ms;
+                config.retries = base.retries;
+            })
+            .configure(|_resolver, config| {
+                config.timeout_ms = 5000;
+            })
+            .register();
+
+        let sp = sc.build();
+        let staging = sp.get_required::<Options<StagingConfig>>().get();
+        assert_eq!(staging.endpoint, "https://api.internal");
+        assert_eq!(staging.timeout_ms, 5000);
+        assert_eq!(staging.retries, 3);
+        assert_eq!(sp.get_required::<Options<BaseConfig>>().get().timeout_ms, 1000);
+    }
+
+    #[test]
+    fn test_options_borrow_leaves_refcount_untouched() {
+        #[derive(Default)]
+        struct RateLimits { per_second: u32 }
+
+        let mut sc = ServiceCollection::new();
+        sc.add_options::<RateLimits>()
+            .default_with(|| RateLimits { per_second: 50 })
+            .register();
+        let sp = sc.build();
+        let options = sp.get_required::<Options<RateLimits>>();
+
+        let before = Arc::strong_count(options.value());
+        let mut total = 0;
+        for _ in 0..1_000 {
+            let limits = options.borrow();
+            assert_eq!(Arc::strong_count(options.value()), before);
+            total += limits.per_second;
+        }
+        assert_eq!(total, 50_000);
+        assert_eq!(Arc::strong_count(options.value()), before);
+    }
 }
\ No newline at end of file
@@ -6,7 +6,7 @@
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, SystemTime};
 use std::collections::HashMap;
-use crate::Key;
+use crate::{Key, Lifetime, ServiceCollection, ServiceProvider};
 
 /// Central metrics collector for ferrous-di operations
 #[derive(Debug)]
@@ -368,6 +368,77 @@ impl HealthChecker {
     }
 }
 
+/// Heap footprint reported by a service, for capacity planning.
+///
+/// Implement it on memory-heavy singletons (model weights, caches) and opt
+/// them in with [`ServiceCollection::track_memory`] to have them listed by
+/// [`ServiceProvider::singleton_memory_report`].
+pub trait SizeHint {
+    /// Approximate number of bytes this instance holds.
+    fn size_bytes(&self) -> usize;
+}
+
+/// Reads the size of a type-erased instance of `T`.
+pub(crate) type SizeProbe = fn(&crate::registration::AnyArc) -> Option<usize>;
+
+fn probe_size<T: SizeHint + 'static>(value: &crate::registration::AnyArc) -> Option<usize> {
+    value.downcast_ref::<T>().map(SizeHint::size_bytes)
+}
+
+impl ServiceCollection {
+    /// Includes singleton `T` in [`ServiceProvider::singleton_memory_report`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection, SizeHint};
+    ///
+    /// struct Embeddings(Vec<f32>);
+    /// impl SizeHint for Embeddings {
+    ///     fn size_bytes(&self) -> usize { self.0.len() * std::mem::size_of::<f32>() }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Embeddings(vec![0.0; 256]));
+    /// services.track_memory::<Embeddings>();
+    ///
+    /// let provider = services.build();
+    /// provider.get_required::<Embeddings>();
+    /// assert_eq!(provider.singleton_memory_report()[0].1, 1024);
+    /// ```
+    pub fn track_memory<T: SizeHint + Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.memory_probes.insert(crate::key::key_of_type::<T>(), probe_size::<T>);
+        self
+    }
+}
+
+impl ServiceProvider {
+    /// Reported sizes of the singletons opted in with
+    /// [`ServiceCollection::track_memory`], largest first.
+    ///
+    /// Only singletons that have already been built are listed; the report
+    /// never creates one. [`singleton_memory_total`](Self::singleton_memory_total)
+    /// sums it.
+    pub fn singleton_memory_report(&self) -> Vec<(Key, usize)> {
+        let inner = self.inner();
+        let mut report: Vec<(Key, usize)> = inner.memory_probes.iter()
+            .filter_map(|(key, probe)| {
+                let reg = inner.registry.get(key).filter(|reg| reg.lifetime == Lifetime::Singleton)?;
+                let size = probe(&reg.cached_singleton()?)?;
+                Some((key.clone(), size))
+            })
+            .collect();
+        report.sort_by(|(a_key, a), (b_key, b)| b.cmp(a).then_with(|| a_key.cmp(b_key)));
+        report
+    }
+
+    /// Total reported size of the singletons in
+    /// [`singleton_memory_report`](Self::singleton_memory_report).
+    pub fn singleton_memory_total(&self) -> usize {
+        self.singleton_memory_report().iter().map(|(_, size)| size).sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
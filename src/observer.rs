@@ -4,6 +4,7 @@
 //! structured tracing, performance monitoring, and debugging of agent workflows.
 //! Enhanced with run_id correlation for n8n-style workflow engines.
 
+use std::cell::RefCell;
 use std::sync::Arc;
 use std::collections::HashMap;
 use crate::Key;
@@ -65,6 +66,12 @@ impl ObservationContext {
         self
     }
 
+    /// Trace id stamped by a [`TracedResolver`], if the resolution went
+    /// through one.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.metadata.get("trace_id").map(String::as_str)
+    }
+
     /// Gets a correlation string for logging/tracing.
     pub fn correlation_id(&self) -> String {
         match (&self.run_id, &self.workflow_name, &self.node_id) {
@@ -86,6 +93,76 @@ impl Default for ObservationContext {
     }
 }
 
+/// Correlation identifier in the `run:workflow:step` format.
+///
+/// Produced by [`ObservationContext::correlation`] so log lines from the
+/// container and from the host application can be joined on the same key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CorrelationId(String);
+
+impl CorrelationId {
+    /// Builds a correlation id for one step of a workflow run.
+    pub fn new(run_id: &str, workflow_name: &str, step: u32) -> Self {
+        Self(format!("{}:{}:{}", run_id, workflow_name, step))
+    }
+
+    /// Returns the id as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A workflow run context that an [`ObservationContext`] can be built from.
+///
+/// Implement this for the engine's own per-run state to bridge it into DI
+/// observation without keeping a second context type in sync.
+pub trait RunContextInfo {
+    /// Unique identifier of the run
+    fn run_id(&self) -> &str;
+    /// Name of the workflow being executed
+    fn workflow_name(&self) -> &str;
+    /// Current step number
+    fn step(&self) -> u32;
+}
+
+impl ObservationContext {
+    /// Creates an observation context from a workflow run context.
+    ///
+    /// The step becomes the node id, so [`correlation_id`](Self::correlation_id)
+    /// yields `run:workflow:step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ObservationContext, RunContextInfo};
+    ///
+    /// struct Run { id: String, step: u32 }
+    /// impl RunContextInfo for Run {
+    ///     fn run_id(&self) -> &str { &self.id }
+    ///     fn workflow_name(&self) -> &str { "ingest" }
+    ///     fn step(&self) -> u32 { self.step }
+    /// }
+    ///
+    /// let ctx = ObservationContext::from_run_context(&Run { id: "run-1".into(), step: 2 });
+    /// assert_eq!(ctx.correlation_id(), "run-1:ingest:2");
+    /// ```
+    pub fn from_run_context(run: &impl RunContextInfo) -> Self {
+        Self::workflow(run.run_id(), run.workflow_name(), Some(run.step().to_string()))
+    }
+
+    /// Returns the typed correlation id if run, workflow and a numeric step are all known.
+    pub fn correlation(&self) -> Option<CorrelationId> {
+        let step = self.node_id.as_ref()?.parse().ok()?;
+        Some(CorrelationId::new(self.run_id.as_ref()?, self.workflow_name.as_ref()?, step))
+    }
+}
+
 /// Observer trait for dependency injection resolution events.
 ///
 /// This trait enables structured tracing and monitoring of the DI container's
@@ -263,6 +340,66 @@ pub trait DiObserver: Send + Sync {
             }
         }
     }
+
+    /// Called when a factory returns an error instead of a service.
+    ///
+    /// The default implementation calls `resolved()`, so observers that don't
+    /// care about the difference see every finished resolution there.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The service key whose resolution failed
+    /// * `duration` - Time elapsed from `resolving` to the failure
+    /// * `error` - The error the resolution returned
+    fn resolution_failed(&self, key: &Key, duration: std::time::Duration, error: &crate::DiError) {
+        let _ = error;
+        self.resolved(key, duration);
+    }
+
+    /// Called when a factory returns an error, with workflow context.
+    ///
+    /// The default implementation calls `resolved_with_context()`.
+    fn resolution_failed_with_context(
+        &self,
+        key: &Key,
+        duration: std::time::Duration,
+        error: &crate::DiError,
+        context: &ObservationContext,
+    ) {
+        let _ = error;
+        self.resolved_with_context(key, duration, context);
+    }
+
+    /// Called the first time a service marked with
+    /// [`ServiceCollection::deprecate`](crate::ServiceCollection::deprecate) is resolved.
+    ///
+    /// Reported once per registration so lingering callers can be found without
+    /// flooding the logs. The default implementation does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The deprecated service key
+    /// * `message` - The deprecation note given at registration
+    fn deprecated_resolution(&self, key: &Key, message: &str) {
+        let _ = (key, message);
+    }
+
+    /// Name reported by
+    /// [`ServiceProvider::observer_names`](crate::ServiceProvider::observer_names).
+    ///
+    /// Defaults to the implementing type's name; override it to tell apart
+    /// several instances of the same observer type.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Most recent resolution events this observer retains, oldest first.
+    ///
+    /// Reported by observers that keep a history, such as
+    /// [`RingBufferObserver`]; the default returns `None`.
+    fn recent_events(&self) -> Option<Vec<(Key, std::time::Duration, ResolutionOutcome)>> {
+        None
+    }
 }
 
 /// Container for registered observers.
@@ -288,6 +425,22 @@ impl Observers {
         self.observers.push(observer);
     }
 
+    /// Number of registered observers.
+    pub(crate) fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Names of the registered observers, in registration order.
+    pub(crate) fn names(&self) -> Vec<&'static str> {
+        self.observers.iter().map(|observer| observer.name()).collect()
+    }
+
+    /// Events from the first observer that keeps a history.
+    #[cfg(feature = "diagnostics")]
+    pub(crate) fn recent_events(&self) -> Option<Vec<(Key, std::time::Duration, ResolutionOutcome)>> {
+        self.observers.iter().find_map(|observer| observer.recent_events())
+    }
+
     /// Returns true if any observers are registered.
     #[inline]
     pub(crate) fn has_observers(&self) -> bool {
@@ -320,6 +473,28 @@ impl Observers {
         }
     }
 
+    /// Notifies all observers that resolution failed with workflow context.
+    #[inline]
+    pub(crate) fn resolution_failed_with_context(
+        &self,
+        key: &Key,
+        duration: std::time::Duration,
+        error: &crate::DiError,
+        context: &ObservationContext,
+    ) {
+        for observer in &self.observers {
+            observer.resolution_failed_with_context(key, duration, error, context);
+        }
+    }
+
+    /// Notifies all observers that a deprecated service was resolved.
+    #[inline]
+    pub(crate) fn deprecated_resolution(&self, key: &Key, message: &str) {
+        for observer in &self.observers {
+            observer.deprecated_resolution(key, message);
+        }
+    }
+
     /// Notifies all observers that a factory function panicked with workflow context.
     #[inline]
     #[allow(dead_code)]
@@ -403,6 +578,11 @@ impl DiObserver for LoggingObserver {
         eprintln!("{} [{}] FACTORY PANIC in {}: {}", 
             self.prefix, context.correlation_id(), key.display_name(), message);
     }
+
+    fn deprecated_resolution(&self, key: &Key, message: &str) {
+        eprintln!("{} DEPRECATED: {} is still being resolved: {}", 
+            self.prefix, key.display_name(), message);
+    }
 }
 
 /// Workflow-aware observer that focuses on correlation and performance tracking.
@@ -637,6 +817,383 @@ impl DiObserver for MetricsObserver {
     }
 }
 
+/// Observer that records resolution timings as folded stacks for flame graphs.
+///
+/// Each resolution becomes a frame under a synthetic `root` frame, nested
+/// inside whatever resolution was in progress on the same thread. Samples are
+/// self time in microseconds (time spent in a factory minus time spent
+/// resolving its dependencies), which is what `inferno` and `flamegraph.pl`
+/// expect.
+///
+/// Only resolutions that notify observers are recorded: singletons and
+/// transients resolved through the provider.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{ServiceCollection, ResolutionProfiler, Resolver};
+/// use std::sync::Arc;
+///
+/// struct Database;
+/// struct UserService { db: Arc<Database> }
+///
+/// let profiler = Arc::new(ResolutionProfiler::new());
+/// let mut services = ServiceCollection::new();
+/// services.add_observer(profiler.clone());
+/// services.add_singleton_factory::<Database, _>(|_| Database);
+/// services.add_transient_factory::<UserService, _>(|r| UserService {
+///     db: r.get_required::<Database>(),
+/// });
+///
+/// let provider = services.build();
+/// provider.get_required::<UserService>();
+///
+/// // Write this to a file and feed it to `inferno-flamegraph`
+/// let folded = profiler.to_folded();
+/// assert_eq!(folded.lines().count(), 2);
+/// ```
+#[derive(Default)]
+pub struct ResolutionProfiler {
+    stacks: std::sync::Mutex<HashMap<std::thread::ThreadId, Vec<ProfileFrame>>>,
+    samples: std::sync::Mutex<HashMap<String, u64>>,
+}
+
+/// A resolution in progress on one thread.
+struct ProfileFrame {
+    name: &'static str,
+    child_time: std::time::Duration,
+}
+
+impl ResolutionProfiler {
+    /// Creates an empty profiler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded samples in folded-stack format, one
+    /// `root;Outer;Inner <microseconds>` line per distinct path, sorted by path.
+    pub fn to_folded(&self) -> String {
+        let samples = self.samples.lock().unwrap();
+        let mut lines: Vec<_> = samples.iter().collect();
+        lines.sort();
+        lines
+            .into_iter()
+            .map(|(path, micros)| format!("{} {}\n", path, micros))
+            .collect()
+    }
+
+    /// Discards all recorded samples.
+    pub fn reset(&self) {
+        self.samples.lock().unwrap().clear();
+    }
+}
+
+impl DiObserver for ResolutionProfiler {
+    fn resolving(&self, key: &Key) {
+        let mut stacks = self.stacks.lock().unwrap();
+        stacks.entry(std::thread::current().id()).or_default().push(ProfileFrame {
+            name: key.display_name(),
+            child_time: std::time::Duration::ZERO,
+        });
+    }
+
+    fn resolved(&self, key: &Key, duration: std::time::Duration) {
+        let mut stacks = self.stacks.lock().unwrap();
+        let stack = stacks.entry(std::thread::current().id()).or_default();
+
+        // Frames above ours belong to resolutions that unwound without reporting
+        let name = key.display_name();
+        let Some(pos) = stack.iter().rposition(|frame| frame.name == name) else { return };
+        stack.truncate(pos + 1);
+        let frame = stack.pop().expect("frame found above");
+
+        let mut path = String::from("root");
+        for parent in stack.iter() {
+            path.push(';');
+            path.push_str(&parent.name.replace(';', ","));
+        }
+        path.push(';');
+        path.push_str(&name.replace(';', ","));
+
+        if let Some(parent) = stack.last_mut() {
+            parent.child_time += duration;
+        }
+        drop(stacks);
+
+        let self_time = duration.saturating_sub(frame.child_time).as_micros() as u64;
+        *self.samples.lock().unwrap().entry(path).or_default() += self_time;
+    }
+
+    fn factory_panic(&self, _key: &Key, _message: &str) {
+        // The unwound frame is discarded by the next `resolved` on this thread
+    }
+
+    fn resolving_with_context(&self, key: &Key, _context: &ObservationContext) {
+        self.resolving(key);
+    }
+
+    fn resolved_with_context(&self, key: &Key, duration: std::time::Duration, _context: &ObservationContext) {
+        self.resolved(key, duration);
+    }
+
+    fn factory_panic_with_context(&self, key: &Key, message: &str, _context: &ObservationContext) {
+        self.factory_panic(key, message);
+    }
+}
+
+/// How a resolution recorded by [`RingBufferObserver`] ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolutionOutcome {
+    /// The factory returned a service.
+    Resolved,
+    /// The factory returned an error, shown here as its message.
+    Failed(String),
+    /// The factory panicked with the given message.
+    Panicked(String),
+}
+
+/// Always-on observer that keeps only the last `capacity` resolution events.
+///
+/// Cheap enough to leave attached in production: each event takes one short
+/// per-slot lock, so concurrent resolutions rarely contend. Query it with
+/// [`snapshot`](Self::snapshot) after a failure to see what the container was
+/// doing just before. Panicked resolutions are recorded with a zero duration.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{ResolutionOutcome, Resolver, RingBufferObserver, ServiceCollection};
+/// use std::sync::Arc;
+///
+/// struct Request;
+///
+/// let recent = Arc::new(RingBufferObserver::new(64));
+/// let mut services = ServiceCollection::new();
+/// services.add_observer(recent.clone());
+/// services.add_transient_factory::<Request, _>(|_| Request);
+///
+/// let provider = services.build();
+/// provider.get_required::<Request>();
+///
+/// let events = recent.snapshot();
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].2, ResolutionOutcome::Resolved);
+/// ```
+pub struct RingBufferObserver {
+    slots: Box<[RingSlot]>,
+    next: std::sync::atomic::AtomicUsize,
+}
+
+type RingEvent = (Key, std::time::Duration, ResolutionOutcome);
+/// Holds an event with its sequence number, to spot overwritten slots.
+type RingSlot = std::sync::Mutex<Option<(usize, RingEvent)>>;
+
+impl RingBufferObserver {
+    /// Creates an observer retaining the last `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "RingBufferObserver capacity must be non-zero");
+        Self {
+            slots: (0..capacity).map(|_| std::sync::Mutex::new(None)).collect(),
+            next: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of events the buffer retains.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// The retained events, oldest first.
+    ///
+    /// Events recorded while the snapshot is taken may be missing from it.
+    pub fn snapshot(&self) -> Vec<(Key, std::time::Duration, ResolutionOutcome)> {
+        let end = self.next.load(std::sync::atomic::Ordering::Acquire);
+        let start = end.saturating_sub(self.capacity());
+        (start..end)
+            .filter_map(|seq| {
+                let slot = self.slots[seq % self.capacity()].lock().unwrap();
+                match &*slot {
+                    Some((stored, event)) if *stored == seq => Some(event.clone()),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    fn record(&self, key: &Key, duration: std::time::Duration, outcome: ResolutionOutcome) {
+        let seq = self.next.fetch_add(1, std::sync::atomic::Ordering::AcqRel);
+        *self.slots[seq % self.capacity()].lock().unwrap() = Some((seq, (key.clone(), duration, outcome)));
+    }
+}
+
+impl DiObserver for RingBufferObserver {
+    fn resolving(&self, _key: &Key) {}
+
+    fn resolved(&self, key: &Key, duration: std::time::Duration) {
+        self.record(key, duration, ResolutionOutcome::Resolved);
+    }
+
+    fn resolution_failed(&self, key: &Key, duration: std::time::Duration, error: &crate::DiError) {
+        self.record(key, duration, ResolutionOutcome::Failed(error.to_string()));
+    }
+
+    fn factory_panic(&self, key: &Key, message: &str) {
+        self.record(key, std::time::Duration::ZERO, ResolutionOutcome::Panicked(message.to_string()));
+    }
+
+    fn resolving_with_context(&self, key: &Key, _context: &ObservationContext) {
+        self.resolving(key);
+    }
+
+    fn resolved_with_context(&self, key: &Key, duration: std::time::Duration, _context: &ObservationContext) {
+        self.resolved(key, duration);
+    }
+
+    fn resolution_failed_with_context(
+        &self,
+        key: &Key,
+        duration: std::time::Duration,
+        error: &crate::DiError,
+        _context: &ObservationContext,
+    ) {
+        self.resolution_failed(key, duration, error);
+    }
+
+    fn factory_panic_with_context(&self, key: &Key, message: &str, _context: &ObservationContext) {
+        self.factory_panic(key, message);
+    }
+
+    fn recent_events(&self) -> Option<Vec<(Key, std::time::Duration, ResolutionOutcome)>> {
+        Some(self.snapshot())
+    }
+}
+
+thread_local! {
+    /// Trace ids of the `TracedResolver` calls in progress on this thread, innermost last
+    static ACTIVE_TRACE_IDS: RefCell<Vec<Arc<str>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the trace id pushed by [`TracedResolver`], even if a factory panics.
+struct TraceIdGuard;
+
+impl Drop for TraceIdGuard {
+    fn drop(&mut self) {
+        ACTIVE_TRACE_IDS.with(|active| active.borrow_mut().pop());
+    }
+}
+
+/// Trace id of the innermost traced resolution on this thread.
+pub(crate) fn current_trace_id() -> Option<Arc<str>> {
+    ACTIVE_TRACE_IDS.with(|active| active.borrow().last().cloned())
+}
+
+/// Resolver that stamps a trace id onto every resolution made through it.
+///
+/// Created by [`ServiceProvider::with_trace_id`](crate::ServiceProvider::with_trace_id).
+/// Observers see the id as the `trace_id` entry of the [`ObservationContext`]
+/// metadata (also available through [`ObservationContext::trace_id`]),
+/// including for the dependencies resolved along the way. It is a lighter
+/// alternative to a full workflow context when only log correlation is needed.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{DiObserver, Key, ObservationContext, Resolver, ServiceCollection};
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+///
+/// #[derive(Default)]
+/// struct TraceLog(Mutex<Vec<Option<String>>>);
+/// impl DiObserver for TraceLog {
+///     fn resolving(&self, _key: &Key) {}
+///     fn resolved(&self, _key: &Key, _duration: Duration) {}
+///     fn factory_panic(&self, _key: &Key, _message: &str) {}
+///     fn resolving_with_context(&self, _key: &Key, context: &ObservationContext) {
+///         self.0.lock().unwrap().push(context.trace_id().map(String::from));
+///     }
+/// }
+///
+/// let log = Arc::new(TraceLog::default());
+/// let mut services = ServiceCollection::new();
+/// services.add_observer(log.clone());
+/// services.add_transient_factory::<String, _>(|_| "report".to_string());
+/// let provider = services.build();
+///
+/// provider.with_trace_id("req-7f3a").get_required::<String>();
+/// assert_eq!(log.0.lock().unwrap()[0].as_deref(), Some("req-7f3a"));
+/// ```
+#[derive(Clone)]
+pub struct TracedResolver {
+    provider: crate::ServiceProvider,
+    trace_id: Arc<str>,
+}
+
+impl TracedResolver {
+    pub(crate) fn new(provider: crate::ServiceProvider, trace_id: &str) -> Self {
+        Self { provider, trace_id: trace_id.into() }
+    }
+
+    /// The trace id stamped onto resolutions.
+    pub fn trace_id(&self) -> &str {
+        &self.trace_id
+    }
+
+    /// Runs `f` with this resolver's trace id active on the current thread.
+    fn traced<T>(&self, f: impl FnOnce() -> T) -> T {
+        ACTIVE_TRACE_IDS.with(|active| active.borrow_mut().push(self.trace_id.clone()));
+        let _guard = TraceIdGuard;
+        f()
+    }
+}
+
+impl crate::traits::ResolverCore for TracedResolver {
+    fn resolve_any(&self, key: &Key) -> crate::DiResult<Arc<dyn std::any::Any + Send + Sync>> {
+        self.traced(|| self.provider.resolve_any(key))
+    }
+
+    fn resolve_many(&self, key: &Key) -> crate::DiResult<Vec<Arc<dyn std::any::Any + Send + Sync>>> {
+        self.traced(|| self.provider.resolve_many(key))
+    }
+
+    fn cache_status(&self, key: &Key) -> Option<(crate::Lifetime, bool)> {
+        self.provider.cache_status(key)
+    }
+
+    fn scope_labels(&self) -> Option<&crate::ScopeLabels> {
+        self.provider.scope_labels()
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.provider.deadline()
+    }
+
+    fn scope_id(&self) -> Option<u64> {
+        self.provider.scope_id()
+    }
+
+    fn provider(&self) -> Option<&crate::ServiceProvider> {
+        Some(&self.provider)
+    }
+
+    fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
+        self.provider.push_sync_disposer(f);
+    }
+
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.provider.push_sync_disposer_with_priority(priority, f);
+    }
+
+    fn push_async_disposer(&self, f: Box<dyn FnOnce() -> crate::internal::BoxFutureUnit + Send>) {
+        self.provider.push_async_disposer(f);
+    }
+}
+
+impl crate::traits::Resolver for TracedResolver {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -657,6 +1214,26 @@ mod tests {
         assert_eq!(context.correlation_id(), "run-456:user_flow:step_1");
     }
 
+    #[test]
+    fn test_observation_context_from_run_context() {
+        struct Run;
+        impl RunContextInfo for Run {
+            fn run_id(&self) -> &str { "run-789" }
+            fn workflow_name(&self) -> &str { "research" }
+            fn step(&self) -> u32 { 3 }
+        }
+
+        let context = ObservationContext::from_run_context(&Run);
+        assert_eq!(context.correlation_id(), "run-789:research:3");
+
+        let id = context.correlation().unwrap();
+        assert_eq!(id, CorrelationId::new("run-789", "research", 3));
+        assert_eq!(id.as_str(), context.correlation_id());
+
+        let context = ObservationContext::workflow("run-1", "flow", Some("fetch"));
+        assert!(context.correlation().is_none());
+    }
+
     #[test]
     fn test_observation_context_metadata() {
         let context = ObservationContext::with_run_id("run-123")
@@ -729,4 +1306,94 @@ mod tests {
         observers.resolved_with_context(&key, Duration::from_millis(1), &context);
         observers.factory_panic_with_context(&key, "test", &context);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_resolution_profiler_folds_nested_chain() {
+        use crate::{Resolver, ServiceCollection};
+
+        struct Database;
+        struct Repository { _db: Arc<Database> }
+        struct UserService { _repo: Arc<Repository> }
+
+        let profiler = Arc::new(ResolutionProfiler::new());
+        let mut services = ServiceCollection::new();
+        services.add_observer(profiler.clone());
+        services.add_singleton_factory::<Database, _>(|_| {
+            std::thread::sleep(Duration::from_millis(5));
+            Database
+        });
+        services.add_transient_factory::<Repository, _>(|r| Repository { _db: r.get_required::<Database>() });
+        services.add_transient_factory::<UserService, _>(|r| UserService { _repo: r.get_required::<Repository>() });
+
+        let provider = services.build();
+        provider.get_required::<UserService>();
+
+        let folded = profiler.to_folded();
+        let deepest = format!(
+            "root;{};{};{} ",
+            std::any::type_name::<UserService>(),
+            std::any::type_name::<Repository>(),
+            std::any::type_name::<Database>(),
+        );
+        let line = folded.lines().find(|l| l.starts_with(&deepest)).expect("deepest path recorded");
+        let micros: u64 = line.rsplit(' ').next().unwrap().parse().unwrap();
+        assert!((5_000..5_000_000).contains(&micros), "implausible duration: {}", line);
+
+        // The outer frames only count their own time, not the sleep below them
+        assert_eq!(folded.lines().count(), 3);
+        for outer in folded.lines().filter(|l| !l.starts_with(&deepest)) {
+            let micros: u64 = outer.rsplit(' ').next().unwrap().parse().unwrap();
+            assert!(micros < 5_000, "self time leaked into {}", outer);
+        }
+    }
+
+    #[test]
+    fn test_traced_resolver_stamps_trace_id_on_resolutions() {
+        use crate::{Resolver, ServiceCollection};
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct TraceRecorder {
+            seen: Mutex<Vec<(String, Option<String>)>>,
+        }
+
+        impl DiObserver for TraceRecorder {
+            fn resolving(&self, _key: &Key) {}
+            fn resolved(&self, _key: &Key, _duration: Duration) {}
+            fn factory_panic(&self, _key: &Key, _message: &str) {}
+
+            fn resolving_with_context(&self, key: &Key, context: &ObservationContext) {
+                let trace_id = context.trace_id().map(String::from);
+                self.seen.lock().unwrap().push((key.display_name().to_string(), trace_id));
+            }
+        }
+
+        struct Database;
+        struct Repository {
+            _db: Arc<Database>,
+        }
+
+        let recorder = Arc::new(TraceRecorder::default());
+        let mut services = ServiceCollection::new();
+        services.add_observer(recorder.clone());
+        services.add_singleton_factory::<Database, _>(|_| Database);
+        services.add_transient_factory::<Repository, _>(|r| Repository { _db: r.get_required::<Database>() });
+        let provider = services.build();
+
+        let traced = provider.with_trace_id("req-42");
+        assert_eq!(traced.trace_id(), "req-42");
+        traced.get_required::<Repository>();
+        {
+            let seen = recorder.seen.lock().unwrap();
+            // The dependency resolved by the factory carries the id too
+            assert_eq!(seen.len(), 2);
+            assert!(seen.iter().all(|(_, id)| id.as_deref() == Some("req-42")));
+        }
+
+        recorder.seen.lock().unwrap().clear();
+        provider.get_required::<Repository>();
+        let seen = recorder.seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert!(seen.iter().all(|(_, id)| id.is_none()));
+    }
+}
@@ -0,0 +1,306 @@
+//! OpenTelemetry export of resolution events.
+//!
+//! [`OtelObserver`] turns each observed resolution into a span and records
+//! counters and a duration histogram, so container activity shows up in the
+//! same traces and dashboards as the rest of a distributed agent.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread::ThreadId;
+use std::time::Duration;
+
+use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::trace::{Span, Status, TraceContextExt, Tracer};
+use opentelemetry::{Context, KeyValue};
+
+use crate::observer::{DiObserver, ObservationContext};
+use crate::Key;
+
+/// Observer that reports resolutions as OpenTelemetry spans and metrics.
+///
+/// Each resolution starts a span named `resolve <service>` whose parent is the
+/// resolution in progress on the same thread, or the current OTel context for
+/// outermost resolutions. Spans carry these attributes:
+///
+/// - `di.service.name` - display name of the resolved key
+/// - `di.service.lifetime` - `Singleton`, `Scoped` or `Transient`, when known
+/// - `di.resolution.duration_ms` - time spent resolving
+/// - `di.run_id`, `di.workflow.name`, `di.workflow.node` - from the
+///   [`ObservationContext`], when present
+///
+/// Failed resolutions and panicking factories end their span with an error
+/// status; failures also record the error on the span.
+///
+/// The meter receives a `di.resolutions` counter, a `di.resolution_failures`
+/// counter, a `di.factory_panics` counter and a `di.resolution.duration`
+/// histogram in milliseconds, all tagged with the service name and lifetime.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{OtelObserver, Resolver, ServiceCollection};
+/// use std::sync::Arc;
+///
+/// struct Database;
+///
+/// let observer = OtelObserver::new(
+///     opentelemetry::global::tracer("ferrous-di"),
+///     &opentelemetry::global::meter("ferrous-di"),
+/// );
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_observer(Arc::new(observer));
+/// services.add_singleton_factory::<Database, _>(|_| Database);
+///
+/// let provider = services.build();
+/// provider.get_required::<Database>();
+/// ```
+pub struct OtelObserver<T: Tracer> {
+    tracer: T,
+    resolutions: Counter<u64>,
+    failures: Counter<u64>,
+    panics: Counter<u64>,
+    durations: Histogram<f64>,
+    spans: OpenSpans,
+}
+
+/// Contexts of the spans still open on each thread with the name of their
+/// service, innermost last.
+type OpenSpans = Mutex<HashMap<ThreadId, Vec<(&'static str, Context)>>>;
+
+impl<T> OtelObserver<T>
+where
+    T: Tracer + Send + Sync,
+    T::Span: Send + Sync + 'static,
+{
+    /// Creates an observer that starts spans with `tracer` and creates its
+    /// instruments on `meter`.
+    pub fn new(tracer: T, meter: &Meter) -> Self {
+        Self {
+            tracer,
+            resolutions: meter
+                .u64_counter("di.resolutions")
+                .with_description("Services resolved by the container")
+                .build(),
+            failures: meter
+                .u64_counter("di.resolution_failures")
+                .with_description("Resolutions that returned an error")
+                .build(),
+            panics: meter
+                .u64_counter("di.factory_panics")
+                .with_description("Factories that panicked during resolution")
+                .build(),
+            durations: meter
+                .f64_histogram("di.resolution.duration")
+                .with_description("Time spent resolving a service")
+                .with_unit("ms")
+                .build(),
+            spans: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Removes the span for `key` from this thread's stack, dropping any spans
+    /// above it that were abandoned by an unwinding resolution. Returns the
+    /// context holding the span.
+    fn pop_span(&self, key: &Key) -> Option<Context> {
+        let mut spans = self.spans.lock().unwrap();
+        let stack = spans.get_mut(&std::thread::current().id())?;
+        let name = key.display_name();
+        let pos = stack.iter().rposition(|(frame, _)| *frame == name)?;
+        let (_, cx) = stack.drain(pos..).next()?;
+        if stack.is_empty() {
+            spans.remove(&std::thread::current().id());
+        }
+        Some(cx)
+    }
+
+    fn metric_attributes(key: &Key, context: &ObservationContext) -> Vec<KeyValue> {
+        let mut attributes = vec![KeyValue::new("di.service.name", key.display_name())];
+        if let Some(lifetime) = context.metadata.get("lifetime") {
+            attributes.push(KeyValue::new("di.service.lifetime", lifetime.clone()));
+        }
+        attributes
+    }
+}
+
+impl<T> DiObserver for OtelObserver<T>
+where
+    T: Tracer + Send + Sync,
+    T::Span: Send + Sync + 'static,
+{
+    fn resolving(&self, key: &Key) {
+        self.resolving_with_context(key, &ObservationContext::new());
+    }
+
+    fn resolved(&self, key: &Key, duration: Duration) {
+        self.resolved_with_context(key, duration, &ObservationContext::new());
+    }
+
+    fn factory_panic(&self, key: &Key, message: &str) {
+        self.factory_panic_with_context(key, message, &ObservationContext::new());
+    }
+
+    fn resolving_with_context(&self, key: &Key, context: &ObservationContext) {
+        let name = key.display_name();
+        let mut spans = self.spans.lock().unwrap();
+        let stack = spans.entry(std::thread::current().id()).or_default();
+
+        let parent = match stack.last() {
+            Some((_, cx)) => cx.clone(),
+            None => Context::current(),
+        };
+        let mut span = self.tracer.start_with_context(format!("resolve {}", name), &parent);
+
+        span.set_attribute(KeyValue::new("di.service.name", name));
+        if let Some(lifetime) = context.metadata.get("lifetime") {
+            span.set_attribute(KeyValue::new("di.service.lifetime", lifetime.clone()));
+        }
+        if let Some(run_id) = &context.run_id {
+            span.set_attribute(KeyValue::new("di.run_id", run_id.clone()));
+        }
+        if let Some(workflow) = &context.workflow_name {
+            span.set_attribute(KeyValue::new("di.workflow.name", workflow.clone()));
+        }
+        if let Some(node) = &context.node_id {
+            span.set_attribute(KeyValue::new("di.workflow.node", node.clone()));
+        }
+        stack.push((name, parent.with_span(span)));
+    }
+
+    fn resolved_with_context(&self, key: &Key, duration: Duration, context: &ObservationContext) {
+        let millis = duration.as_secs_f64() * 1000.0;
+        let attributes = Self::metric_attributes(key, context);
+        self.resolutions.add(1, &attributes);
+        self.durations.record(millis, &attributes);
+
+        if let Some(cx) = self.pop_span(key) {
+            let span = cx.span();
+            span.set_attribute(KeyValue::new("di.resolution.duration_ms", millis));
+            span.end();
+        }
+    }
+
+    fn resolution_failed(&self, key: &Key, duration: Duration, error: &crate::DiError) {
+        self.resolution_failed_with_context(key, duration, error, &ObservationContext::new());
+    }
+
+    fn resolution_failed_with_context(
+        &self,
+        key: &Key,
+        duration: Duration,
+        error: &crate::DiError,
+        context: &ObservationContext,
+    ) {
+        let millis = duration.as_secs_f64() * 1000.0;
+        let attributes = Self::metric_attributes(key, context);
+        self.failures.add(1, &attributes);
+        self.durations.record(millis, &attributes);
+
+        if let Some(cx) = self.pop_span(key) {
+            let span = cx.span();
+            span.set_attribute(KeyValue::new("di.resolution.duration_ms", millis));
+            span.record_error(error);
+            span.set_status(Status::error(error.to_string()));
+            span.end();
+        }
+    }
+
+    fn factory_panic_with_context(&self, key: &Key, message: &str, context: &ObservationContext) {
+        self.panics.add(1, &Self::metric_attributes(key, context));
+
+        if let Some(cx) = self.pop_span(key) {
+            let span = cx.span();
+            span.set_status(Status::error(message.to_string()));
+            span.end();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scope_local::{ScopeLocal, WorkflowContext};
+    use crate::{Resolver, ServiceCollection};
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry::Value;
+    use opentelemetry_sdk::metrics::SdkMeterProvider;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+    use opentelemetry::metrics::MeterProvider;
+    use std::sync::Arc;
+
+    struct Database;
+    struct Repository {
+        _db: Arc<Database>,
+    }
+
+    #[test]
+    fn test_resolution_produces_span_with_attributes() {
+        let exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let meter_provider = SdkMeterProvider::default();
+        let observer = OtelObserver::new(tracer_provider.tracer("test"), &meter_provider.meter("test"));
+
+        let mut services = ServiceCollection::new();
+        services.add_observer(Arc::new(observer));
+        services.add_singleton(ScopeLocal::new(WorkflowContext::with_run_id("ingest", "run-42")));
+        services.add_singleton_factory::<Database, _>(|_| Database);
+        services.add_transient_factory::<Repository, _>(|r| Repository {
+            _db: r.get_required::<Database>(),
+        });
+
+        let provider = services.build();
+        provider.get_required::<Repository>();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let attribute = |span: &opentelemetry_sdk::trace::SpanData, name: &str| {
+            span.attributes.iter().find(|kv| kv.key.as_str() == name).map(|kv| kv.value.clone())
+        };
+
+        let repo_name = std::any::type_name::<Repository>();
+        let repo = spans.iter()
+            .find(|span| attribute(span, "di.service.name") == Some(Value::from(repo_name)))
+            .expect("span for the transient");
+        assert_eq!(repo.name, format!("resolve {}", repo_name));
+        assert_eq!(attribute(repo, "di.service.lifetime"), Some(Value::from("Transient")));
+        assert_eq!(attribute(repo, "di.run_id"), Some(Value::from("run-42")));
+        assert!(attribute(repo, "di.resolution.duration_ms").is_some());
+
+        // The dependency is nested under the resolution that requested it
+        let db = spans.iter()
+            .find(|span| attribute(span, "di.service.name") == Some(Value::from(std::any::type_name::<Database>())))
+            .expect("span for the singleton");
+        assert_eq!(attribute(db, "di.service.lifetime"), Some(Value::from("Singleton")));
+        assert_eq!(db.parent_span_id, repo.span_context.span_id());
+        assert_eq!(db.span_context.trace_id(), repo.span_context.trace_id());
+    }
+
+    #[test]
+    fn test_failed_resolution_ends_span_with_error_status() {
+        trait Store: Send + Sync {}
+        trait BlobStore: Store {}
+
+        let exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let meter_provider = SdkMeterProvider::default();
+        let observer = OtelObserver::new(tracer_provider.tracer("test"), &meter_provider.meter("test"));
+
+        let mut services = ServiceCollection::new();
+        services.add_observer(Arc::new(observer));
+        // The upcast's factory fails because nothing implements BlobStore
+        services.register_upcast::<dyn BlobStore, dyn Store>(|store| store);
+
+        let provider = services.build();
+        assert!(provider.get_trait::<dyn Store>().is_err());
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let store = spans.iter()
+            .find(|span| span.name == format!("resolve {}", std::any::type_name::<dyn Store>()))
+            .expect("span for the failed resolution");
+        assert!(matches!(&store.status, opentelemetry::trace::Status::Error { description } if description.contains("not found")));
+        assert!(store.events.iter().any(|event| event.name == "exception"));
+    }
+}
@@ -0,0 +1,270 @@
+//! Per-call dependency overrides.
+//!
+//! [`Resolver::get_with_overrides`](crate::Resolver::get_with_overrides) runs
+//! a service's factory once with some of its dependencies replaced, which is
+//! the usual shape of a unit test that swaps one collaborator for a stub. The
+//! replacement is only visible to that call, so concurrent resolutions and
+//! cached singletons are unaffected.
+//!
+//! The same machinery backs [`Resolver::get_factory`](crate::Resolver::get_factory),
+//! which pins a service's singleton and scoped dependencies as overrides so
+//! that fresh instances can be built later without a resolver at hand.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::internal::BoxFutureUnit;
+use crate::registration::AnyArc;
+use crate::traits::ResolverCore;
+use crate::{DiError, DiResult, Key, Lifetime, ResolverContext, ServiceProvider};
+
+/// Instances that stand in for dependencies during one resolution.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{OverrideSet, Resolver, ServiceCollection};
+/// use std::sync::Arc;
+///
+/// trait Database: Send + Sync {
+///     fn name(&self) -> &str;
+/// }
+/// struct Postgres;
+/// impl Database for Postgres {
+///     fn name(&self) -> &str { "postgres" }
+/// }
+/// struct Stub;
+/// impl Database for Stub {
+///     fn name(&self) -> &str { "stub" }
+/// }
+///
+/// struct UserService { db: Arc<dyn Database> }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton_trait::<dyn Database>(Arc::new(Postgres));
+/// services.add_transient_factory::<UserService, _>(|r| UserService {
+///     db: r.get_required_trait::<dyn Database>(),
+/// });
+///
+/// let provider = services.build();
+/// let overrides = OverrideSet::new().with_trait::<dyn Database>(Arc::new(Stub));
+/// let service = provider.get_with_overrides::<UserService>(overrides).unwrap();
+/// assert_eq!(service.db.name(), "stub");
+/// ```
+#[derive(Clone, Default)]
+pub struct OverrideSet {
+    values: HashMap<Key, AnyArc>,
+}
+
+impl OverrideSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the concrete service `T` with `value`.
+    pub fn with<T: 'static + Send + Sync>(self, value: T) -> Self {
+        self.with_arc(Arc::new(value))
+    }
+
+    /// Replaces the concrete service `T` with an existing instance.
+    pub fn with_arc<T: 'static + Send + Sync>(mut self, value: Arc<T>) -> Self {
+        let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+        self.values.insert(key, value);
+        self
+    }
+
+    /// Replaces the single binding of trait `T` with `value`.
+    pub fn with_trait<T: ?Sized + 'static + Send + Sync>(mut self, value: Arc<T>) -> Self {
+        let key = Key::Trait(std::any::type_name::<T>());
+        self.values.insert(key, Arc::new(value));
+        self
+    }
+
+    /// Number of overridden keys.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if nothing is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Resolver handed to the overridden factory: answers from the set first and
+/// defers everything else to the caller's resolver.
+struct OverridingResolver<'a> {
+    inner: &'a dyn ResolverCore,
+    overrides: &'a OverrideSet,
+}
+
+impl ResolverCore for OverridingResolver<'_> {
+    fn resolve_any(&self, key: &Key) -> DiResult<AnyArc> {
+        match self.overrides.values.get(key) {
+            Some(value) => Ok(value.clone()),
+            None => self.inner.resolve_any(key),
+        }
+    }
+
+    fn resolve_many(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
+        self.inner.resolve_many(key)
+    }
+
+    fn cache_status(&self, key: &Key) -> Option<(Lifetime, bool)> {
+        self.inner.cache_status(key)
+    }
+
+    fn scope_labels(&self) -> Option<&crate::labeled_scopes::ScopeLabels> {
+        self.inner.scope_labels()
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.inner.deadline()
+    }
+
+    fn scope_id(&self) -> Option<u64> {
+        self.inner.scope_id()
+    }
+
+    fn provider(&self) -> Option<&ServiceProvider> {
+        self.inner.provider()
+    }
+
+    fn share_scope(&self) -> Option<crate::Scope> {
+        self.inner.share_scope()
+    }
+
+    fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
+        self.inner.push_sync_disposer(f);
+    }
+
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.inner.push_sync_disposer_with_priority(priority, f);
+    }
+
+    fn push_async_disposer(&self, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>) {
+        self.inner.push_async_disposer(f);
+    }
+}
+
+/// Runs the factory registered for `T` against `resolver`, with `overrides`
+/// answering the factory's own requests.
+pub(crate) fn resolve_with_overrides<T: 'static + Send + Sync>(
+    resolver: &dyn ResolverCore,
+    overrides: &OverrideSet,
+) -> DiResult<Arc<T>> {
+    let name = std::any::type_name::<T>();
+    let key = Key::Type(TypeId::of::<T>(), name);
+    let provider = resolver.provider().ok_or(DiError::NotFound(name))?;
+    let reg = provider.inner().registry.get(&key).ok_or(DiError::NotFound(name))?;
+
+    let overriding = OverridingResolver { inner: resolver, overrides };
+    (reg.ctor)(&ResolverContext::new(&overriding))?
+        .downcast::<T>()
+        .map_err(|_| DiError::TypeMismatch(name))
+}
+
+/// Factory handle returned by [`Resolver::get_factory`](crate::Resolver::get_factory).
+pub type ServiceFactory<T> = Arc<dyn Fn() -> DiResult<Arc<T>> + Send + Sync>;
+
+/// Resolver that remembers the singleton and scoped instances handed to the
+/// factory, so later calls can reuse them.
+struct PinningResolver<'a> {
+    inner: &'a dyn ResolverCore,
+    pinned: Mutex<OverrideSet>,
+}
+
+impl ResolverCore for PinningResolver<'_> {
+    fn resolve_any(&self, key: &Key) -> DiResult<AnyArc> {
+        let value = self.inner.resolve_any(key)?;
+        if let Some((Lifetime::Singleton | Lifetime::Scoped, _)) = self.inner.cache_status(key) {
+            self.pinned.lock().unwrap().values.insert(key.clone(), value.clone());
+        }
+        Ok(value)
+    }
+
+    fn resolve_many(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
+        self.inner.resolve_many(key)
+    }
+
+    fn cache_status(&self, key: &Key) -> Option<(Lifetime, bool)> {
+        self.inner.cache_status(key)
+    }
+
+    fn scope_labels(&self) -> Option<&crate::labeled_scopes::ScopeLabels> {
+        self.inner.scope_labels()
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.inner.deadline()
+    }
+
+    fn scope_id(&self) -> Option<u64> {
+        self.inner.scope_id()
+    }
+
+    fn provider(&self) -> Option<&ServiceProvider> {
+        self.inner.provider()
+    }
+
+    fn share_scope(&self) -> Option<crate::Scope> {
+        self.inner.share_scope()
+    }
+
+    fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
+        self.inner.push_sync_disposer(f);
+    }
+
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.inner.push_sync_disposer_with_priority(priority, f);
+    }
+
+    fn push_async_disposer(&self, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>) {
+        self.inner.push_async_disposer(f);
+    }
+}
+
+/// Builds a handle that produces instances of `T` on demand.
+///
+/// Non-transient registrations resolve once and the handle returns that
+/// instance. Transient registrations run their factory once here to learn
+/// which singleton and scoped dependencies they use; the instance built doing
+/// so is the handle's first result, and later calls rerun the factory with
+/// those dependencies pinned. The reruns resolve everything else through the
+/// scope the handle came from (shared, not cloned), or the root provider when
+/// it came from there, so nested scoped dependencies and disposal hooks land
+/// in that scope.
+pub(crate) fn factory_handle<T: 'static + Send + Sync>(resolver: &dyn ResolverCore) -> DiResult<ServiceFactory<T>> {
+    let name = std::any::type_name::<T>();
+    let key = Key::Type(TypeId::of::<T>(), name);
+    let provider = resolver.provider().ok_or(DiError::NotFound(name))?;
+    let reg = provider.inner().registry.get(&key).ok_or(DiError::NotFound(name))?;
+    let downcast = move |any: AnyArc| any.downcast::<T>().map_err(|_| DiError::TypeMismatch(name));
+
+    if reg.lifetime != Lifetime::Transient {
+        let instance = downcast(resolver.resolve_any(&key)?)?;
+        return Ok(Arc::new(move || Ok(instance.clone())));
+    }
+
+    let pinning = PinningResolver { inner: resolver, pinned: Mutex::new(OverrideSet::new()) };
+    let first = downcast((reg.ctor)(&ResolverContext::new(&pinning))?)?;
+    let pinned = pinning.pinned.into_inner().unwrap();
+    let ctor = reg.ctor.clone();
+    let scope = resolver.share_scope();
+    let provider = provider.clone();
+    let first = Mutex::new(Some(first));
+
+    Ok(Arc::new(move || {
+        if let Some(instance) = first.lock().unwrap().take() {
+            return Ok(instance);
+        }
+        let origin: &dyn ResolverCore = match &scope {
+            Some(scope) => scope,
+            None => &provider,
+        };
+        let overriding = OverridingResolver { inner: origin, overrides: &pinned };
+        (ctor)(&ResolverContext::new(&overriding)).and_then(downcast)
+    }))
+}
@@ -0,0 +1,144 @@
+//! Fixed-size instance pools.
+//!
+//! A pooled service sits between a singleton (one shared instance) and a
+//! transient (a new instance per request): [`ServiceCollection::add_pooled`]
+//! builds a fixed number of instances once, and
+//! [`Resolver::get_pooled`](crate::Resolver::get_pooled) hands them out,
+//! preferring instances nobody else is holding.
+
+use std::sync::{Arc, Mutex};
+
+use crate::{ResolverContext, ServiceCollection};
+
+/// A fixed set of instances of `T` handed out in least-recently-used order.
+///
+/// An instance counts as in use while any `Arc` returned by
+/// [`acquire`](Self::acquire) is alive and returns to the pool when the last
+/// one is dropped. When every instance is in use, the least recently acquired
+/// one is shared again rather than growing the pool.
+pub struct ServicePool<T> {
+    instances: Vec<Arc<T>>,
+    /// Acquisition tick at which each instance was last handed out.
+    last_used: Mutex<(u64, Vec<u64>)>,
+}
+
+impl<T> ServicePool<T> {
+    fn new(instances: Vec<Arc<T>>) -> Self {
+        let ticks = vec![0; instances.len()];
+        Self {
+            instances,
+            last_used: Mutex::new((0, ticks)),
+        }
+    }
+
+    /// Hands out an idle instance if there is one, otherwise the least
+    /// recently acquired instance.
+    pub fn acquire(&self) -> Arc<T> {
+        let mut guard = self.last_used.lock().unwrap();
+        let (tick, last_used) = &mut *guard;
+        let idle = |i: &usize| Arc::strong_count(&self.instances[*i]) == 1;
+
+        let pick = (0..self.instances.len())
+            .filter(idle)
+            .min_by_key(|&i| last_used[i])
+            .or_else(|| (0..self.instances.len()).min_by_key(|&i| last_used[i]))
+            .expect("pools are never empty");
+
+        *tick += 1;
+        last_used[pick] = *tick;
+        self.instances[pick].clone()
+    }
+
+    /// Number of instances in the pool.
+    pub fn size(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Number of instances currently held outside the pool.
+    pub fn in_use(&self) -> usize {
+        self.instances.iter().filter(|i| Arc::strong_count(i) > 1).count()
+    }
+}
+
+impl ServiceCollection {
+    /// Registers a pool of `size` instances of `T`, resolved with
+    /// [`get_pooled`](crate::Resolver::get_pooled).
+    ///
+    /// The factory runs `size` times when the pool is first needed; the pool
+    /// itself is a singleton. `get_required::<T>()` does not resolve pooled
+    /// services.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Resolver};
+    /// use std::sync::Arc;
+    ///
+    /// struct Connection { id: usize }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// let next_id = std::sync::atomic::AtomicUsize::new(0);
+    /// services.add_pooled::<Connection, _>(4, move |_| Connection {
+    ///     id: next_id.fetch_add(1, std::sync::atomic::Ordering::SeqCst),
+    /// });
+    ///
+    /// let provider = services.build();
+    /// let first = provider.get_pooled::<Connection>().unwrap();
+    /// let second = provider.get_pooled::<Connection>().unwrap();
+    /// assert_ne!(first.id, second.id);
+    /// ```
+    pub fn add_pooled<T, F>(&mut self, size: usize, factory: F) -> &mut Self
+    where
+        T: 'static + Send + Sync,
+        F: Fn(&ResolverContext) -> T + Send + Sync + 'static,
+    {
+        assert!(size > 0, "pool for {} must hold at least one instance", std::any::type_name::<T>());
+        self.add_singleton_factory::<ServicePool<T>, _>(move |r| {
+            ServicePool::new((0..size).map(|_| Arc::new(factory(r))).collect())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Resolver;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Worker;
+
+    #[test]
+    fn test_pool_reuses_instances_beyond_its_size() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = built.clone();
+
+        let mut services = ServiceCollection::new();
+        services.add_pooled::<Worker, _>(2, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Worker
+        });
+        let provider = services.build();
+
+        let a = provider.get_pooled::<Worker>().unwrap();
+        let b = provider.get_pooled::<Worker>().unwrap();
+        assert!(!Arc::ptr_eq(&a, &b));
+
+        // Both are held, so the third acquisition shares the least recently used
+        let c = provider.get_pooled::<Worker>().unwrap();
+        assert!(Arc::ptr_eq(&c, &a));
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+
+        // Dropping every handle to `b` makes it the preferred idle instance
+        drop(b);
+        let d = provider.get_pooled::<Worker>().unwrap();
+        assert!(!Arc::ptr_eq(&d, &a));
+
+        let pool = provider.get_required::<ServicePool<Worker>>();
+        assert_eq!(pool.size(), 2);
+        assert_eq!(pool.in_use(), 2);
+    }
+}
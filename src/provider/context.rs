@@ -3,6 +3,8 @@
 //! This module contains the ResolverContext type which provides
 //! the interface for factory functions to resolve dependencies.
 
+use crate::key::Key;
+use crate::provider::Scope;
 use crate::traits::{Resolver, ResolverCore};
 
 /// Context passed to factory functions for resolving dependencies.
@@ -33,6 +35,8 @@ use crate::traits::{Resolver, ResolverCore};
 /// ```
 pub struct ResolverContext<'a> {
     resolver: &'a dyn ResolverCore,
+    /// Scoped service being built, which owns the disposal hooks registered here.
+    owner: Option<(&'a Scope, Key)>,
 }
 
 impl<'a> ResolverContext<'a> {
@@ -41,7 +45,52 @@ impl<'a> ResolverContext<'a> {
     where 
         T: ResolverCore,
     {
-        Self { resolver }
+        Self { resolver, owner: None }
+    }
+
+    /// Creates a context for building the scoped service `key` in `scope`.
+    ///
+    /// Disposal hooks registered through it are tagged with `key`, so
+    /// [`Scope::take_scoped`] can detach them.
+    pub(crate) fn for_scoped(scope: &'a Scope, key: &Key) -> Self {
+        Self { resolver: scope, owner: Some((scope, key.clone())) }
+    }
+
+    /// Returns a caller-supplied value passed via [`Scope::get_with_context`].
+    ///
+    /// Values are visible only to the factory of the service requested from
+    /// that call, not to those of its dependencies. Returns `None` anywhere
+    /// else or if no value of type `A` was supplied.
+    ///
+    /// [`Scope::get_with_context`]: crate::Scope::get_with_context
+    pub fn arg<A: std::any::Any + Send + Sync>(&self) -> Option<std::sync::Arc<A>> {
+        crate::resolution_args::current_arg::<A>()
+    }
+
+    /// Returns the [`id`](crate::Scope::id) of the scope doing the resolving.
+    ///
+    /// Scoped factories can derive a per-scope identity from it without
+    /// shared counters. Returns `None` when resolving from the root provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct RequestId(String);
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_factory::<RequestId, _>(|r| {
+    ///     RequestId(format!("req-{}", r.scope_id().unwrap_or_default()))
+    /// });
+    ///
+    /// let provider = services.build();
+    /// let scope = provider.create_scope();
+    /// let id = scope.get_required::<RequestId>();
+    /// assert_eq!(id.0, format!("req-{}", scope.id()));
+    /// ```
+    pub fn scope_id(&self) -> Option<u64> {
+        self.resolver.scope_id()
     }
 }
 
@@ -54,12 +103,49 @@ impl<'a> ResolverCore for ResolverContext<'a> {
         self.resolver.resolve_many(key)
     }
 
+    fn cache_status(&self, key: &crate::Key) -> Option<(crate::Lifetime, bool)> {
+        self.resolver.cache_status(key)
+    }
+
+    fn scope_labels(&self) -> Option<&crate::labeled_scopes::ScopeLabels> {
+        self.resolver.scope_labels()
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.resolver.deadline()
+    }
+
+    fn scope_id(&self) -> Option<u64> {
+        self.resolver.scope_id()
+    }
+
+    fn provider(&self) -> Option<&crate::ServiceProvider> {
+        self.resolver.provider()
+    }
+
+    fn share_scope(&self) -> Option<crate::Scope> {
+        self.resolver.share_scope()
+    }
+
     fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
-        self.resolver.push_sync_disposer(f);
+        match &self.owner {
+            Some((scope, key)) => scope.scoped_disposers.lock().unwrap().push_sync_owned(key.clone(), 0, f),
+            None => self.resolver.push_sync_disposer(f),
+        }
+    }
+
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        match &self.owner {
+            Some((scope, key)) => scope.scoped_disposers.lock().unwrap().push_sync_owned(key.clone(), priority, f),
+            None => self.resolver.push_sync_disposer_with_priority(priority, f),
+        }
     }
 
     fn push_async_disposer(&self, f: Box<dyn FnOnce() -> crate::internal::BoxFutureUnit + Send>) {
-        self.resolver.push_async_disposer(f);
+        match &self.owner {
+            Some((scope, key)) => scope.scoped_disposers.lock().unwrap().push_async_owned(key.clone(), f),
+            None => self.resolver.push_async_disposer(f),
+        }
     }
 }
 
@@ -68,16 +154,13 @@ impl<'a> Resolver for ResolverContext<'a> {
     where
         T: crate::traits::Dispose + 'static,
     {
-        self.resolver.push_sync_disposer(Box::new(move || service.dispose()));
+        self.push_sync_disposer(crate::traits::sync_hook(self.resolver.provider(), service));
     }
 
     fn register_async_disposer<T>(&self, service: std::sync::Arc<T>)
     where
         T: crate::traits::AsyncDispose + 'static,
     {
-        self.resolver.push_async_disposer(Box::new(move || {
-            let service = service.clone();
-            Box::pin(async move { service.dispose().await })
-        }));
+        self.push_async_disposer(crate::traits::async_hook(self.resolver.provider(), service));
     }
 }
\ No newline at end of file
@@ -3,8 +3,9 @@
 //! This module contains the ServiceProvider type and related functionality
 //! for resolving registered services from the DI container.
 
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{DiResult, DiError, Key, Lifetime};
 use crate::registration::{Registry, AnyArc};
@@ -12,15 +13,22 @@ use crate::internal::{DisposeBag, BoxFutureUnit, with_circular_catch};
 use crate::observer::{Observers, ObservationContext};
 use crate::capabilities::{CapabilityRegistry, ToolSelectionCriteria, ToolDiscoveryResult, ToolInfo};
 use crate::fast_singletons::FastSingletonCache;
-use crate::traits::{Resolver, ResolverCore, Dispose, AsyncDispose};
+use crate::prewarm::PrewarmSet;
+use crate::traits::{Resolver, ResolverCore, Dispose, AsyncDispose, DisposalSummary};
 
 // Re-export Scope and ResolverContext
 pub mod scope;
 pub mod context;
+pub mod restricted;
 pub use scope::*;
 pub use context::ResolverContext;
+pub use restricted::RestrictedScope;
 use context::ResolverContext as LocalResolverContext;
 
+/// Finished throwaway scopes with async disposers that are left for
+/// `dispose_all` before reaping runs them detached.
+const EPHEMERAL_ASYNC_BACKLOG: usize = 64;
+
 /// Service provider for resolving dependencies from the DI container.
 ///
 /// The `ServiceProvider` is the heart of the dependency injection system. It resolves
@@ -64,6 +72,44 @@ pub struct ServiceProvider {
     inner: Arc<ProviderInner>,
 }
 
+/// Non-owning handle to a [`ServiceProvider`], held by disposal hooks that
+/// live inside the provider itself.
+#[derive(Clone)]
+pub(crate) struct WeakProvider(Weak<ProviderInner>);
+
+impl WeakProvider {
+    pub(crate) fn upgrade(&self) -> Option<ServiceProvider> {
+        self.0.upgrade().map(|inner| ServiceProvider { inner })
+    }
+}
+
+/// Scoped slot utilization, returned by [`ServiceProvider::scoped_slot_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopedSlotStats {
+    /// Slots reserved in every scope, one per scoped registration
+    pub total_slots: usize,
+    /// Most slots filled by any scope that has been dropped so far
+    pub typically_used: usize,
+}
+
+/// What happens when a scoped service is resolved from the root provider.
+///
+/// Set with [`ServiceCollection::scoped_from_root_policy`](crate::ServiceCollection::scoped_from_root_policy)
+/// and read back with [`ServiceProvider::scoped_from_root_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScopedFromRootPolicy {
+    /// Fail with [`DiError::WrongLifetime`]
+    #[default]
+    Error,
+    /// Build the service in a throwaway scope, as if it were transient.
+    ///
+    /// The scope is not disposed when the returned instance is dropped: its
+    /// disposers run at the next root resolution of a scoped service after
+    /// that, or at [`ServiceProvider::dispose_all`], whichever comes first.
+    /// A scope with async disposers is always left to `dispose_all`.
+    EphemeralScope,
+}
+
 pub(crate) struct ProviderInner {
     pub registry: Registry,
     pub singletons: Mutex<HashMap<Key, AnyArc>>, // Legacy cache for multi-bindings
@@ -71,6 +117,28 @@ pub(crate) struct ProviderInner {
     pub root_disposers: Mutex<DisposeBag>,
     pub observers: Observers,
     pub capabilities: CapabilityRegistry,
+    pub prewarm: PrewarmSet,
+    pub singleton_origins: Mutex<HashMap<Key, u64>>, // Scope id a singleton was first created in
+    pub expensive: HashSet<&'static str>, // Services marked via mark_expensive
+    pub multi_singleton_groups: HashMap<&'static str, OnceLock<Vec<AnyArc>>>, // Lock-free cache for all-singleton multi-bindings
+    pub live_scopes: AtomicUsize, // Scopes created and not yet dropped
+    pub max_scoped_slots_used: AtomicUsize, // Most scoped slots filled by any dropped scope
+    pub build_warnings: Vec<DiError>, // Lint findings collected during build()
+    pub tracked_scopes: Mutex<Vec<scope::TrackedScope>>, // Scopes passed to register_scope_for_tracking
+    pub dispose_observers: Arc<[Arc<dyn crate::DisposeObserver>]>, // Notified as each disposal hook runs
+    pub disposed_keys: Arc<Mutex<HashSet<Key>>>, // Services whose disposal hook has run, refused by DisposeContext
+    pub service_labels: crate::service_labels::ServiceLabels, // Typed labels attached with ServiceCollection::label
+    pub key_rewriters: Vec<Arc<dyn crate::KeyRewriter>>, // Consulted before each single-service resolution
+    pub fallback: OnceLock<ServiceProvider>, // Parent that resolves keys this provider has no registration for
+    pub scoped_from_root: ScopedFromRootPolicy, // How scoped services resolved from the root are handled
+    pub memory_probes: HashMap<Key, crate::metrics::SizeProbe>, // Singletons opted into singleton_memory_report
+    pub ephemeral_scopes: Mutex<Vec<(Weak<dyn std::any::Any + Send + Sync>, DisposeBag)>>, // Disposers of throwaway scopes, by the instance they were built for
+    #[cfg(feature = "async")]
+    pub async_depth_limit: Option<(usize, crate::AsyncDepthPolicy)>, // Nesting limit for async factories resolved synchronously
+    #[cfg(test)]
+    pub singleton_lock_count: std::sync::atomic::AtomicUsize,
+    #[cfg(test)]
+    pub registry_lookup_count: std::sync::atomic::AtomicUsize,
 }
 
 impl ServiceProvider {
@@ -80,6 +148,50 @@ impl ServiceProvider {
         &self.inner
     }
 
+    /// Applies the key rewriters to `key`, following the chain until no
+    /// rewriter applies. Returns `None` when the key is left alone.
+    #[inline]
+    pub(crate) fn rewrite_key(&self, key: &Key) -> DiResult<Option<Key>> {
+        let rewriters = &self.inner().key_rewriters;
+        if rewriters.is_empty() {
+            return Ok(None);
+        }
+        let mut path = vec![key.clone()];
+        while let Some(next) = rewriters.iter().find_map(|r| r.rewrite(path.last().unwrap())) {
+            if next == *path.last().unwrap() {
+                break;
+            }
+            let revisits = path.contains(&next);
+            path.push(next);
+            if revisits {
+                return Err(DiError::KeyRewriteLoop(path));
+            }
+        }
+        Ok(if path.len() > 1 { path.pop() } else { None })
+    }
+
+    /// Returns the fallback provider if `key` has no registration here.
+    #[inline]
+    pub(crate) fn fallback_for(&self, key: &Key) -> Option<&ServiceProvider> {
+        let parent = self.inner().fallback.get()?;
+        let registry = &self.inner().registry;
+        let registered = registry.contains_key(key)
+            || matches!(key, Key::Trait(name) if registry.many.contains_key(name));
+        (!registered).then_some(parent)
+    }
+
+    /// Returns the fallback provider if trait `name` has no multi-bindings here.
+    #[inline]
+    pub(crate) fn fallback_for_many(&self, name: &str) -> Option<&ServiceProvider> {
+        let parent = self.inner().fallback.get()?;
+        (!self.inner().registry.many.contains_key(name)).then_some(parent)
+    }
+
+    /// Handle that doesn't keep the provider alive.
+    pub(crate) fn downgrade(&self) -> WeakProvider {
+        WeakProvider(Arc::downgrade(&self.inner))
+    }
+
     /// Creates a new scope for resolving scoped services.
     ///
     /// Scoped services are cached per scope and are ideal for request-scoped
@@ -125,30 +237,171 @@ impl ServiceProvider {
     /// assert!(!Arc::ptr_eq(&req1a, &req2)); // Different scopes, different instances
     /// ```
     pub fn create_scope(&self) -> Scope {
-        #[cfg(feature = "once-cell")]
-        {
-            use once_cell::sync::OnceCell;
-            
-            let scoped_count = self.inner().registry.scoped_count;
-            let scoped_cells: Box<[OnceCell<AnyArc>]> = (0..scoped_count)
-                .map(|_| OnceCell::new())
-                .collect::<Vec<_>>()
-                .into_boxed_slice();
-                
-            Scope {
-                root: self.clone(),
-                scoped_cells,
-                scoped_disposers: Mutex::new(DisposeBag::default()),
+        Scope::new(self.clone())
+    }
+
+    /// Returns a resolver that stamps `trace_id` onto every resolution made
+    /// through it, for correlating observer output with request logs.
+    ///
+    /// See [`TracedResolver`](crate::TracedResolver). Resolutions made
+    /// directly on the provider carry no trace id.
+    pub fn with_trace_id(&self, trace_id: &str) -> crate::TracedResolver {
+        crate::TracedResolver::new(self.clone(), trace_id)
+    }
+
+    /// Delegates resolutions this provider has no registration for to `parent`.
+    ///
+    /// Suits plugin architectures: a plugin's provider registers its own
+    /// services and overrides, and falls back to the host for everything
+    /// shared. Services resolved through the parent come from the parent's
+    /// caches, so parent singletons are shared between the two. Scopes of
+    /// this provider delegate to the parent provider itself, so scoped
+    /// services of the parent are not reachable through the fallback.
+    ///
+    /// Multi-bindings fall back as a whole: [`get_all_trait`](crate::Resolver::get_all_trait)
+    /// returns the parent's bindings only when this provider has none for
+    /// the trait, and never merges the two lists.
+    ///
+    /// The fallback is stored in state shared by every clone of this
+    /// provider and by its scopes, so attaching it affects all of them,
+    /// including handles created before the call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fallback was already attached, or if `parent` falls back
+    /// to this provider, directly or through its own fallbacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct HttpClient;
+    /// struct PluginConfig { name: &'static str }
+    ///
+    /// let mut host = ServiceCollection::new();
+    /// host.add_singleton(HttpClient);
+    /// host.add_singleton(PluginConfig { name: "host" });
+    /// let host = host.build();
+    ///
+    /// let mut plugin = ServiceCollection::new();
+    /// plugin.add_singleton(PluginConfig { name: "plugin" });
+    /// let plugin = plugin.build().with_fallback(host.clone());
+    ///
+    /// assert_eq!(plugin.get_required::<PluginConfig>().name, "plugin");
+    /// assert!(std::sync::Arc::ptr_eq(
+    ///     &plugin.get_required::<HttpClient>(),
+    ///     &host.get_required::<HttpClient>(),
+    /// ));
+    /// ```
+    pub fn with_fallback(self, parent: ServiceProvider) -> Self {
+        let mut ancestor = Some(&parent);
+        while let Some(provider) = ancestor {
+            if Arc::ptr_eq(&provider.inner, &self.inner) {
+                panic!("fallback would form a cycle");
             }
+            ancestor = provider.inner().fallback.get();
         }
-        
-        #[cfg(not(feature = "once-cell"))]
-        {
-            Scope {
-                root: self.clone(),
-                scoped: Mutex::new(HashMap::new()),
-                scoped_disposers: Mutex::new(DisposeBag::default()),
-            }
+        if self.inner().fallback.set(parent).is_err() {
+            panic!("provider already has a fallback");
+        }
+        self
+    }
+
+    /// Returns the number of scopes created from this provider that are still alive.
+    ///
+    /// A count that keeps growing in a long-running server usually means scopes
+    /// are being leaked (kept in a map, captured by a task that never ends).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::ServiceCollection;
+    ///
+    /// let provider = ServiceCollection::new().build();
+    /// let scope = provider.create_scope();
+    /// assert_eq!(provider.live_scope_count(), 1);
+    /// drop(scope);
+    /// assert_eq!(provider.live_scope_count(), 0);
+    /// ```
+    pub fn live_scope_count(&self) -> usize {
+        self.inner().live_scopes.load(Ordering::Relaxed)
+    }
+
+    /// Number of observers registered with
+    /// [`ServiceCollection::add_observer`](crate::ServiceCollection::add_observer).
+    ///
+    /// Zero explains a silent trace; a count higher than expected explains
+    /// doubled log lines.
+    pub fn observer_count(&self) -> usize {
+        self.inner().observers.len()
+    }
+
+    /// Names of the registered observers, in registration order.
+    ///
+    /// Each entry is the observer's [`DiObserver::name`](crate::DiObserver::name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{MetricsObserver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_observer(Arc::new(MetricsObserver::new()));
+    ///
+    /// let provider = services.build();
+    /// assert_eq!(provider.observer_count(), 1);
+    /// assert_eq!(provider.observer_names(), ["ferrous_di::observer::MetricsObserver"]);
+    /// ```
+    pub fn observer_names(&self) -> Vec<&'static str> {
+        self.inner().observers.names()
+    }
+
+    /// Keys of the services labeled with `label` via
+    /// [`ServiceCollection::label`](crate::ServiceCollection::label), in
+    /// [`Key`] order.
+    ///
+    /// Multi-bindings are reported as [`Key::MultiTrait`].
+    pub fn services_with_label<L>(&self, label: L) -> Vec<Key>
+    where
+        L: Eq + std::hash::Hash + 'static,
+    {
+        self.inner().service_labels.keys_with(&label)
+    }
+
+    /// Reports how many scoped slots each scope allocates and how many were
+    /// actually filled.
+    ///
+    /// Every scope reserves one slot per scoped registration. When
+    /// `typically_used` stays far below `total_slots`, most scoped services
+    /// are rarely resolved and could move to a separate, lighter scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct Session;
+    /// struct AuditLog;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_factory::<Session, _>(|_| Session);
+    /// services.add_scoped_factory::<AuditLog, _>(|_| AuditLog);
+    ///
+    /// let provider = services.build();
+    /// let scope = provider.create_scope();
+    /// scope.get_required::<Session>();
+    /// drop(scope);
+    ///
+    /// let stats = provider.scoped_slot_stats();
+    /// assert_eq!(stats.total_slots, 2);
+    /// assert_eq!(stats.typically_used, 1);
+    /// ```
+    pub fn scoped_slot_stats(&self) -> ScopedSlotStats {
+        ScopedSlotStats {
+            total_slots: self.inner().registry.scoped_count,
+            typically_used: self.inner().max_scoped_slots_used.load(Ordering::Relaxed),
         }
     }
 
@@ -158,6 +411,9 @@ impl ServiceProvider {
     /// followed by all synchronous disposal hooks (in reverse order). This ensures
     /// proper cleanup of singleton services.
     ///
+    /// Returns a [`DisposalSummary`] with the number of hooks that ran and any
+    /// hooks that panicked. A panicking hook does not stop the others.
+    ///
     /// # Examples
     ///
     /// ```
@@ -198,13 +454,370 @@ impl ServiceProvider {
     /// provider.dispose_all().await;
     /// # }
     /// ```
-    pub async fn dispose_all(&self) {
+    pub async fn dispose_all(&self) -> DisposalSummary {
+        let mut summary = DisposalSummary::default();
+        // Throwaway scopes from ScopedFromRootPolicy::EphemeralScope go first
+        let ephemeral = std::mem::take(&mut *self.inner().ephemeral_scopes.lock().unwrap());
+        for (_, mut bag) in ephemeral.into_iter().rev() {
+            summary.merge(bag.run_all_async_reverse().await);
+            summary.merge(bag.run_all_sync_reverse());
+        }
+        // Take the hooks out so the lock isn't held across await points
+        let mut bag = std::mem::take(&mut *self.inner().root_disposers.lock().unwrap());
         // First run async disposers in reverse order
-        self.inner().root_disposers.lock().unwrap().run_all_async_reverse().await;
-        // Then run sync disposers in reverse order  
-        self.inner().root_disposers.lock().unwrap().run_all_sync_reverse();
+        summary.merge(bag.run_all_async_reverse().await);
+        // Then run sync disposers in reverse order
+        summary.merge(bag.run_all_sync_reverse());
+        summary
+    }
+
+    /// Tracks `scope` so that [`dispose_all_scopes`](Self::dispose_all_scopes)
+    /// disposes it on shutdown.
+    ///
+    /// Only weak references to the scope's state are kept, so tracking
+    /// doesn't keep a finished scope's resources alive.
+    pub fn register_scope_for_tracking(&self, scope: &Scope) {
+        let mut tracked = self.inner().tracked_scopes.lock().unwrap();
+        tracked.retain(scope::TrackedScope::is_alive);
+        tracked.push(scope::TrackedScope::new(scope));
+    }
+
+    /// Disposes every tracked scope that is still alive, in the order they
+    /// were registered, and then the root provider.
+    ///
+    /// Each scope is disposed like [`Scope::dispose_all`], including being
+    /// marked disposed, so its scoped services fail with
+    /// [`DiError::ScopeDisposed`] afterwards. The returned summary covers all
+    /// scopes and the root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Dispose, Resolver};
+    /// use std::sync::Arc;
+    ///
+    /// struct Session;
+    /// impl Dispose for Session {
+    ///     fn dispose(&self) { /* flush */ }
+    /// }
+    ///
+    /// # async fn example() {
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_factory::<Session, _>(|r| {
+    ///     let session = Arc::new(Session);
+    ///     r.register_disposer(session.clone());
+    ///     Session
+    /// });
+    /// let provider = services.build();
+    ///
+    /// let request = provider.create_scope();
+    /// provider.register_scope_for_tracking(&request);
+    /// request.get_required::<Session>();
+    ///
+    /// // On shutdown
+    /// let summary = provider.dispose_all_scopes().await;
+    /// assert_eq!(summary.sync_run, 1);
+    /// # }
+    /// ```
+    pub async fn dispose_all_scopes(&self) -> DisposalSummary {
+        let tracked = std::mem::take(&mut *self.inner().tracked_scopes.lock().unwrap());
+        let mut summary = DisposalSummary::default();
+        for mut bag in tracked.iter().filter_map(scope::TrackedScope::begin_disposal) {
+            summary.merge(bag.run_all_async_reverse().await);
+            summary.merge(bag.run_all_sync_reverse());
+        }
+        summary.merge(self.dispose_all().await);
+        summary
     }
     
+    /// Resolves all implementations of a trait, grouped by a tag value.
+    ///
+    /// Tags come from [`ServiceCollection::add_trait_implementation_tagged`].
+    /// Implementations without a value for `tag_key` are left out. Within a
+    /// group, implementations keep their registration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Lifetime};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {}
+    /// struct FileReader;
+    /// struct FileWriter;
+    /// impl Tool for FileReader {}
+    /// impl Tool for FileWriter {}
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation_tagged::<dyn Tool>(Arc::new(FileReader), Lifetime::Singleton, &[("category", "read")]);
+    /// services.add_trait_implementation_tagged::<dyn Tool>(Arc::new(FileWriter), Lifetime::Singleton, &[("category", "write")]);
+    ///
+    /// let provider = services.build();
+    /// let groups = provider.get_all_trait_grouped::<dyn Tool>("category").unwrap();
+    /// assert_eq!(groups["read"].len(), 1);
+    /// assert_eq!(groups["write"].len(), 1);
+    /// ```
+    ///
+    /// [`ServiceCollection::add_trait_implementation_tagged`]: crate::ServiceCollection::add_trait_implementation_tagged
+    pub fn get_all_trait_grouped<T: ?Sized + 'static + Send + Sync>(
+        &self,
+        tag_key: &str,
+    ) -> DiResult<HashMap<String, Vec<Arc<T>>>> {
+        let name = std::any::type_name::<T>();
+        let implementations = self.get_all_trait::<T>()?;
+        let regs = match self.inner().registry.many.get(name) {
+            Some(regs) => regs,
+            None => return Ok(HashMap::new()),
+        };
+
+        let mut groups: HashMap<String, Vec<Arc<T>>> = HashMap::new();
+        for (reg, implementation) in regs.iter().zip(implementations) {
+            let tag = reg.metadata.as_ref()
+                .and_then(|m| m.downcast_ref::<HashMap<String, String>>())
+                .and_then(|tags| tags.get(tag_key));
+            if let Some(tag) = tag {
+                groups.entry(tag.clone()).or_default().push(implementation);
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Resolves every named single binding of a trait, keyed by name.
+    ///
+    /// Collects the registrations made with
+    /// [`add_named_singleton_trait`](crate::ServiceCollection::add_named_singleton_trait)
+    /// and its siblings. Unlike [`get_all_trait`](crate::Resolver::get_all_trait),
+    /// which only sees multi-bindings and drops names, this is suited to
+    /// dispatch tables that look implementations up by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::ServiceCollection;
+    /// use std::sync::Arc;
+    ///
+    /// trait Handler: Send + Sync {}
+    /// struct Ping;
+    /// impl Handler for Ping {}
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_named_singleton_trait::<dyn Handler>("ping", Arc::new(Ping));
+    ///
+    /// let provider = services.build();
+    /// let handlers = provider.get_named_map::<dyn Handler>().unwrap();
+    /// assert!(handlers.contains_key("ping"));
+    /// ```
+    pub fn get_named_map<T: ?Sized + 'static + Send + Sync>(
+        &self,
+    ) -> DiResult<HashMap<String, Arc<T>>> {
+        let trait_name = std::any::type_name::<T>();
+        let names: Vec<&'static str> = self.inner().registry.iter()
+            .filter_map(|(key, _)| match key {
+                Key::TraitNamed(name, service) if *name == trait_name => Some(*service),
+                _ => None,
+            })
+            .collect();
+
+        names.into_iter()
+            .map(|name| Ok((name.to_string(), self.get_named_trait::<T>(name)?)))
+            .collect()
+    }
+
+    /// Resolves a trait service, awaiting its factory if it was registered with
+    /// [`ServiceCollection::add_singleton_trait_async`] or a sibling.
+    ///
+    /// Services registered synchronously resolve as they would through
+    /// [`get_trait`](crate::Resolver::get_trait).
+    ///
+    /// [`ServiceCollection::add_singleton_trait_async`]: crate::ServiceCollection::add_singleton_trait_async
+    #[cfg(feature = "async")]
+    pub async fn get_trait_async<T: ?Sized + 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let name = std::any::type_name::<T>();
+        let any = self.resolve_any_async(&Key::Trait(name)).await?;
+        any.downcast::<Arc<T>>()
+            .map(|boxed| (*boxed).clone())
+            .map_err(|_| DiError::TypeMismatch(name))
+    }
+
+    /// Like [`get_trait_async`](Self::get_trait_async), but panics if the service can't be resolved.
+    #[cfg(feature = "async")]
+    pub async fn get_required_trait_async<T: ?Sized + 'static + Send + Sync>(&self) -> Arc<T> {
+        self.get_trait_async::<T>()
+            .await
+            .unwrap_or_else(|e| panic!("Failed to resolve trait {}: {:?}", std::any::type_name::<T>(), e))
+    }
+
+    /// Spawns a task that resolves `T`, awaiting its async factory if it has
+    /// one, and then runs `work` with the instance.
+    ///
+    /// The task owns a clone of the provider, so nothing borrowed from the
+    /// caller is held across an await, and the bounds require everything
+    /// `work` captures or produces to be `Send`. Resolution errors are
+    /// returned through the join handle instead of running `work`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::ServiceCollection;
+    /// use std::sync::Arc;
+    ///
+    /// struct Index { docs: usize }
+    ///
+    /// # async fn example() {
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Index { docs: 3 });
+    /// let provider = services.build();
+    ///
+    /// let handle = provider.spawn_resolving::<Index, _, _>(|index| async move { index.docs * 2 });
+    /// assert_eq!(handle.await.unwrap().unwrap(), 6);
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn spawn_resolving<T, F, Fut>(&self, work: F) -> tokio::task::JoinHandle<DiResult<Fut::Output>>
+    where
+        T: 'static + Send + Sync,
+        F: FnOnce(Arc<T>) -> Fut + Send + 'static,
+        Fut: std::future::Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        let provider = self.clone();
+        tokio::spawn(async move {
+            let name = std::any::type_name::<T>();
+            let service = provider.resolve_any_async(&Key::Type(std::any::TypeId::of::<T>(), name))
+                .await?
+                .downcast::<T>()
+                .map_err(|_| DiError::TypeMismatch(name))?;
+            Ok(work(service).await)
+        })
+    }
+
+    /// Lazily resolves the implementations of a multi-bound trait, one per item.
+    ///
+    /// Unlike [`get_all_trait`](crate::Resolver::get_all_trait), factories only
+    /// run as the iterator is advanced, so `.find()` or `.take_while()` over a
+    /// large registry doesn't instantiate implementations it never reaches.
+    /// Singletons are cached exactly as they are for `get_all_trait`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Lifetime};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    /// struct Search;
+    /// struct Browse;
+    /// impl Tool for Search { fn name(&self) -> &str { "search" } }
+    /// impl Tool for Browse { fn name(&self) -> &str { "browse" } }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation(Arc::new(Search) as Arc<dyn Tool>, Lifetime::Singleton);
+    /// services.add_trait_implementation(Arc::new(Browse) as Arc<dyn Tool>, Lifetime::Singleton);
+    ///
+    /// let provider = services.build();
+    /// let found = provider.resolve_trait_stream::<dyn Tool>()
+    ///     .find(|tool| tool.as_ref().is_ok_and(|t| t.name() == "search"));
+    /// assert!(found.is_some());
+    /// ```
+    pub fn resolve_trait_stream<T: ?Sized + 'static + Send + Sync>(
+        &self,
+    ) -> impl Iterator<Item = DiResult<Arc<T>>> + '_ {
+        let name = std::any::type_name::<T>();
+        let regs = self.inner().registry.many.get(name).map_or(&[][..], Vec::as_slice);
+
+        regs.iter().enumerate().map(move |(i, reg)| {
+            let any = with_circular_catch(name, || self.resolve_multi_at(name, i, reg))?;
+            any.downcast::<Arc<T>>()
+                .map(|boxed| (*boxed).clone())
+                .map_err(|_| DiError::TypeMismatch(name))
+        })
+    }
+
+    /// Resolves the `k` most recently registered implementations of a
+    /// multi-bound trait, newest first.
+    ///
+    /// Suits layered defaults where later, more specific registrations take
+    /// precedence. Only the returned implementations are constructed; fewer
+    /// than `k` are returned if fewer are registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Lifetime};
+    /// use std::sync::Arc;
+    ///
+    /// trait Layer: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    /// struct Named(&'static str);
+    /// impl Layer for Named { fn name(&self) -> &str { self.0 } }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation(Arc::new(Named("defaults")) as Arc<dyn Layer>, Lifetime::Singleton);
+    /// services.add_trait_implementation(Arc::new(Named("site")) as Arc<dyn Layer>, Lifetime::Singleton);
+    ///
+    /// let provider = services.build();
+    /// let top = provider.get_trait_top::<dyn Layer>(1).unwrap();
+    /// assert_eq!(top[0].name(), "site");
+    /// ```
+    pub fn get_trait_top<T: ?Sized + 'static + Send + Sync>(&self, k: usize) -> DiResult<Vec<Arc<T>>> {
+        let name = std::any::type_name::<T>();
+        let regs = self.inner().registry.many.get(name).map_or(&[][..], Vec::as_slice);
+
+        regs.iter().enumerate().rev().take(k)
+            .map(|(i, reg)| {
+                let any = with_circular_catch(name, || self.resolve_multi_at(name, i, reg))?;
+                any.downcast::<Arc<T>>()
+                    .map(|boxed| (*boxed).clone())
+                    .map_err(|_| DiError::TypeMismatch(name))
+            })
+            .collect()
+    }
+
+    /// Returns the first implementation of a multi-bound trait that matches
+    /// `predicate`, in registration order.
+    ///
+    /// Built on [`resolve_trait_stream`](Self::resolve_trait_stream), so
+    /// implementations registered after the match are never constructed.
+    /// Implementations that fail to resolve are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Lifetime};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    /// struct ReadFile;
+    /// struct Search;
+    /// impl Tool for ReadFile { fn name(&self) -> &str { "fs.read" } }
+    /// impl Tool for Search { fn name(&self) -> &str { "search" } }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation(Arc::new(ReadFile) as Arc<dyn Tool>, Lifetime::Singleton);
+    /// services.add_trait_implementation(Arc::new(Search) as Arc<dyn Tool>, Lifetime::Singleton);
+    ///
+    /// let provider = services.build();
+    /// let tool = provider.find_trait::<dyn Tool>(|t| t.name() == "fs.read").unwrap();
+    /// assert_eq!(tool.name(), "fs.read");
+    /// ```
+    pub fn find_trait<T: ?Sized + 'static + Send + Sync>(
+        &self,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Option<Arc<T>> {
+        self.resolve_trait_stream::<T>()
+            .filter_map(Result::ok)
+            .find(|service| predicate(service))
+    }
+
     #[cfg(feature = "diagnostics")]
     pub fn to_debug_string(&self) -> String {
         let mut s = String::new();
@@ -247,12 +860,20 @@ impl Drop for ServiceProvider {
 
 impl ResolverCore for ServiceProvider {
     fn resolve_any(&self, key: &Key) -> DiResult<AnyArc> {
+        let rewritten = self.rewrite_key(key)?;
+        let key = rewritten.as_ref().unwrap_or(key);
+        if let Some(parent) = self.fallback_for(key) {
+            return parent.resolve_any(key);
+        }
         let name = key.display_name();
         with_circular_catch(name, || self.resolve_any_impl(key))
     }
     
     fn resolve_many(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
-        if let Key::Trait(_trait_name) = key {
+        if let Key::Trait(trait_name) = key {
+            if let Some(parent) = self.fallback_for_many(trait_name) {
+                return parent.resolve_many(key);
+            }
             let name = key.display_name();
             with_circular_catch(name, || self.resolve_many_impl(key))
         } else {
@@ -260,10 +881,24 @@ impl ResolverCore for ServiceProvider {
         }
     }
 
+    fn cache_status(&self, key: &Key) -> Option<(Lifetime, bool)> {
+        let (_, reg) = self.inner().registry.alias_target(key)?;
+        let cached = reg.lifetime == Lifetime::Singleton && reg.cached_singleton().is_some();
+        Some((reg.lifetime, cached))
+    }
+
+    fn provider(&self) -> Option<&ServiceProvider> {
+        Some(self)
+    }
+
     fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
         self.inner().root_disposers.lock().unwrap().push_sync(f);
     }
 
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.inner().root_disposers.lock().unwrap().push_sync_with_priority(priority, f);
+    }
+
     fn push_async_disposer(&self, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>) {
         self.inner().root_disposers.lock().unwrap().push_async(move || (f)());
     }
@@ -297,7 +932,18 @@ impl ServiceProvider {
 
     /// Ultra-optimized singleton resolution using embedded OnceCell
     #[inline(always)]
-    pub(crate) fn resolve_singleton(&self, reg: &crate::registration::Registration, _key: &Key) -> DiResult<AnyArc> {
+    pub(crate) fn resolve_singleton(&self, reg: &crate::registration::Registration, key: &Key) -> DiResult<AnyArc> {
+        self.resolve_singleton_from(reg, key, None)
+    }
+
+    /// Singleton resolution that remembers the scope (if any) that triggered creation.
+    #[inline(always)]
+    pub(crate) fn resolve_singleton_from(
+        &self,
+        reg: &crate::registration::Registration,
+        key: &Key,
+        origin: Option<u64>,
+    ) -> DiResult<AnyArc> {
         #[cfg(feature = "once-cell")]
         {
             if let Some(cell) = &reg.single_runtime {
@@ -311,7 +957,10 @@ impl ServiceProvider {
                 {
                     let ctx = LocalResolverContext::new(self);
                     let v = (reg.ctor)(&ctx)?;
-                    let stored = cell.get_or_init(|| v.clone()).clone();
+                    let stored = cell.get_or_init(|| {
+                        self.record_singleton_origin(key, origin);
+                        v.clone()
+                    }).clone();
                     return Ok(stored);
                 }
             }
@@ -320,31 +969,197 @@ impl ServiceProvider {
         #[cfg(not(feature = "once-cell"))]
         {
             if let Some(mutex) = &reg.single_runtime {
-                let mut guard = mutex.lock().unwrap();
+                // A factory that panicked while holding the lock left the slot
+                // empty, so recovering simply retries the factory
+                let mut guard = mutex.lock().unwrap_or_else(PoisonError::into_inner);
                 if let Some(value) = guard.as_ref() {
                     return Ok(value.clone());
                 }
                 
                 let ctx = LocalResolverContext::new(self);
                 let value = (reg.ctor)(&ctx)?;
+                self.record_singleton_origin(key, origin);
                 *guard = Some(value.clone());
                 return Ok(value);
             }
         }
         
-        // Fallback to old behavior if no single_runtime (shouldn't happen)
+        // Singletons without a cache cell, such as reactive ones, hold their
+        // own current value and are asked for it on every resolution
         let ctx = LocalResolverContext::new(self);
         (reg.ctor)(&ctx)
     }
-    
-    /// Creates observation context from available scope-local data.
-    fn create_observation_context(&self) -> ObservationContext {
+
+    /// Caches a singleton created outside `resolve_singleton_from` (e.g. by an
+    /// async factory), returning the instance that won if another was stored first.
+    #[cfg(feature = "async")]
+    pub(crate) fn store_singleton(
+        &self,
+        reg: &crate::registration::Registration,
+        key: &Key,
+        value: AnyArc,
+        origin: Option<u64>,
+    ) -> AnyArc {
+        #[cfg(feature = "once-cell")]
+        {
+            if let Some(cell) = &reg.single_runtime {
+                return cell.get_or_init(|| {
+                    self.record_singleton_origin(key, origin);
+                    value
+                }).clone();
+            }
+        }
+
+        #[cfg(not(feature = "once-cell"))]
+        {
+            if let Some(mutex) = &reg.single_runtime {
+                let mut guard = mutex.lock().unwrap_or_else(PoisonError::into_inner);
+                if let Some(existing) = guard.as_ref() {
+                    return existing.clone();
+                }
+                self.record_singleton_origin(key, origin);
+                *guard = Some(value.clone());
+            }
+        }
+
+        value
+    }
+
+    /// Resolves `key`, awaiting its async constructor if it was registered with one.
+    ///
+    /// Registrations without an async constructor resolve synchronously as usual.
+    #[cfg(feature = "async")]
+    pub(crate) async fn resolve_any_async(&self, key: &Key) -> DiResult<AnyArc> {
+        let rewritten = self.rewrite_key(key)?;
+        let key = rewritten.as_ref().unwrap_or(key);
+        let reg = match self.inner().registry.get(key) {
+            Some(reg) => reg,
+            None => return self.resolve_any(key),
+        };
+        if let Some(alias) = &reg.alias {
+            let value = Box::pin(self.resolve_any_async(&alias.target)).await?;
+            return (alias.cast)(value);
+        }
+        let Some(async_ctor) = &reg.async_ctor else {
+            return self.resolve_any(key);
+        };
+
+        self.report_deprecation(reg, key);
+        if let Some(flag) = reg.feature_gate {
+            Self::check_feature_gate(self, flag)?;
+        }
+        match reg.lifetime {
+            Lifetime::Singleton => match reg.cached_singleton() {
+                Some(value) => Ok(value),
+                None => {
+                    let value = self.catch_factory_panic(key, reg.lifetime, async_ctor(self)).await?;
+                    Ok(self.store_singleton(reg, key, value, None))
+                }
+            },
+            Lifetime::Scoped => {
+                if self.inner().scoped_from_root == ScopedFromRootPolicy::Error {
+                    return Err(DiError::WrongLifetime("Cannot resolve scoped service from root provider"));
+                }
+                self.reap_ephemeral_scopes();
+                let scope = self.create_scope();
+                let result = self.catch_factory_panic(key, reg.lifetime, async_ctor(&scope)).await;
+                self.track_ephemeral_scope(scope, result)
+            }
+            Lifetime::Transient => self.catch_factory_panic(key, reg.lifetime, async_ctor(self)).await,
+        }
+    }
+
+    /// Awaits an async factory, turning a panic into [`DiError::FactoryPanicked`]
+    /// and reporting it to the `factory_panic` observers. A circular dependency
+    /// panic keeps unwinding so the enclosing resolution reports it as
+    /// [`DiError::Circular`].
+    #[cfg(feature = "async")]
+    pub(crate) async fn catch_factory_panic<F>(&self, key: &Key, lifetime: Lifetime, factory: F) -> DiResult<AnyArc>
+    where
+        F: std::future::Future<Output = DiResult<AnyArc>>,
+    {
+        use futures::FutureExt;
+
+        match std::panic::AssertUnwindSafe(factory).catch_unwind().await {
+            Ok(result) => result,
+            Err(payload) if payload.is::<crate::internal::CircularPanic>() => std::panic::resume_unwind(payload),
+            Err(payload) => {
+                let message = if let Some(s) = payload.downcast_ref::<&str>() {
+                    (*s).to_string()
+                } else if let Some(s) = payload.downcast_ref::<String>() {
+                    s.clone()
+                } else {
+                    "async factory panicked".to_string()
+                };
+                if self.inner().observers.has_observers() {
+                    let context = self.create_observation_context(lifetime);
+                    self.inner().observers.factory_panic_with_context(key, &message, &context);
+                }
+                Err(DiError::FactoryPanicked { key: key.clone(), message })
+            }
+        }
+    }
+
+    /// Records the scope a singleton was first created in. Root creations are not recorded.
+    pub(crate) fn record_singleton_origin(&self, key: &Key, origin: Option<u64>) {
+        if let Some(scope_id) = origin {
+            self.inner().singleton_origins.lock().unwrap().insert(key.clone(), scope_id);
+        }
+    }
+
+    /// Returns the id of the scope in which a singleton was first initialized.
+    ///
+    /// Returns `None` if the singleton was created from the root provider or
+    /// has not been created yet. A singleton whose first resolution happened
+    /// inside a scope deserves a second look: if its factory captured
+    /// anything scope-local, every later consumer shares that stale state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Resolver};
+    ///
+    /// struct Cache;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_factory::<Cache, _>(|_| Cache);
+    /// let provider = services.build();
+    ///
+    /// let scope = provider.create_scope();
+    /// let _ = scope.get_required::<Cache>();
+    ///
+    /// let key = ferrous_di::key_of_type::<Cache>();
+    /// assert_eq!(provider.singleton_init_origin(&key), Some(scope.id()));
+    /// ```
+    pub fn singleton_init_origin(&self, key: &Key) -> Option<u64> {
+        self.inner().singleton_origins.lock().unwrap().get(key).copied()
+    }
+
+    /// Creates observation context from available scope-local data, tagged
+    /// with the lifetime of the registration being resolved.
+    fn create_observation_context(&self, lifetime: Lifetime) -> ObservationContext {
+        let context = self.workflow_observation_context()
+            .with_metadata("lifetime", format!("{:?}", lifetime));
+        match crate::observer::current_trace_id() {
+            Some(trace_id) => context.with_metadata("trace_id", &*trace_id),
+            None => context,
+        }
+    }
+
+    fn workflow_observation_context(&self) -> ObservationContext {
         // Try to extract workflow context from scope-local storage
         // This allows rich observation when workflow context is available
         use crate::scope_local::{ScopeLocal, WorkflowContext};
         
-        // Attempt to get workflow context if available
-        if let Ok(workflow_ctx) = self.get::<ScopeLocal<WorkflowContext>>() {
+        // Resolved without notifying observers, which would otherwise recurse
+        // back into this method
+        let key = crate::key_of_type::<ScopeLocal<WorkflowContext>>();
+        let workflow_ctx = self.inner().registry.get(&key)
+            .filter(|reg| reg.lifetime == Lifetime::Singleton)
+            .and_then(|reg| self.resolve_singleton(reg, &key).ok())
+            .and_then(|any| any.downcast::<ScopeLocal<WorkflowContext>>().ok());
+
+        if let Some(workflow_ctx) = workflow_ctx {
             ObservationContext::workflow(
                 workflow_ctx.run_id(),
                 workflow_ctx.workflow_name(),
@@ -360,31 +1175,39 @@ impl ServiceProvider {
         let name = key.display_name();
         
         if let Some(reg) = self.inner().registry.get(key) {
+            self.report_deprecation(reg, key);
+            if let Some(flag) = reg.feature_gate {
+                Self::check_feature_gate(self, flag)?;
+            }
+            if let Some(alias) = &reg.alias {
+                return (alias.cast)(self.resolve_any(&alias.target)?);
+            }
             match reg.lifetime {
                 Lifetime::Singleton => {
                     // Observer support with optimized path
                     if self.inner().observers.has_observers() {
                         let start = std::time::Instant::now();
-                        let context = self.create_observation_context();
+                        let context = self.create_observation_context(reg.lifetime);
                         self.inner().observers.resolving_with_context(key, &context);
                         
                         let result = self.resolve_singleton(reg, key);
                         
                         let duration = start.elapsed();
-                        self.inner().observers.resolved_with_context(key, duration, &context);
+                        match &result {
+                            Ok(_) => self.inner().observers.resolved_with_context(key, duration, &context),
+                            Err(err) => self.inner().observers.resolution_failed_with_context(key, duration, err, &context),
+                        }
                         result
                     } else {
                         // Ultra-fast path: no observer overhead
                         self.resolve_singleton(reg, key)
                     }
                 }
-                Lifetime::Scoped => {
-                    Err(DiError::WrongLifetime("Cannot resolve scoped service from root provider"))
-                }
+                Lifetime::Scoped => self.resolve_scoped_from_root(reg),
                 Lifetime::Transient => {
                     if self.inner().observers.has_observers() {
                         let start = std::time::Instant::now();
-                        let context = self.create_observation_context();
+                        let context = self.create_observation_context(reg.lifetime);
                         self.inner().observers.resolving_with_context(key, &context);
                         
                         let ctx = LocalResolverContext::new(self);
@@ -395,9 +1218,9 @@ impl ServiceProvider {
                                 let duration = start.elapsed();
                                 self.inner().observers.resolved_with_context(key, duration, &context);
                             }
-                            Err(_) => {
+                            Err(err) => {
                                 let duration = start.elapsed();
-                                self.inner().observers.resolved_with_context(key, duration, &context);
+                                self.inner().observers.resolution_failed_with_context(key, duration, err, &context);
                             }
                         }
                         result
@@ -408,12 +1231,16 @@ impl ServiceProvider {
                 }
             }
         } else if let Key::Trait(trait_name) = key {
-            // Fallback: if trait has multi-bindings, return last as single
+            let candidates = self.inner().registry.trait_candidates(trait_name);
+            if candidates.len() > 1 {
+                return Err(DiError::AmbiguousRegistration { trait_name, candidates });
+            }
+            // Fallback: if trait has a single multi-binding, return it as single
             if let Some(regs) = self.inner().registry.many.get(trait_name) {
                 if let Some(last) = regs.last() {
                     if self.inner().observers.has_observers() {
                         let start = std::time::Instant::now();
-                        let context = self.create_observation_context();
+                        let context = self.create_observation_context(last.lifetime);
                         self.inner().observers.resolving_with_context(key, &context);
                         
                         let ctx = LocalResolverContext::new(self);
@@ -424,9 +1251,9 @@ impl ServiceProvider {
                                 let duration = start.elapsed();
                                 self.inner().observers.resolved_with_context(key, duration, &context);
                             }
-                            Err(_) => {
+                            Err(err) => {
                                 let duration = start.elapsed();
-                                self.inner().observers.resolved_with_context(key, duration, &context);
+                                self.inner().observers.resolution_failed_with_context(key, duration, err, &context);
                             }
                         }
                         result
@@ -447,51 +1274,22 @@ impl ServiceProvider {
     
     fn resolve_many_impl(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
         if let Key::Trait(trait_name) = key {
+            let group = self.inner().multi_singleton_groups.get(trait_name);
+            if let Some(cached) = group.and_then(|g| g.get()) {
+                return Ok(cached.clone());
+            }
+
             if let Some(regs) = self.inner().registry.many.get(trait_name) {
                 let mut results = Vec::with_capacity(regs.len());
                 
                 for (i, reg) in regs.iter().enumerate() {
-                    let multi_key = Key::MultiTrait(trait_name, i);
-                    
-                    let value = match reg.lifetime {
-                        Lifetime::Singleton => {
-                            // Expert fix: Double-checked locking - never hold lock while invoking factory
-                            {
-                                let cache = self.inner().singletons.lock().unwrap();
-                                if let Some(cached) = cache.get(&multi_key) {
-                                    results.push(cached.clone());
-                                    continue;
-                                }
-                            } // Lock released here
-                            
-                            // Create without holding lock
-                            let ctx = ResolverContext::new(self);
-                            let value = (reg.ctor)(&ctx)?;
-                            
-                            // Double-checked insert
-                            {
-                                let mut cache = self.inner().singletons.lock().unwrap();
-                                if let Some(cached) = cache.get(&multi_key) {
-                                    cached.clone() // Another thread beat us
-                                } else {
-                                    cache.insert(multi_key, value.clone());
-                                    value
-                                }
-                            }
-                        }
-                        Lifetime::Scoped => {
-                            return Err(DiError::WrongLifetime("Cannot resolve scoped service from root provider"));
-                        }
-                        Lifetime::Transient => {
-                            let ctx = ResolverContext::new(self);
-                            (reg.ctor)(&ctx)?
-                        }
-                    };
-                    
-                    results.push(value);
+                    results.push(self.resolve_multi_at(trait_name, i, reg)?);
                 }
                 
-                Ok(results)
+                match group {
+                    Some(group) => Ok(group.get_or_init(|| results).clone()),
+                    None => Ok(results),
+                }
             } else {
                 Ok(Vec::new())
             }
@@ -500,6 +1298,145 @@ impl ServiceProvider {
         }
     }
 
+    /// Resolves the `i`-th implementation of a multi-binding, caching singletons.
+    pub(crate) fn resolve_multi_at(
+        &self,
+        trait_name: &'static str,
+        i: usize,
+        reg: &crate::registration::Registration,
+    ) -> DiResult<AnyArc> {
+        let multi_key = Key::MultiTrait(trait_name, i);
+        
+        match reg.lifetime {
+            Lifetime::Singleton => {
+                // Expert fix: Double-checked locking - never hold lock while invoking factory
+                {
+                    let cache = self.lock_singletons();
+                    if let Some(cached) = cache.get(&multi_key) {
+                        return Ok(cached.clone());
+                    }
+                } // Lock released here
+                
+                // Create without holding lock
+                let ctx = ResolverContext::new(self);
+                let value = (reg.ctor)(&ctx)?;
+                
+                // Double-checked insert
+                let mut cache = self.lock_singletons();
+                if let Some(cached) = cache.get(&multi_key) {
+                    Ok(cached.clone()) // Another thread beat us
+                } else {
+                    cache.insert(multi_key, value.clone());
+                    Ok(value)
+                }
+            }
+            Lifetime::Scoped => self.resolve_scoped_from_root(reg),
+            Lifetime::Transient => {
+                let ctx = ResolverContext::new(self);
+                (reg.ctor)(&ctx)
+            }
+        }
+    }
+
+    /// Handles a scoped registration resolved from the root, per the
+    /// provider's [`ScopedFromRootPolicy`].
+    fn resolve_scoped_from_root(&self, reg: &crate::registration::Registration) -> DiResult<AnyArc> {
+        if self.inner().scoped_from_root == ScopedFromRootPolicy::Error {
+            return Err(DiError::WrongLifetime("Cannot resolve scoped service from root provider"));
+        }
+        self.reap_ephemeral_scopes();
+
+        // Built directly against the scope so the instance isn't cached in it
+        let scope = self.create_scope();
+        let result = (reg.ctor)(&ResolverContext::new(&scope));
+        self.track_ephemeral_scope(scope, result)
+    }
+
+    /// Keeps a throwaway scope's disposers for [`reap_ephemeral_scopes`](Self::reap_ephemeral_scopes)
+    /// to run once the instance built in it is gone. If building failed they
+    /// run right away, unless async hooks leave them to `dispose_all`.
+    fn track_ephemeral_scope(&self, scope: Scope, result: DiResult<AnyArc>) -> DiResult<AnyArc> {
+        let mut bag = std::mem::take(&mut *scope.scoped_disposers.lock().unwrap());
+        match result {
+            Ok(value) => {
+                self.inner().ephemeral_scopes.lock().unwrap().push((Arc::downgrade(&value), bag));
+                Ok(value)
+            }
+            Err(err) => {
+                if bag.has_async() {
+                    let gone: Weak<dyn std::any::Any + Send + Sync> = Weak::<()>::new();
+                    self.inner().ephemeral_scopes.lock().unwrap().push((gone, bag));
+                } else {
+                    bag.run_all_sync_reverse();
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Disposes the throwaway scopes whose instance has been dropped. Scopes
+    /// with async disposers are kept for [`dispose_all`](Self::dispose_all),
+    /// which can await them in order, until [`EPHEMERAL_ASYNC_BACKLOG`] of
+    /// them have finished; then they are run detached so they can't pile up.
+    fn reap_ephemeral_scopes(&self) {
+        let (finished, detached): (Vec<DisposeBag>, Vec<DisposeBag>) = {
+            let mut ephemeral = self.inner().ephemeral_scopes.lock().unwrap();
+            let (finished, live): (Vec<_>, Vec<_>) = std::mem::take(&mut *ephemeral).into_iter()
+                .partition(|(instance, _)| instance.strong_count() == 0);
+            *ephemeral = live;
+            let (waiting, finished): (Vec<_>, Vec<_>) = finished.into_iter()
+                .map(|(_, bag)| bag)
+                .partition(DisposeBag::has_async);
+            if waiting.len() < EPHEMERAL_ASYNC_BACKLOG {
+                let gone = || Weak::<()>::new() as Weak<dyn std::any::Any + Send + Sync>;
+                ephemeral.extend(waiting.into_iter().map(|bag| (gone(), bag)));
+                (finished, Vec::new())
+            } else {
+                (finished, waiting)
+            }
+        };
+        for mut bag in finished {
+            bag.run_all_sync_reverse();
+        }
+        for bag in detached {
+            bag.run_all_detached();
+        }
+    }
+
+    /// How scoped services resolved from this provider are handled.
+    pub fn scoped_from_root_policy(&self) -> ScopedFromRootPolicy {
+        self.inner().scoped_from_root
+    }
+
+    /// Reports a deprecated registration to observers the first time it is resolved.
+    #[inline]
+    pub(crate) fn report_deprecation(&self, reg: &crate::registration::Registration, key: &Key) {
+        if let Some(message) = reg.take_deprecation_notice() {
+            self.inner().observers.deprecated_resolution(key, message);
+        }
+    }
+
+    /// Fails with `FeatureDisabled` unless `flag` is on in the registered `dyn FeatureFlags`.
+    pub(crate) fn check_feature_gate(resolver: &impl Resolver, flag: &'static str) -> DiResult<()> {
+        let flags = resolver.get_trait::<dyn crate::FeatureFlags>()?;
+        if flags.is_enabled(flag) {
+            Ok(())
+        } else {
+            Err(DiError::FeatureDisabled(flag))
+        }
+    }
+
+    /// Locks the legacy singleton cache used by multi-bindings.
+    ///
+    /// Poisoning is ignored: the map is only changed by single inserts, so a
+    /// panic on another thread can't leave it half-updated, and one failing
+    /// factory mustn't break resolution of every other key.
+    pub(crate) fn lock_singletons(&self) -> MutexGuard<'_, HashMap<Key, AnyArc>> {
+        #[cfg(test)]
+        self.inner().singleton_lock_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.inner().singletons.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Create a new ServiceProvider with the given registry.
     /// This is used internally by ServiceCollection.build().
     #[allow(dead_code)]
@@ -521,6 +1458,13 @@ impl ServiceProvider {
         observers: Observers, 
         capabilities: CapabilityRegistry
     ) -> Self {
+        // Groups made only of singletons never change once resolved, so their
+        // results can be cached as a whole and served without locking.
+        let multi_singleton_groups = registry.many.iter()
+            .filter(|(_, regs)| !regs.is_empty() && regs.iter().all(|r| r.lifetime == Lifetime::Singleton))
+            .map(|(name, _)| (*name, OnceLock::new()))
+            .collect();
+
         Self {
             inner: Arc::new(ProviderInner {
                 registry,
@@ -529,10 +1473,126 @@ impl ServiceProvider {
                 root_disposers: Mutex::new(DisposeBag::default()),
                 observers,
                 capabilities,
+                prewarm: PrewarmSet::new(),
+                singleton_origins: Mutex::new(HashMap::new()),
+                expensive: HashSet::new(),
+                multi_singleton_groups,
+                live_scopes: AtomicUsize::new(0),
+                max_scoped_slots_used: AtomicUsize::new(0),
+                build_warnings: Vec::new(),
+                tracked_scopes: Mutex::new(Vec::new()),
+                dispose_observers: Arc::new([]),
+                disposed_keys: Arc::new(Mutex::new(HashSet::new())),
+                service_labels: Default::default(),
+                key_rewriters: Vec::new(),
+                fallback: OnceLock::new(),
+                scoped_from_root: ScopedFromRootPolicy::Error,
+                ephemeral_scopes: Mutex::new(Vec::new()),
+                #[cfg(feature = "async")]
+                async_depth_limit: None,
+                memory_probes: HashMap::new(),
+                #[cfg(test)]
+                singleton_lock_count: std::sync::atomic::AtomicUsize::new(0),
+                #[cfg(test)]
+                registry_lookup_count: std::sync::atomic::AtomicUsize::new(0),
             }),
         }
     }
 
+    /// Attaches the set of services to warm up in `ready()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_prewarm(mut self, prewarm: PrewarmSet) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .prewarm = prewarm;
+        self
+    }
+
+    /// Attaches the observers registered with `on_dispose()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_dispose_observers(mut self, observers: Vec<Arc<dyn crate::DisposeObserver>>) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .dispose_observers = observers.into();
+        self
+    }
+
+    /// Attaches the typed labels added with `label()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_service_labels(mut self, labels: crate::service_labels::ServiceLabels) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .service_labels = labels;
+        self
+    }
+
+    /// Attaches the key rewriters added with `add_key_rewriter()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_key_rewriters(mut self, rewriters: Vec<Arc<dyn crate::KeyRewriter>>) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .key_rewriters = rewriters;
+        self
+    }
+
+    /// Attaches the services marked as expensive for `validate_lifetimes()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_expensive(mut self, expensive: HashSet<&'static str>) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .expensive = expensive;
+        self
+    }
+
+    /// Sets how scoped services resolved from the root are handled.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_scoped_from_root_policy(mut self, policy: ScopedFromRootPolicy) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .scoped_from_root = policy;
+        self
+    }
+
+    /// Sets the nesting limit for async factories resolved synchronously.
+    /// This is used internally by ServiceCollection.build().
+    #[cfg(feature = "async")]
+    pub(crate) fn with_async_depth_limit(mut self, limit: Option<(usize, crate::AsyncDepthPolicy)>) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .async_depth_limit = limit;
+        self
+    }
+
+    /// Attaches the size probes added with `track_memory()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_memory_probes(mut self, probes: HashMap<Key, crate::metrics::SizeProbe>) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .memory_probes = probes;
+        self
+    }
+
+    /// Runs the unused-service lint and stores its findings for `build_warnings()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_unused_lint(mut self, roots: &HashSet<&'static str>) -> Self {
+        let findings = self.find_unused_services(roots);
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .build_warnings = findings;
+        self
+    }
+
+    /// Runs the trait cast check and adds its findings to `build_warnings()`.
+    /// This is used internally by ServiceCollection.build().
+    pub(crate) fn with_trait_cast_lint(mut self) -> Self {
+        let findings = self.find_trait_cast_mismatches();
+        Arc::get_mut(&mut self.inner)
+            .expect("provider must not be shared before build completes")
+            .build_warnings
+            .extend(findings);
+        self
+    }
+
     /// Discovers available tools based on capability requirements.
     ///
     /// This is the main entry point for agent planners to find suitable tools
@@ -587,16 +1647,115 @@ impl Resolver for ServiceProvider {
     where
         T: Dispose + 'static,
     {
-        self.push_sync_disposer(Box::new(move || service.dispose()));
+        self.push_sync_disposer(crate::traits::sync_hook(self.provider(), service));
     }
 
     fn register_async_disposer<T>(&self, service: Arc<T>)
     where
         T: AsyncDispose + 'static,
     {
-        self.push_async_disposer(Box::new(move || {
-            let service = service.clone();
-            Box::pin(async move { service.dispose().await })
-        }));
+        self.push_async_disposer(crate::traits::async_hook(self.provider(), service));
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServiceCollection;
+    use std::sync::atomic::Ordering;
+
+    trait Plugin: Send + Sync {}
+    struct PluginA;
+    struct PluginB;
+    impl Plugin for PluginA {}
+    impl Plugin for PluginB {}
+
+    #[test]
+    fn all_singleton_group_is_lock_free_after_warmup() {
+        let mut services = ServiceCollection::new();
+        services.add_trait_implementation(Arc::new(PluginA) as Arc<dyn Plugin>, Lifetime::Singleton);
+        services.add_trait_factory::<dyn Plugin, _>(Lifetime::Singleton, |_| Arc::new(PluginB));
+        let provider = services.build();
+        let scope = provider.create_scope();
+
+        let first = provider.get_all_trait::<dyn Plugin>().unwrap();
+        let locks_after_warmup = provider.inner().singleton_lock_count.load(Ordering::Relaxed);
+        assert!(locks_after_warmup > 0);
+
+        for _ in 0..10 {
+            let again = provider.get_all_trait::<dyn Plugin>().unwrap();
+            let from_scope = scope.get_all_trait::<dyn Plugin>().unwrap();
+            for ((a, b), c) in first.iter().zip(&again).zip(&from_scope) {
+                assert!(Arc::ptr_eq(a, b));
+                assert!(Arc::ptr_eq(a, c));
+            }
+        }
+        assert_eq!(provider.inner().singleton_lock_count.load(Ordering::Relaxed), locks_after_warmup);
+    }
+
+    #[test]
+    fn multi_binding_factory_panicking_under_the_cache_lock_does_not_poison_it() {
+        trait Hook: Send + Sync {}
+
+        let handle: Arc<OnceLock<WeakProvider>> = Arc::default();
+        let factory_handle = handle.clone();
+        let mut services = ServiceCollection::new();
+        services.add_trait_factory::<dyn Hook, _>(Lifetime::Singleton, move |_| -> Arc<dyn Hook> {
+            let provider = factory_handle.get().and_then(WeakProvider::upgrade).unwrap();
+            let _cache = provider.lock_singletons();
+            panic!("hook factory failed while holding the cache lock")
+        });
+        services.add_trait_implementation(Arc::new(PluginA) as Arc<dyn Plugin>, Lifetime::Singleton);
+        services.add_trait_factory::<dyn Plugin, _>(Lifetime::Singleton, |_| Arc::new(PluginB));
+        let provider = services.build();
+        let _ = handle.set(provider.downgrade());
+
+        let hooks = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| provider.get_all_trait::<dyn Hook>()));
+        assert!(hooks.is_err());
+        assert!(provider.inner().singletons.is_poisoned());
+
+        // The next resolution through the cache recovers the lock
+        assert_eq!(provider.get_all_trait::<dyn Plugin>().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn negative_cache_skips_registry_for_repeated_misses() {
+        struct Telemetry;
+        struct Config;
+
+        let mut services = ServiceCollection::new();
+        services.add_singleton(Config);
+        let provider = services.build();
+        let lookups = || provider.inner().registry_lookup_count.load(Ordering::Relaxed);
+
+        let scope = provider.create_scope().with_negative_cache(std::time::Duration::from_secs(60));
+        for _ in 0..50 {
+            assert!(matches!(scope.get::<Telemetry>(), Err(DiError::NotFound(_))));
+        }
+        assert_eq!(lookups(), 1);
+
+        // Registered services still resolve normally
+        scope.get_required::<Config>();
+        assert_eq!(lookups(), 2);
+
+        // Entries expire after the TTL
+        let scope = provider.create_scope().with_negative_cache(std::time::Duration::ZERO);
+        for _ in 0..3 {
+            assert!(scope.get::<Telemetry>().is_err());
+        }
+        assert_eq!(lookups(), 5);
+    }
+
+    #[test]
+    fn mixed_lifetime_group_still_creates_transients() {
+        let mut services = ServiceCollection::new();
+        services.add_trait_implementation(Arc::new(PluginA) as Arc<dyn Plugin>, Lifetime::Singleton);
+        services.add_trait_factory::<dyn Plugin, _>(Lifetime::Transient, |_| Arc::new(PluginB));
+        let provider = services.build();
+
+        let first = provider.get_all_trait::<dyn Plugin>().unwrap();
+        let second = provider.get_all_trait::<dyn Plugin>().unwrap();
+        assert!(Arc::ptr_eq(&first[0], &second[0]));
+        assert!(!Arc::ptr_eq(&first[1], &second[1]));
+    }
+}
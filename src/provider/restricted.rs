@@ -0,0 +1,153 @@
+//! Lifetime-restricted view of a scope.
+//!
+//! This module contains the RestrictedScope type, a resolver that a host can
+//! hand to plugin code to limit which kinds of services it may request.
+
+use std::sync::Arc;
+
+use crate::internal::BoxFutureUnit;
+use crate::registration::AnyArc;
+use crate::traits::{AsyncDispose, Dispose, Resolver, ResolverCore};
+use crate::{DiError, DiResult, Key, Lifetime};
+
+use super::Scope;
+
+/// Resolver over a [`Scope`] that rejects services of disallowed lifetimes.
+///
+/// Created with [`Scope::restricted`]. Requests for a service whose lifetime
+/// is not in the allowed set fail with [`DiError::LifetimeNotPermitted`]
+/// before any factory runs. The restriction applies to what the holder asks
+/// for directly; dependencies resolved inside factories are unaffected.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{DiError, Lifetime, Resolver, ServiceCollection};
+///
+/// struct Config;
+/// struct Buffer;
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton(Config);
+/// services.add_transient_factory::<Buffer, _>(|_| Buffer);
+///
+/// let provider = services.build();
+/// let scope = provider.create_scope();
+/// let plugin_view = scope.restricted(&[Lifetime::Singleton, Lifetime::Scoped]);
+///
+/// assert!(plugin_view.get::<Config>().is_ok());
+/// assert!(matches!(plugin_view.get::<Buffer>(), Err(DiError::LifetimeNotPermitted { .. })));
+/// ```
+pub struct RestrictedScope<'a> {
+    scope: &'a Scope,
+    allowed: Vec<Lifetime>,
+}
+
+impl Scope {
+    /// Returns a resolver over this scope that only resolves services with one
+    /// of the `allowed` lifetimes.
+    ///
+    /// See [`RestrictedScope`].
+    pub fn restricted(&self, allowed: &[Lifetime]) -> RestrictedScope<'_> {
+        RestrictedScope {
+            scope: self,
+            allowed: allowed.to_vec(),
+        }
+    }
+}
+
+impl RestrictedScope<'_> {
+    /// The lifetimes this resolver permits.
+    pub fn allowed(&self) -> &[Lifetime] {
+        &self.allowed
+    }
+
+    fn check(&self, service: &'static str, lifetime: Lifetime) -> DiResult<()> {
+        if self.allowed.contains(&lifetime) {
+            Ok(())
+        } else {
+            Err(DiError::LifetimeNotPermitted { service, lifetime })
+        }
+    }
+}
+
+impl ResolverCore for RestrictedScope<'_> {
+    fn resolve_any(&self, key: &Key) -> DiResult<AnyArc> {
+        let registry = &self.scope.root.inner().registry;
+        let lifetime = match (registry.get(key), key) {
+            (Some(reg), _) => Some(reg.lifetime),
+            // Mirrors the scope's fallback to a lone multi-binding
+            (None, Key::Trait(name)) => match registry.many.get(name).map(Vec::as_slice) {
+                Some([reg]) => Some(reg.lifetime),
+                _ => None,
+            },
+            (None, _) => None,
+        };
+        if let Some(lifetime) = lifetime {
+            self.check(key.display_name(), lifetime)?;
+        }
+        self.scope.resolve_any(key)
+    }
+
+    fn resolve_many(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
+        if let Key::Trait(name) = key {
+            let regs = self.scope.root.inner().registry.many.get(name);
+            for reg in regs.into_iter().flatten() {
+                self.check(name, reg.lifetime)?;
+            }
+        }
+        self.scope.resolve_many(key)
+    }
+
+    fn cache_status(&self, key: &Key) -> Option<(Lifetime, bool)> {
+        self.scope.cache_status(key)
+    }
+
+    fn scope_labels(&self) -> Option<&crate::labeled_scopes::ScopeLabels> {
+        self.scope.scope_labels()
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.scope.deadline()
+    }
+
+    fn scope_id(&self) -> Option<u64> {
+        self.scope.scope_id()
+    }
+
+    fn provider(&self) -> Option<&crate::ServiceProvider> {
+        Some(&self.scope.root)
+    }
+
+    fn share_scope(&self) -> Option<crate::Scope> {
+        self.scope.share_scope()
+    }
+
+    fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
+        self.scope.push_sync_disposer(f);
+    }
+
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.scope.push_sync_disposer_with_priority(priority, f);
+    }
+
+    fn push_async_disposer(&self, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>) {
+        self.scope.push_async_disposer(f);
+    }
+}
+
+impl Resolver for RestrictedScope<'_> {
+    fn register_disposer<T>(&self, service: Arc<T>)
+    where
+        T: Dispose + 'static,
+    {
+        self.scope.register_disposer(service);
+    }
+
+    fn register_async_disposer<T>(&self, service: Arc<T>)
+    where
+        T: AsyncDispose + 'static,
+    {
+        self.scope.register_async_disposer(service);
+    }
+}
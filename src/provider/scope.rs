@@ -3,9 +3,9 @@
 //! This module contains the Scope and ScopedResolver types for managing
 //! request-scoped services and their automatic disposal.
 
-#[cfg(not(feature = "once-cell"))]
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::future::Future;
 
 #[cfg(feature = "once-cell")]
@@ -15,7 +15,7 @@ use crate::{DiResult, DiError, Key, Lifetime};
 use crate::registration::AnyArc;
 use super::ResolverContext;
 use crate::internal::{DisposeBag, BoxFutureUnit, with_circular_catch};
-use crate::traits::{Resolver, ResolverCore, Dispose, AsyncDispose};
+use crate::traits::{Resolver, ResolverCore, Dispose, AsyncDispose, DisposalSummary};
 use super::ServiceProvider;
 
 /// Scoped service container for request-scoped dependency resolution.
@@ -72,49 +72,130 @@ pub struct Scope {
     pub(crate) root: ServiceProvider,
     // Slot-based scoped storage for O(1) access
     #[cfg(feature = "once-cell")]
-    pub(crate) scoped_cells: Box<[OnceCell<AnyArc>]>,
+    pub(crate) scoped_cells: Arc<[OnceCell<AnyArc>]>,
     #[cfg(not(feature = "once-cell"))]
-    pub(crate) scoped: Mutex<HashMap<Key, AnyArc>>,
-    pub(crate) scoped_disposers: Mutex<DisposeBag>,
+    pub(crate) scoped: Arc<Mutex<HashMap<Key, AnyArc>>>,
+    // Shared so the provider can track it weakly for batch disposal
+    pub(crate) scoped_disposers: Arc<Mutex<DisposeBag>>,
+    /// Process-unique identifier of this scope
+    pub(crate) id: u64,
+    /// Key/value labels, inherited by child scopes
+    pub(crate) labels: crate::labeled_scopes::ScopeLabels,
+    /// Transients cached for this scope by `memoize`
+    pub(crate) memoized: Arc<Mutex<HashMap<Key, AnyArc>>>,
+    /// Point in time after which async resolutions fail, inherited by clones
+    pub(crate) deadline: Option<std::time::Instant>,
+    /// Set by `dispose_all`; scoped resolutions fail afterwards
+    pub(crate) disposed: Arc<AtomicBool>,
+    /// Whether singletons still resolve through the scope once it is disposed
+    pub(crate) singletons_after_dispose: bool,
+    /// Recent `NotFound` results, set up by `with_negative_cache`
+    pub(crate) negative_cache: Option<NegativeCache>,
 }
 
-impl Clone for Scope {
-    fn clone(&self) -> Self {
-        // Create a new scope with the same root but fresh scoped state
-        #[cfg(feature = "once-cell")]
-        {
-            let scoped_count = self.root.inner().registry.scoped_count;
-            let scoped_cells: Box<[OnceCell<AnyArc>]> = (0..scoped_count)
-                .map(|_| OnceCell::new())
-                .collect::<Vec<_>>()
-                .into_boxed_slice();
-                
-            Self {
-                root: self.root.clone(),
-                scoped_cells,
-                scoped_disposers: Mutex::new(DisposeBag::default()),
+/// Keys a scope recently failed to find, remembered for `ttl`.
+pub(crate) struct NegativeCache {
+    ttl: std::time::Duration,
+    misses: Mutex<std::collections::HashMap<Key, std::time::Instant>>,
+}
+
+impl NegativeCache {
+    fn new(ttl: std::time::Duration) -> Self {
+        Self { ttl, misses: Mutex::new(std::collections::HashMap::new()) }
+    }
+
+    /// Whether `key` was reported missing less than `ttl` ago.
+    fn contains(&self, key: &Key) -> bool {
+        let mut misses = self.misses.lock().unwrap();
+        match misses.get(key) {
+            Some(at) if at.elapsed() < self.ttl => true,
+            Some(_) => {
+                misses.remove(key);
+                false
             }
+            None => false,
         }
-        
-        #[cfg(not(feature = "once-cell"))]
-        {
-            Self {
-                root: self.root.clone(),
-                scoped: Mutex::new(HashMap::new()),
-                scoped_disposers: Mutex::new(DisposeBag::default()),
-            }
+    }
+
+    fn insert(&self, key: &Key) {
+        self.misses.lock().unwrap().insert(key.clone(), std::time::Instant::now());
+    }
+}
+
+/// Weak handle to a scope's disposal state, kept by
+/// [`ServiceProvider::register_scope_for_tracking`].
+pub(crate) struct TrackedScope {
+    disposers: std::sync::Weak<Mutex<DisposeBag>>,
+    disposed: std::sync::Weak<AtomicBool>,
+}
+
+impl TrackedScope {
+    pub(crate) fn new(scope: &Scope) -> Self {
+        Self {
+            disposers: Arc::downgrade(&scope.scoped_disposers),
+            disposed: Arc::downgrade(&scope.disposed),
+        }
+    }
+
+    /// Whether the scope is still alive.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.disposers.strong_count() > 0
+    }
+
+    /// Marks the scope disposed, like [`Scope::dispose_all`], and takes its
+    /// hooks. Returns `None` once the scope is gone.
+    pub(crate) fn begin_disposal(&self) -> Option<DisposeBag> {
+        let disposers = self.disposers.upgrade()?;
+        if let Some(disposed) = self.disposed.upgrade() {
+            disposed.store(true, Ordering::Release);
         }
+        let bag = std::mem::take(&mut *disposers.lock().unwrap());
+        Some(bag)
+    }
+}
+
+/// Source of process-unique scope ids. Zero is never handed out.
+static NEXT_SCOPE_ID: AtomicU64 = AtomicU64::new(1);
+
+impl Clone for Scope {
+    fn clone(&self) -> Self {
+        // Create a new scope with the same root but fresh scoped state
+        let mut scope = Scope::new(self.root.clone());
+        scope.labels = self.labels.clone();
+        scope.deadline = self.deadline;
+        scope.singletons_after_dispose = self.singletons_after_dispose;
+        scope.negative_cache = self.negative_cache.as_ref().map(|cache| NegativeCache::new(cache.ttl));
+        scope
     }
 }
 
 impl ResolverCore for Scope {
     fn resolve_any(&self, key: &Key) -> DiResult<AnyArc> {
+        let rewritten = self.root.rewrite_key(key)?;
+        let key = rewritten.as_ref().unwrap_or(key);
+        if let Some(parent) = self.root.fallback_for(key) {
+            return parent.resolve_any(key);
+        }
         let name = key.display_name();
-        with_circular_catch(name, || self.resolve_any_impl(key))
+        let Some(negative) = &self.negative_cache else {
+            return with_circular_catch(name, || self.resolve_any_impl(key));
+        };
+        if negative.contains(key) {
+            return Err(DiError::NotFound(name));
+        }
+        let result = with_circular_catch(name, || self.resolve_any_impl(key));
+        // Only the key's own absence is cached, not a missing dependency
+        if matches!(result, Err(DiError::NotFound(missing)) if missing == name) {
+            negative.insert(key);
+        }
+        result
     }
     
     fn resolve_many(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
-        if let Key::Trait(_trait_name) = key {
+        if let Key::Trait(trait_name) = key {
+            if let Some(parent) = self.root.fallback_for_many(trait_name) {
+                return parent.resolve_many(key);
+            }
             let name = key.display_name();
             with_circular_catch(name, || self.resolve_many_impl(key))
         } else {
@@ -122,19 +203,154 @@ impl ResolverCore for Scope {
         }
     }
 
+    fn cache_status(&self, key: &Key) -> Option<(Lifetime, bool)> {
+        let (key, reg) = self.root.inner().registry.alias_target(key)?;
+        let cached = match reg.lifetime {
+            Lifetime::Singleton => reg.cached_singleton().is_some(),
+            Lifetime::Scoped => self.has_scoped_instance(reg, key),
+            Lifetime::Transient => false,
+        };
+        Some((reg.lifetime, cached))
+    }
+
+    fn scope_labels(&self) -> Option<&crate::labeled_scopes::ScopeLabels> {
+        Some(&self.labels)
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.deadline
+    }
+
+    fn scope_id(&self) -> Option<u64> {
+        Some(self.id)
+    }
+
+    fn provider(&self) -> Option<&ServiceProvider> {
+        Some(&self.root)
+    }
+
+    fn share_scope(&self) -> Option<Scope> {
+        Some(self.share())
+    }
+
     fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>) {
         self.scoped_disposers.lock().unwrap().push_sync(f);
     }
 
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        self.scoped_disposers.lock().unwrap().push_sync_with_priority(priority, f);
+    }
+
     fn push_async_disposer(&self, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>) {
         self.scoped_disposers.lock().unwrap().push_async(move || (f)());
     }
 }
 
 impl Scope {
+    /// Creates a fresh scope with empty scoped state under `root`.
+    pub(crate) fn new(root: ServiceProvider) -> Self {
+        let id = NEXT_SCOPE_ID.fetch_add(1, Ordering::Relaxed);
+        root.inner().live_scopes.fetch_add(1, Ordering::Relaxed);
+
+        #[cfg(feature = "once-cell")]
+        {
+            let scoped_count = root.inner().registry.scoped_count;
+            let scoped_cells: Arc<[OnceCell<AnyArc>]> = (0..scoped_count)
+                .map(|_| OnceCell::new())
+                .collect();
+
+            Self {
+                root,
+                scoped_cells,
+                scoped_disposers: Arc::new(Mutex::new(DisposeBag::default())),
+                id,
+                labels: Default::default(),
+                memoized: Arc::new(Mutex::new(HashMap::new())),
+                deadline: None,
+                disposed: Arc::new(AtomicBool::new(false)),
+                singletons_after_dispose: true,
+                negative_cache: None,
+            }
+        }
+
+        #[cfg(not(feature = "once-cell"))]
+        {
+            Self {
+                root,
+                scoped: Arc::new(Mutex::new(HashMap::new())),
+                scoped_disposers: Arc::new(Mutex::new(DisposeBag::default())),
+                id,
+                labels: Default::default(),
+                memoized: Arc::new(Mutex::new(HashMap::new())),
+                deadline: None,
+                disposed: Arc::new(AtomicBool::new(false)),
+                singletons_after_dispose: true,
+                negative_cache: None,
+            }
+        }
+    }
+
+    /// Another handle to this same scope.
+    ///
+    /// Unlike [`clone`](Clone::clone), the handle shares the scope's id,
+    /// scoped instances, memoized transients and disposal bag, so services
+    /// resolved through it behave as if resolved through `self`. It counts as
+    /// a live scope until dropped.
+    pub(crate) fn share(&self) -> Scope {
+        self.root.inner().live_scopes.fetch_add(1, Ordering::Relaxed);
+        Scope {
+            root: self.root.clone(),
+            #[cfg(feature = "once-cell")]
+            scoped_cells: self.scoped_cells.clone(),
+            #[cfg(not(feature = "once-cell"))]
+            scoped: self.scoped.clone(),
+            scoped_disposers: self.scoped_disposers.clone(),
+            id: self.id,
+            labels: self.labels.clone(),
+            memoized: self.memoized.clone(),
+            deadline: self.deadline,
+            disposed: self.disposed.clone(),
+            singletons_after_dispose: self.singletons_after_dispose,
+            negative_cache: self.negative_cache.as_ref().map(|cache| NegativeCache::new(cache.ttl)),
+        }
+    }
+
+    /// Number of scoped slots holding an instance.
+    fn filled_slots(&self) -> usize {
+        #[cfg(feature = "once-cell")]
+        {
+            self.scoped_cells.iter().filter(|cell| cell.get().is_some()).count()
+        }
+        #[cfg(not(feature = "once-cell"))]
+        {
+            self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner).len()
+        }
+    }
+
+    /// Returns true if this scope already holds an instance of the scoped `reg`.
+    pub(crate) fn has_scoped_instance(&self, reg: &crate::registration::Registration, _key: &Key) -> bool {
+        #[cfg(feature = "once-cell")]
+        {
+            reg.scoped_slot.is_some_and(|slot| self.scoped_cells[slot].get().is_some())
+        }
+        #[cfg(not(feature = "once-cell"))]
+        {
+            let _ = reg;
+            self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner).contains_key(_key)
+        }
+    }
+
+    /// Returns this scope's process-unique identifier.
+    ///
+    /// Ids are never reused, so they can be used to correlate diagnostics
+    /// (for example [`ServiceProvider::singleton_init_origin`]) with a scope.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Ultra-optimized scoped resolution using slot-based Vec storage
     #[inline(always)]
-    fn resolve_scoped(&self, reg: &crate::registration::Registration, _key: &Key) -> DiResult<AnyArc> {
+    fn resolve_scoped(&self, reg: &crate::registration::Registration, key: &Key) -> DiResult<AnyArc> {
         #[cfg(feature = "once-cell")]
         {
             if let Some(slot) = reg.scoped_slot {
@@ -148,7 +364,7 @@ impl Scope {
                 // Slow path: initialize with factory (unlikely after first access)
                 // TODO: Add std::hint::unlikely when stable
                 {
-                    let ctx = ResolverContext::new(self);
+                    let ctx = ResolverContext::for_scoped(self, key);
                     let v = (reg.ctor)(&ctx)?;
                     let stored = cell.get_or_init(|| v.clone()).clone();
                     return Ok(stored);
@@ -159,23 +375,23 @@ impl Scope {
         #[cfg(not(feature = "once-cell"))]
         {
             // Use HashMap for scoped caching when once-cell is not available
-            let key = _key.clone();
+            let key = key.clone();
             
             // Check if already cached
             {
-                let guard = self.scoped.lock().unwrap();
+                let guard = self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
                 if let Some(cached) = guard.get(&key) {
                     return Ok(cached.clone());
                 }
             }
             
             // Create and cache the value
-            let ctx = ResolverContext::new(self);
+            let ctx = ResolverContext::for_scoped(self, &key);
             let value = (reg.ctor)(&ctx)?;
             
             // Cache the value
             {
-                let mut guard = self.scoped.lock().unwrap();
+                let mut guard = self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
                 guard.insert(key, value.clone());
             }
             
@@ -190,14 +406,351 @@ impl Scope {
         }
     }
 
+    /// Caches a scoped instance created outside the normal resolution path, returning the
+    /// instance that won if another was stored first.
+    #[cfg(any(feature = "async", feature = "checkpoint"))]
+    pub(crate) fn store_scoped(&self, reg: &crate::registration::Registration, _key: &Key, value: AnyArc) -> AnyArc {
+        #[cfg(feature = "once-cell")]
+        {
+            match reg.scoped_slot {
+                Some(slot) => self.scoped_cells[slot].get_or_init(|| value).clone(),
+                None => value,
+            }
+        }
+        #[cfg(not(feature = "once-cell"))]
+        {
+            let _ = reg;
+            self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner).entry(_key.clone()).or_insert(value).clone()
+        }
+    }
+
+    /// Resolves `T` with caller-supplied values available to its factory.
+    ///
+    /// The factory of `T` reads the values with [`ResolverContext::arg`];
+    /// dependencies it resolves don't see them. A scoped or singleton `T`
+    /// that is already cached is returned as-is, so this is mainly useful
+    /// for transient services.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ResolutionArgs, ServiceCollection};
+    ///
+    /// struct Tenant(&'static str);
+    /// struct Greeting(String);
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_transient_factory::<Greeting, _>(|r| {
+    ///     let tenant = r.arg::<Tenant>().map_or("anonymous", |t| t.0);
+    ///     Greeting(format!("hello {tenant}"))
+    /// });
+    ///
+    /// let provider = services.build();
+    /// let scope = provider.create_scope();
+    /// let greeting = scope.get_with_context::<Greeting>(ResolutionArgs::new().with(Tenant("acme"))).unwrap();
+    /// assert_eq!(greeting.0, "hello acme");
+    /// ```
+    pub fn get_with_context<T: 'static + Send + Sync>(&self, args: crate::ResolutionArgs) -> DiResult<Arc<T>> {
+        crate::resolution_args::with_args(args, || self.get::<T>())
+    }
+
+    /// Resolves `T` once per scope, even if it is registered as transient.
+    ///
+    /// The first call in a scope resolves `T` normally and caches the result
+    /// in this scope; later `memoize` calls return that instance. Plain `get`
+    /// calls are unaffected and still follow the registered lifetime, and
+    /// other scopes keep their own cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// struct Tokenizer;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_transient_factory::<Tokenizer, _>(|_| Tokenizer);
+    ///
+    /// let provider = services.build();
+    /// let scope = provider.create_scope();
+    /// let a = scope.memoize::<Tokenizer>().unwrap();
+    /// let b = scope.memoize::<Tokenizer>().unwrap();
+    /// assert!(Arc::ptr_eq(&a, &b));
+    /// assert!(!Arc::ptr_eq(&a, &scope.get_required::<Tokenizer>()));
+    /// ```
+    pub fn memoize<T: 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let key = Key::Type(std::any::TypeId::of::<T>(), std::any::type_name::<T>());
+        let cached = self.memoized.lock().unwrap().get(&key).cloned();
+        let any = match cached {
+            Some(any) => any,
+            None => {
+                // Resolve without holding the lock so the factory can memoize too
+                let value = self.resolve_any(&key)?;
+                self.memoized.lock().unwrap().entry(key).or_insert(value).clone()
+            }
+        };
+        any.downcast::<T>()
+            .map_err(|_| DiError::TypeMismatch(std::any::type_name::<T>()))
+    }
+
+    /// Resolves the scoped service `T` and hands its disposal over to the caller.
+    ///
+    /// Disposal hooks that `T`'s factory registered through its resolver
+    /// context are removed from this scope, so disposing the scope no longer
+    /// disposes the instance; the caller must dispose it instead. Hooks of
+    /// `T`'s dependencies stay with the scope. The scope keeps the instance
+    /// cached and returns it to later `get` calls.
+    ///
+    /// Fails with [`DiError::WrongLifetime`] if `T` is not registered as scoped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Dispose, Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// struct Upload { closed: Arc<AtomicBool> }
+    /// impl Dispose for Upload {
+    ///     fn dispose(&self) { self.closed.store(true, Ordering::SeqCst); }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_factory::<Upload, _>(|r| {
+    ///     let closed = Arc::new(AtomicBool::new(false));
+    ///     r.register_disposer(Arc::new(Upload { closed: closed.clone() }));
+    ///     Upload { closed }
+    /// });
+    ///
+    /// let provider = services.build();
+    /// let scope = provider.create_scope();
+    /// let upload = scope.take_scoped::<Upload>().unwrap();
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// scope.dispose_all().await;
+    /// # });
+    /// assert!(!upload.closed.load(Ordering::SeqCst));
+    ///
+    /// // The consumer finishes with it and disposes it itself
+    /// upload.dispose();
+    /// assert!(upload.closed.load(Ordering::SeqCst));
+    /// ```
+    pub fn take_scoped<T: 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let key = crate::key::key_of_type::<T>();
+        if let Some(reg) = self.root.inner().registry.get(&key) {
+            if reg.lifetime != Lifetime::Scoped {
+                return Err(DiError::WrongLifetime("take_scoped only accepts scoped services"));
+            }
+        }
+        let value = self.get::<T>()?;
+        // Dropping the detached hooks releases them without running them
+        drop(self.scoped_disposers.lock().unwrap().detach_owned(&key));
+        Ok(value)
+    }
+
+    /// Resolves `key`, awaiting its async constructor if it was registered with one.
+    #[cfg(feature = "async")]
+    async fn resolve_any_async(&self, key: &Key) -> DiResult<AnyArc> {
+        let rewritten = self.root.rewrite_key(key)?;
+        let key = rewritten.as_ref().unwrap_or(key);
+        let reg = match self.root.inner().registry.get(key) {
+            Some(reg) => reg,
+            None => return self.resolve_any(key),
+        };
+        if let Some(alias) = &reg.alias {
+            let value = Box::pin(self.resolve_any_async(&alias.target)).await?;
+            return (alias.cast)(value);
+        }
+        let Some(async_ctor) = &reg.async_ctor else {
+            return self.resolve_any(key);
+        };
+
+        self.root.report_deprecation(reg, key);
+        if let Some(flag) = reg.feature_gate {
+            ServiceProvider::check_feature_gate(self, flag)?;
+        }
+        self.check_not_disposed(reg.lifetime, key)?;
+        match reg.lifetime {
+            Lifetime::Singleton => match reg.cached_singleton() {
+                Some(value) => Ok(value),
+                None => {
+                    // Singletons are built against the root, as in resolve_singleton_from
+                    let value = self.root.catch_factory_panic(key, reg.lifetime, async_ctor(&self.root)).await?;
+                    Ok(self.root.store_singleton(reg, key, value, Some(self.id)))
+                }
+            },
+            Lifetime::Scoped => {
+                if self.has_scoped_instance(reg, key) {
+                    return self.resolve_scoped(reg, key);
+                }
+                let value = self.root.catch_factory_panic(key, reg.lifetime, async_ctor(self)).await?;
+                Ok(self.store_scoped(reg, key, value))
+            }
+            Lifetime::Transient => self.root.catch_factory_panic(key, reg.lifetime, async_ctor(self)).await,
+        }
+    }
+
+    /// Sets a deadline for async resolutions from this scope.
+    ///
+    /// Once the deadline passes, [`get_async`](Self::get_async) and
+    /// [`get_trait_async`](Self::get_trait_async) fail with
+    /// [`DiError::DeadlineExceeded`], abandoning async factories that are
+    /// still running. Factories can read the deadline with
+    /// [`ResolverCore::deadline`]. Clones of the scope keep the deadline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ResolverCore, ServiceCollection};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let provider = ServiceCollection::new().build();
+    /// let deadline = Instant::now() + Duration::from_secs(30);
+    /// let scope = provider.create_scope().with_deadline(deadline);
+    /// assert_eq!(scope.deadline(), Some(deadline));
+    /// ```
+    pub fn with_deadline(mut self, deadline: std::time::Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Remembers services this scope fails to find for `ttl`.
+    ///
+    /// Hot paths that probe for an optional service with
+    /// [`get`](crate::Resolver::get) pay for a registry walk on every miss.
+    /// With a negative cache, a [`DiError::NotFound`] for a key is returned
+    /// straight away until `ttl` has passed since it was first reported.
+    /// Misses of a dependency inside a factory are not cached, and keys
+    /// served by a [fallback provider](ServiceProvider::with_fallback) are
+    /// never looked up here. Clones of the scope keep the setting but start
+    /// with an empty cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    /// use std::time::Duration;
+    ///
+    /// struct Telemetry;
+    ///
+    /// let provider = ServiceCollection::new().build();
+    /// let scope = provider.create_scope().with_negative_cache(Duration::from_secs(5));
+    /// for _ in 0..100 {
+    ///     assert!(scope.get::<Telemetry>().is_err());
+    /// }
+    /// ```
+    pub fn with_negative_cache(mut self, ttl: std::time::Duration) -> Self {
+        self.negative_cache = Some(NegativeCache::new(ttl));
+        self
+    }
+
+    /// Sets whether singletons can still be resolved through this scope after
+    /// [`dispose_all`](Self::dispose_all). Allowed by default.
+    ///
+    /// Scoped services always fail with [`DiError::ScopeDisposed`] once the
+    /// scope is disposed. Pass `false` to fail singleton resolutions the same
+    /// way, for teardown paths that must not reach shared services through a
+    /// dead scope. Clones of the scope keep the setting.
+    pub fn with_singletons_after_dispose(mut self, allowed: bool) -> Self {
+        self.singletons_after_dispose = allowed;
+        self
+    }
+
+    /// Returns true once [`dispose_all`](Self::dispose_all) has been called.
+    pub fn is_disposed(&self) -> bool {
+        self.disposed.load(Ordering::Acquire)
+    }
+
+    /// Fails if a service of `lifetime` can no longer be resolved because the
+    /// scope was disposed.
+    fn check_not_disposed(&self, lifetime: Lifetime, key: &Key) -> DiResult<()> {
+        let blocked = match lifetime {
+            Lifetime::Scoped => true,
+            Lifetime::Singleton => !self.singletons_after_dispose,
+            Lifetime::Transient => false,
+        };
+        if blocked && self.is_disposed() {
+            return Err(DiError::ScopeDisposed(key.display_name()));
+        }
+        Ok(())
+    }
+
+    /// Resolves `key` asynchronously, giving up when the scope's deadline passes.
+    #[cfg(feature = "async")]
+    async fn resolve_any_async_within_deadline(&self, key: &Key) -> DiResult<AnyArc> {
+        let Some(deadline) = self.deadline else {
+            return self.resolve_any_async(key).await;
+        };
+        let exceeded = DiError::DeadlineExceeded(key.display_name());
+        if std::time::Instant::now() >= deadline {
+            return Err(exceeded);
+        }
+        tokio::time::timeout_at(deadline.into(), self.resolve_any_async(key))
+            .await
+            .unwrap_or(Err(exceeded))
+    }
+
+    /// Resolves a concrete service, awaiting its factory if it was registered
+    /// with [`add_singleton_async`](crate::ServiceCollection::add_singleton_async)
+    /// or [`add_scoped_async`](crate::ServiceCollection::add_scoped_async).
+    ///
+    /// Services registered synchronously resolve as they would through
+    /// [`get`](crate::Resolver::get).
+    #[cfg(feature = "async")]
+    pub async fn get_async<T: 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let name = std::any::type_name::<T>();
+        let any = self.resolve_any_async_within_deadline(&Key::Type(std::any::TypeId::of::<T>(), name)).await?;
+        any.downcast::<T>().map_err(|_| DiError::TypeMismatch(name))
+    }
+
+    /// Like [`get_async`](Self::get_async), but panics if the service can't be resolved.
+    #[cfg(feature = "async")]
+    pub async fn get_required_async<T: 'static + Send + Sync>(&self) -> Arc<T> {
+        self.get_async::<T>()
+            .await
+            .unwrap_or_else(|e| panic!("Failed to resolve {}: {:?}", std::any::type_name::<T>(), e))
+    }
+
+    /// Resolves a trait service, awaiting its factory if it was registered with
+    /// one of the `add_*_trait_async` methods.
+    ///
+    /// Services registered synchronously resolve as they would through
+    /// [`get_trait`](crate::Resolver::get_trait).
+    #[cfg(feature = "async")]
+    pub async fn get_trait_async<T: ?Sized + 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let name = std::any::type_name::<T>();
+        let any = self.resolve_any_async_within_deadline(&Key::Trait(name)).await?;
+        any.downcast::<Arc<T>>()
+            .map(|boxed| (*boxed).clone())
+            .map_err(|_| DiError::TypeMismatch(name))
+    }
+
+    /// Like [`get_trait_async`](Self::get_trait_async), but panics if the service can't be resolved.
+    #[cfg(feature = "async")]
+    pub async fn get_required_trait_async<T: ?Sized + 'static + Send + Sync>(&self) -> Arc<T> {
+        self.get_trait_async::<T>()
+            .await
+            .unwrap_or_else(|e| panic!("Failed to resolve trait {}: {:?}", std::any::type_name::<T>(), e))
+    }
+
     fn resolve_any_impl(&self, key: &Key) -> DiResult<AnyArc> {
         let name = key.display_name();
+        #[cfg(test)]
+        self.root.inner().registry_lookup_count.fetch_add(1, Ordering::Relaxed);
         
         if let Some(reg) = self.root.inner().registry.get(key) {
+            self.root.report_deprecation(reg, key);
+            if let Some(flag) = reg.feature_gate {
+                ServiceProvider::check_feature_gate(self, flag)?;
+            }
+            self.check_not_disposed(reg.lifetime, key)?;
+            if let Some(alias) = &reg.alias {
+                return (alias.cast)(self.resolve_any(&alias.target)?);
+            }
             match reg.lifetime {
                 Lifetime::Singleton => {
                     // Delegate to root provider's optimized singleton resolution
-                    self.root.resolve_singleton(reg, key)
+                    self.root.resolve_singleton_from(reg, key, Some(self.id))
                 }
                 Lifetime::Scoped => {
                     // Use optimized slot-based scoped resolution
@@ -209,7 +762,11 @@ impl Scope {
                 }
             }
         } else if let Key::Trait(trait_name) = key {
-            // Fallback: if trait has multi-bindings, return last as single
+            let candidates = self.root.inner().registry.trait_candidates(trait_name);
+            if candidates.len() > 1 {
+                return Err(DiError::AmbiguousRegistration { trait_name, candidates });
+            }
+            // Fallback: if trait has a single multi-binding, return it as single
             if let Some(regs) = self.root.inner().registry.many.get(trait_name) {
                 if let Some(last) = regs.last() {
                     let ctx = ResolverContext::new(self);
@@ -227,18 +784,23 @@ impl Scope {
     
     fn resolve_many_impl(&self, key: &Key) -> DiResult<Vec<AnyArc>> {
         if let Key::Trait(trait_name) = key {
-            
+            let group = self.root.inner().multi_singleton_groups.get(trait_name);
+            if let Some(cached) = group.and_then(|g| g.get()) {
+                return Ok(cached.clone());
+            }
+
             if let Some(regs) = self.root.inner().registry.many.get(trait_name) {
                 let mut results = Vec::with_capacity(regs.len());
                 
                 for (i, reg) in regs.iter().enumerate() {
                     let multi_key = Key::MultiTrait(trait_name, i);
+                    self.check_not_disposed(reg.lifetime, &multi_key)?;
                     
                     let value = match reg.lifetime {
                         Lifetime::Singleton => {
                             // Expert fix: Double-checked locking for singletons
                             {
-                                let cache = self.root.inner().singletons.lock().unwrap();
+                                let cache = self.root.lock_singletons();
                                 if let Some(cached) = cache.get(&multi_key) {
                                     results.push(cached.clone());
                                     continue;
@@ -251,10 +813,11 @@ impl Scope {
                             
                             // Double-checked insert
                             {
-                                let mut cache = self.root.inner().singletons.lock().unwrap();
+                                let mut cache = self.root.lock_singletons();
                                 if let Some(cached) = cache.get(&multi_key) {
                                     cached.clone() // Another thread beat us
                                 } else {
+                                    self.root.record_singleton_origin(&multi_key, Some(self.id));
                                     cache.insert(multi_key, value.clone());
                                     value
                                 }
@@ -285,7 +848,7 @@ impl Scope {
                                     
                                     // Check if already cached
                                     {
-                                        let guard = self.scoped.lock().unwrap();
+                                        let guard = self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
                                         if let Some(cached) = guard.get(&multi_key) {
                                             cached.clone()
                                         } else {
@@ -295,7 +858,7 @@ impl Scope {
                                             let ctx = ResolverContext::new(self);
                                             let value = (reg.ctor)(&ctx)?;
                                             
-                                            let mut guard = self.scoped.lock().unwrap();
+                                            let mut guard = self.scoped.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
                                             guard.insert(multi_key, value.clone());
                                             value
                                         }
@@ -316,7 +879,10 @@ impl Scope {
                     results.push(value);
                 }
                 
-                Ok(results)
+                match group {
+                    Some(group) => Ok(group.get_or_init(|| results).clone()),
+                    None => Ok(results),
+                }
             } else {
                 Ok(Vec::new())
             }
@@ -331,6 +897,14 @@ impl Scope {
     /// followed by all synchronous disposal hooks (in reverse order). This ensures
     /// proper cleanup of scoped services.
     ///
+    /// Returns a [`DisposalSummary`] with the number of hooks that ran and any
+    /// hooks that panicked. A panicking hook does not stop the others.
+    ///
+    /// Afterwards the scope is marked disposed: scoped services fail with
+    /// [`DiError::ScopeDisposed`] instead of handing out disposed instances.
+    /// See [`with_singletons_after_dispose`](Self::with_singletons_after_dispose)
+    /// for singletons.
+    ///
     /// # Examples
     ///
     /// ```
@@ -361,11 +935,15 @@ impl Scope {
     /// scope.dispose_all().await; // Only disposes scoped resources
     /// # }
     /// ```
-    pub async fn dispose_all(&self) {
+    pub async fn dispose_all(&self) -> DisposalSummary {
+        self.disposed.store(true, Ordering::Release);
+        // Take the hooks out so the lock isn't held across await points
+        let mut bag = std::mem::take(&mut *self.scoped_disposers.lock().unwrap());
         // First run async disposers in reverse order
-        self.scoped_disposers.lock().unwrap().run_all_async_reverse().await;
-        // Then run sync disposers in reverse order  
-        self.scoped_disposers.lock().unwrap().run_all_sync_reverse();
+        let mut summary = bag.run_all_async_reverse().await;
+        // Then run sync disposers in reverse order
+        summary.merge(bag.run_all_sync_reverse());
+        summary
     }
 
     /// Executes an async block with automatic disposal of services resolved via `*_disposable` methods.
@@ -514,8 +1092,16 @@ impl Scope {
 
 impl Drop for Scope {
     fn drop(&mut self) {
+        self.root.inner().live_scopes.fetch_sub(1, Ordering::Relaxed);
+        self.root.inner().max_scoped_slots_used.fetch_max(self.filled_slots(), Ordering::Relaxed);
+
+        // Shared handles leave the warning to the last one dropped
+        if Arc::strong_count(&self.scoped_disposers) > 1 {
+            return;
+        }
+
         // Check if there are undisposed scoped resources and warn
-        let bag = self.scoped_disposers.get_mut().unwrap();
+        let bag = self.scoped_disposers.lock().unwrap();
         if !bag.is_empty() {
             eprintln!("[ferrous-di] Scope dropped with undisposed resources. Call dispose_all().await before dropping.");
         }
@@ -527,17 +1113,14 @@ impl Resolver for Scope {
     where
         T: Dispose + 'static,
     {
-        self.push_sync_disposer(Box::new(move || service.dispose()));
+        self.push_sync_disposer(crate::traits::sync_hook(self.provider(), service));
     }
 
     fn register_async_disposer<T>(&self, service: Arc<T>)
     where
         T: AsyncDispose + 'static,
     {
-        self.push_async_disposer(Box::new(move || {
-            let service = service.clone();
-            Box::pin(async move { service.dispose().await })
-        }));
+        self.push_async_disposer(crate::traits::async_hook(self.provider(), service));
     }
 }
 
@@ -603,16 +1186,80 @@ pub struct ScopedResolver {
     scope: Arc<Scope>,
     // Shared bag so resolver can be moved into async closures safely
     pub(crate) bag: Arc<Mutex<DisposeBag>>,
+    // Bag of the enclosing resolver when created via `child_scope()`
+    parent_bag: Option<Arc<Mutex<DisposeBag>>>,
 }
 
 impl ScopedResolver {
     pub(crate) fn new(scope: &Scope) -> Self {
         Self { 
             scope: Arc::new(scope.clone()), 
-            bag: Arc::new(Mutex::new(DisposeBag::default()))
+            bag: Arc::new(Mutex::new(DisposeBag::default())),
+            parent_bag: None,
         }
     }
 
+    /// Creates a nested disposal domain within the current `using()` block.
+    ///
+    /// The child resolves from the same scope, but services requested through
+    /// its `*_disposable` methods go into the child's own bag. That bag is
+    /// disposed when the child is [`finish`](Self::finish)ed or dropped, so
+    /// sub-resources are always cleaned up before the parent block's resources.
+    ///
+    /// When the child is dropped without `finish()`, sync disposers run
+    /// immediately and async disposers are handed to the parent, where they
+    /// run first during the parent's disposal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ferrous_di::{ServiceCollection, Dispose};
+    /// struct Connection;
+    /// impl Dispose for Connection {
+    ///     fn dispose(&self) { /* close */ }
+    /// }
+    /// struct Cursor;
+    /// impl Dispose for Cursor {
+    ///     fn dispose(&self) { /* release */ }
+    /// }
+    ///
+    /// # async fn example() -> Result<(), ferrous_di::DiError> {
+    /// # let mut services = ServiceCollection::new();
+    /// # services.add_scoped_factory::<Connection, _>(|_| Connection);
+    /// # services.add_transient_factory::<Cursor, _>(|_| Cursor);
+    /// # let provider = services.build();
+    /// # let scope = provider.create_scope();
+    /// scope.using(|resolver| async move {
+    ///     let _conn = resolver.get_disposable::<Connection>()?;
+    ///     {
+    ///         let child = resolver.child_scope();
+    ///         let _cursor = child.get_disposable::<Cursor>()?;
+    ///         child.finish().await; // Cursor disposed here
+    ///     }
+    ///     Ok::<(), ferrous_di::DiError>(())
+    /// }).await?; // Connection disposed here
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn child_scope(&self) -> ScopedResolver {
+        Self {
+            scope: self.scope.clone(),
+            bag: Arc::new(Mutex::new(DisposeBag::default())),
+            parent_bag: Some(self.bag.clone()),
+        }
+    }
+
+    /// Disposes everything registered through this resolver, async then sync, LIFO.
+    ///
+    /// Intended for child resolvers created with [`child_scope`](Self::child_scope);
+    /// the root resolver of a `using()` block is disposed automatically.
+    pub async fn finish(self) -> DisposalSummary {
+        let mut bag = std::mem::take(&mut *self.bag.lock().unwrap());
+        let mut summary = bag.run_all_async_reverse().await;
+        summary.merge(bag.run_all_sync_reverse());
+        summary
+    }
+
     // --- Plain resolution (no auto-dispose) ---
 
     /// Resolves a concrete service type without auto-disposal registration.
@@ -639,6 +1286,36 @@ impl ScopedResolver {
         self.scope.get_all_trait::<T>()
     }
 
+    /// Resolves a concrete service, awaiting its async factory if it has one.
+    ///
+    /// This method works exactly like `Scope::get_async()`. The service
+    /// will NOT be automatically disposed.
+    #[cfg(feature = "async")]
+    pub async fn get_async<T: 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        self.scope.get_async::<T>().await
+    }
+
+    /// Like [`get_async`](Self::get_async), but panics if the service can't be resolved.
+    #[cfg(feature = "async")]
+    pub async fn get_required_async<T: 'static + Send + Sync>(&self) -> Arc<T> {
+        self.scope.get_required_async::<T>().await
+    }
+
+    /// Resolves a trait service, awaiting its async factory if it has one.
+    ///
+    /// This method works exactly like `Scope::get_trait_async()`. The service
+    /// will NOT be automatically disposed.
+    #[cfg(feature = "async")]
+    pub async fn get_trait_async<T: ?Sized + 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        self.scope.get_trait_async::<T>().await
+    }
+
+    /// Like [`get_trait_async`](Self::get_trait_async), but panics if the service can't be resolved.
+    #[cfg(feature = "async")]
+    pub async fn get_required_trait_async<T: ?Sized + 'static + Send + Sync>(&self) -> Arc<T> {
+        self.scope.get_required_trait_async::<T>().await
+    }
+
     // --- Auto-disposing variants for concrete types ---
 
     /// Resolves a concrete service type and registers it for automatic synchronous disposal.
@@ -758,6 +1435,50 @@ impl ScopedResolver {
         Ok(s)
     }
 
+    /// Resolves every implementation of a multi-bound trait and registers each
+    /// for automatic synchronous disposal.
+    ///
+    /// The implementations are disposed when the `using()` block exits, in
+    /// reverse registration order. The trait must extend `Dispose`. Nothing is
+    /// registered if any implementation fails to resolve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ferrous_di::{Lifetime, ServiceCollection, Dispose};
+    /// # use std::sync::Arc;
+    /// trait Tool: Dispose + Send + Sync {}
+    /// struct Search;
+    /// impl Dispose for Search {
+    ///     fn dispose(&self) { /* close connections */ }
+    /// }
+    /// impl Tool for Search {}
+    ///
+    /// # async fn example() -> Result<(), ferrous_di::DiError> {
+    /// # let mut services = ServiceCollection::new();
+    /// # services.add_trait_implementation(Arc::new(Search) as Arc<dyn Tool>, Lifetime::Scoped);
+    /// # let provider = services.build();
+    /// # let scope = provider.create_scope();
+    /// scope.using(|resolver| async move {
+    ///     let tools = resolver.get_all_trait_disposable::<dyn Tool>()?;
+    ///     Ok::<(), ferrous_di::DiError>(())
+    /// }).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_all_trait_disposable<T>(&self) -> DiResult<Vec<Arc<T>>>
+    where
+        T: ?Sized + Dispose + 'static + Send + Sync,
+    {
+        let all = self.scope.get_all_trait::<T>()?;
+        let mut bag = self.bag.lock().unwrap();
+        for s in &all {
+            let clone = s.clone();
+            bag.push_sync(Box::new(move || clone.dispose()));
+        }
+        Ok(all)
+    }
+
     /// Resolves a trait implementation and registers it for automatic asynchronous disposal.
     ///
     /// The trait object will be disposed when the `using()` block exits, in LIFO order.
@@ -806,4 +1527,20 @@ impl ScopedResolver {
         self.bag.lock().unwrap().push_async(move || async move { clone.dispose().await });
         Ok(s)
     }
-}
\ No newline at end of file
+}
+
+impl Drop for ScopedResolver {
+    fn drop(&mut self) {
+        // Only the last handle of a child resolver cleans up its bag
+        let Some(parent) = &self.parent_bag else { return };
+        if Arc::strong_count(&self.bag) != 1 {
+            return;
+        }
+        let mut bag = std::mem::take(&mut *self.bag.lock().unwrap());
+        if bag.is_empty() {
+            return;
+        }
+        bag.drain_async_into(&mut parent.lock().unwrap());
+        bag.run_all_sync_reverse();
+    }
+}
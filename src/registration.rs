@@ -2,10 +2,12 @@
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use crate::error::DiResult;
 use crate::key::Key;
 use crate::lifetime::Lifetime;
+use crate::descriptors::RegistrationKind;
 
 #[cfg(feature = "once-cell")]
 use once_cell::sync::OnceCell;
@@ -16,6 +18,31 @@ pub(crate) use crate::provider::ResolverContext;
 // Type-erased Arc for storage
 pub(crate) type AnyArc = Arc<dyn Any + Send + Sync>;
 
+/// Type-erased sync constructor
+pub(crate) type Ctor = Arc<dyn for<'a> Fn(&ResolverContext<'a>) -> DiResult<AnyArc> + Send + Sync>;
+
+/// Native async constructor, awaited by the `*_async` resolution methods
+#[cfg(feature = "async")]
+pub(crate) type AsyncCtor = Arc<
+    dyn for<'a> Fn(&'a dyn crate::traits::ResolverCore)
+        -> std::pin::Pin<Box<dyn std::future::Future<Output = DiResult<AnyArc>> + Send + 'a>>
+        + Send
+        + Sync,
+>;
+
+/// Converts the instance of an alias target into the alias's own storage form
+pub(crate) type AliasCast = Arc<dyn Fn(AnyArc) -> DiResult<AnyArc> + Send + Sync>;
+
+/// Redirect from an alias key to the registration it stands for.
+///
+/// The target is resolved with its own lifetime and cache; the alias only
+/// converts the instance, so both keys share one singleton or scoped value.
+#[derive(Clone)]
+pub(crate) struct Alias {
+    pub(crate) target: Key,
+    pub(crate) cast: AliasCast,
+}
+
 /// Service registration with lifetime and constructor
 pub(crate) struct Registration {
     pub(crate) lifetime: Lifetime,
@@ -34,6 +61,29 @@ pub(crate) struct Registration {
     
     /// Scoped slot index for O(1) scoped service resolution
     pub(crate) scoped_slot: Option<usize>,
+
+    /// Deprecation note set via `ServiceCollection::deprecate`
+    pub(crate) deprecated: Option<&'static str>,
+    /// Whether the deprecation has already been reported to observers
+    pub(crate) deprecation_reported: AtomicBool,
+    /// Runtime feature flag that must be on for this service to resolve
+    pub(crate) feature_gate: Option<&'static str>,
+    /// Async constructor used instead of `ctor` when resolved from an async context
+    #[cfg(feature = "async")]
+    pub(crate) async_ctor: Option<AsyncCtor>,
+    /// How instances are produced, reported through `ServiceDescriptor`
+    pub(crate) kind: RegistrationKind,
+    /// Dependencies declared at registration, used by the graph exporter
+    /// instead of running the factory
+    pub(crate) declared_deps: Option<Arc<[crate::graph_export::DependencyDecl]>>,
+    /// Address of the shared instance behind an instance-backed multi-binding,
+    /// used to spot the same `Arc` registered twice
+    pub(crate) instance_addr: Option<usize>,
+    /// Constructor as it was before the first resolver-aware decorator was
+    /// applied, used to tell the decorator's dependencies from the service's own
+    pub(crate) undecorated_ctor: Option<Ctor>,
+    /// Set for `add_alias` registrations, which resolve through another key
+    pub(crate) alias: Option<Alias>,
 }
 
 impl Registration {
@@ -59,9 +109,59 @@ impl Registration {
             impl_id: None,
             single_runtime,
             scoped_slot: None,
+            deprecated: None,
+            deprecation_reported: AtomicBool::new(false),
+            feature_gate: None,
+            #[cfg(feature = "async")]
+            async_ctor: None,
+            kind: RegistrationKind::Factory,
+            declared_deps: None,
+            instance_addr: None,
+            undecorated_ctor: None,
+            alias: None,
         }
     }
+
+    /// Creates a registration that redirects to `target` and converts its
+    /// instance with `cast`. Its lifetime is taken from the target when the
+    /// registry is finalized.
+    pub(crate) fn alias(target: Key, cast: AliasCast) -> Self {
+        let alias = Alias { target, cast };
+        let redirect = alias.clone();
+        let ctor = move |r: &ResolverContext| -> DiResult<AnyArc> {
+            use crate::traits::ResolverCore;
+            (redirect.cast)(r.resolve_any(&redirect.target)?)
+        };
+        let mut reg = Self::new(Lifetime::Transient, Arc::new(ctor));
+        reg.alias = Some(alias);
+        reg
+    }
+
+    /// Marks the registration as backed by a pre-built instance.
+    pub(crate) fn instance(mut self) -> Self {
+        self.kind = RegistrationKind::Instance;
+        self
+    }
     
+    /// Copies the constructor and settings into a registration with an empty
+    /// singleton cache. Metadata is not carried over since it can't be cloned.
+    pub(crate) fn fresh_copy(&self) -> Self {
+        let mut reg = Self::new(self.lifetime, self.ctor.clone());
+        reg.impl_id = self.impl_id;
+        reg.deprecated = self.deprecated;
+        reg.feature_gate = self.feature_gate;
+        #[cfg(feature = "async")]
+        {
+            reg.async_ctor = self.async_ctor.clone();
+        }
+        reg.kind = self.kind;
+        reg.declared_deps = self.declared_deps.clone();
+        reg.instance_addr = self.instance_addr;
+        reg.undecorated_ctor = self.undecorated_ctor.clone();
+        reg.alias = self.alias.clone();
+        reg
+    }
+
     /// Creates a new registration with metadata
     pub(crate) fn with_metadata(
         lifetime: Lifetime,
@@ -74,6 +174,27 @@ impl Registration {
         reg.impl_id = impl_id;
         reg
     }
+
+    /// Returns the deprecation note the first time it is asked for, `None` afterwards.
+    #[inline]
+    pub(crate) fn take_deprecation_notice(&self) -> Option<&'static str> {
+        let message = self.deprecated?;
+        (!self.deprecation_reported.swap(true, Ordering::Relaxed)).then_some(message)
+    }
+
+    /// Returns the cached singleton instance, if it has already been created.
+    pub(crate) fn cached_singleton(&self) -> Option<AnyArc> {
+        #[cfg(feature = "once-cell")]
+        {
+            self.single_runtime.as_ref().and_then(|cell| cell.get().cloned())
+        }
+        #[cfg(not(feature = "once-cell"))]
+        {
+            self.single_runtime
+                .as_ref()
+                .and_then(|mutex| mutex.lock().unwrap_or_else(std::sync::PoisonError::into_inner).clone())
+        }
+    }
 }
 
 /// Service registry holding all registrations
@@ -90,6 +211,8 @@ pub(crate) struct Registry {
     pub(crate) multi_scoped_slots: HashMap<(&'static str, usize), usize>,
     /// Threshold for Vec vs HashMap (optimize for small collections)
     pub(crate) small_threshold: usize,
+    /// Key of the most recent registration, for follow-up calls such as `label()`
+    pub(crate) last_key: Option<Key>,
 }
 
 impl Registry {
@@ -101,11 +224,13 @@ impl Registry {
             scoped_count: 0,
             multi_scoped_slots: HashMap::new(),
             small_threshold: 16, // Optimal based on research: Vec faster for ≤15 items
+            last_key: None,
         }
     }
     
     /// Inserts a registration with optimal storage selection
     pub(crate) fn insert(&mut self, key: Key, registration: Registration) {
+        self.last_key = Some(key.clone());
         if self.one_small.len() < self.small_threshold {
             // Use Vec for small collections (cache-friendly linear search)
             if let Some(pos) = self.one_small.iter().position(|(k, _)| k == &key) {
@@ -174,16 +299,113 @@ impl Registry {
             .chain(self.one_large.iter())
     }
 
+    /// Appends a multi-binding for `name`.
+    pub(crate) fn push_many(&mut self, name: &'static str, registration: Registration) {
+        let regs = self.many.entry(name).or_default();
+        self.last_key = Some(Key::MultiTrait(name, regs.len()));
+        regs.push(registration);
+    }
+
+    /// Fresh copies of the registrations whose service name passes `keep`,
+    /// ready to be finalized again.
+    pub(crate) fn fresh_subset(&self, keep: impl Fn(&str) -> bool) -> Registry {
+        let mut subset = Registry::new();
+        for (key, reg) in self.iter().filter(|(key, _)| keep(key.display_name())) {
+            subset.insert(key.clone(), reg.fresh_copy());
+        }
+        for (name, regs) in self.many.iter().filter(|(name, _)| keep(name)) {
+            subset.many.insert(name, regs.iter().map(Registration::fresh_copy).collect());
+        }
+        subset
+    }
+
+    /// Lists the registrations an unnamed lookup of `trait_name` could mean
+    /// when there is no unnamed single binding: named bindings first, then
+    /// multi-bindings in registration order.
+    pub(crate) fn trait_candidates(&self, trait_name: &'static str) -> Vec<String> {
+        let mut candidates: Vec<String> = self.iter()
+            .filter_map(|(key, _)| match key {
+                Key::TraitNamed(name, service) if *name == trait_name => {
+                    Some(format!("{} (named \"{}\")", name, service))
+                }
+                _ => None,
+            })
+            .collect();
+        candidates.sort();
+        let multi = self.many.get(trait_name).map_or(0, Vec::len);
+        candidates.extend((0..multi).map(|i| format!("{}[{}]", trait_name, i)));
+        candidates
+    }
+
+    /// Drops multi-bindings that share their instance with an earlier
+    /// binding of the same trait. The first registration is kept.
+    ///
+    /// Returns, for each trait that lost bindings, the new index of every
+    /// original binding; a dropped duplicate maps to the binding it repeated.
+    pub(crate) fn dedup_many_instances(&mut self) -> HashMap<&'static str, Vec<usize>> {
+        let mut remapped = HashMap::new();
+        for (name, regs) in self.many.iter_mut() {
+            let mut first_at = HashMap::new();
+            let mut indices = Vec::with_capacity(regs.len());
+            let mut kept = Vec::with_capacity(regs.len());
+            for reg in regs.drain(..) {
+                match reg.instance_addr.and_then(|addr| first_at.get(&addr).copied()) {
+                    Some(index) => indices.push(index),
+                    None => {
+                        if let Some(addr) = reg.instance_addr {
+                            first_at.insert(addr, kept.len());
+                        }
+                        indices.push(kept.len());
+                        kept.push(reg);
+                    }
+                }
+            }
+            if kept.len() < indices.len() {
+                remapped.insert(*name, indices);
+            }
+            *regs = kept;
+        }
+        remapped
+    }
+
     /// Finalizes registry by assigning scoped slot indices and sorting Vec
+    /// Follows a chain of aliases from `key` to the registration that
+    /// actually builds the instance. `None` if the chain ends at a missing
+    /// key or loops.
+    pub(crate) fn alias_target<'a>(&'a self, mut key: &'a Key) -> Option<(&'a Key, &'a Registration)> {
+        let mut reg = self.get(key)?;
+        for _ in 0..self.one_small.len() + self.one_large.len() {
+            match &reg.alias {
+                Some(alias) => {
+                    key = &alias.target;
+                    reg = self.get(key)?;
+                }
+                None => return Some((key, reg)),
+            }
+        }
+        None
+    }
+
     pub(crate) fn finalize(&mut self) {
         // Sort small Vec by Key for better cache locality during lookup
         self.one_small.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // Aliases report the lifetime of the registration they redirect to
+        let alias_lifetimes: Vec<(Key, Lifetime)> = self.iter()
+            .filter(|(_, reg)| reg.alias.is_some())
+            .filter_map(|(key, _)| Some((key.clone(), self.alias_target(key)?.1.lifetime)))
+            .collect();
+        for (key, lifetime) in alias_lifetimes {
+            if let Some(reg) = self.get_mut(&key) {
+                reg.lifetime = lifetime;
+            }
+        }
         
         let mut next_scoped_slot = 0;
         
-        // Assign slots to Vec registrations
+        // Assign slots to Vec registrations; aliases use their target's slot
         for (_, reg) in &mut self.one_small {
-            if reg.lifetime == Lifetime::Scoped {
+            if reg.lifetime == Lifetime::Scoped && reg.alias.is_none() {
                 reg.scoped_slot = Some(next_scoped_slot);
                 next_scoped_slot += 1;
             }
@@ -191,7 +413,7 @@ impl Registry {
         
         // Assign slots to HashMap registrations
         for reg in self.one_large.values_mut() {
-            if reg.lifetime == Lifetime::Scoped {
+            if reg.lifetime == Lifetime::Scoped && reg.alias.is_none() {
                 reg.scoped_slot = Some(next_scoped_slot);
                 next_scoped_slot += 1;
             }
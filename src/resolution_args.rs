@@ -0,0 +1,100 @@
+//! Caller-supplied values for parameterized construction.
+//!
+//! [`Scope::get_with_context`](crate::Scope::get_with_context) makes a
+//! [`ResolutionArgs`] bag visible to the factory of the requested service,
+//! where it is read with
+//! [`ResolverContext::arg`](crate::ResolverContext::arg). This covers small
+//! per-call inputs (a request header, a correlation id) without registering
+//! throwaway services for them.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Type-erased bag of per-resolution values, keyed by type.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{ResolutionArgs, Resolver, ServiceCollection};
+///
+/// struct RequestId(String);
+/// struct AuditEntry { request_id: String }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_transient_factory::<AuditEntry, _>(|r| AuditEntry {
+///     request_id: r.arg::<RequestId>().map_or_else(String::new, |id| id.0.clone()),
+/// });
+///
+/// let provider = services.build();
+/// let scope = provider.create_scope();
+/// let args = ResolutionArgs::new().with(RequestId("req-42".into()));
+/// let entry = scope.get_with_context::<AuditEntry>(args).unwrap();
+/// assert_eq!(entry.request_id, "req-42");
+/// ```
+#[derive(Clone, Default)]
+pub struct ResolutionArgs {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl ResolutionArgs {
+    /// Creates an empty bag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `value`, replacing any previous value of the same type.
+    pub fn with<A: Any + Send + Sync>(mut self, value: A) -> Self {
+        self.insert(value);
+        self
+    }
+
+    /// Inserts `value`, replacing any previous value of the same type.
+    pub fn insert<A: Any + Send + Sync>(&mut self, value: A) {
+        self.values.insert(TypeId::of::<A>(), Arc::new(value));
+    }
+
+    /// Returns the value of type `A`, if present.
+    pub fn get<A: Any + Send + Sync>(&self) -> Option<Arc<A>> {
+        self.values.get(&TypeId::of::<A>())?.clone().downcast().ok()
+    }
+}
+
+thread_local! {
+    /// Bags of the `get_with_context` calls in progress on this thread, innermost
+    /// last, each with the resolution depth at which it was supplied
+    static ACTIVE_ARGS: RefCell<Vec<(usize, Arc<ResolutionArgs>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the bag pushed by [`with_args`], even if the factory panics.
+struct ArgsGuard;
+
+impl Drop for ArgsGuard {
+    fn drop(&mut self) {
+        ACTIVE_ARGS.with(|active| active.borrow_mut().pop());
+    }
+}
+
+/// Runs `f` with `args` visible to [`current_arg`] for the service it resolves.
+pub(crate) fn with_args<T>(args: ResolutionArgs, f: impl FnOnce() -> T) -> T {
+    let depth = crate::internal::resolution_depth();
+    ACTIVE_ARGS.with(|active| active.borrow_mut().push((depth, Arc::new(args))));
+    let _guard = ArgsGuard;
+    f()
+}
+
+/// Looks up `A` in the bag supplied for the service being built right now.
+///
+/// A bag only applies one level below where it was supplied, i.e. to the
+/// directly requested service. Dependencies resolved from its factory sit
+/// deeper and see nothing, so a singleton built along the way can't capture
+/// one caller's values.
+pub(crate) fn current_arg<A: Any + Send + Sync>() -> Option<Arc<A>> {
+    let depth = crate::internal::resolution_depth();
+    ACTIVE_ARGS.with(|active| {
+        let active = active.borrow();
+        let (_, args) = active.iter().rev().find(|(base, _)| base + 1 == depth)?;
+        args.get::<A>()
+    })
+}
@@ -0,0 +1,64 @@
+//! Typed labels attached to registrations.
+
+use std::any::{Any, TypeId};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::Key;
+
+/// A label value and the registration it is attached to.
+type LabeledKey = (Arc<dyn Any + Send + Sync>, Key);
+
+/// Registrations grouped by label, keyed by the label's type and hash.
+#[derive(Default)]
+pub(crate) struct ServiceLabels {
+    by_label: HashMap<(TypeId, u64), Vec<LabeledKey>>,
+}
+
+impl ServiceLabels {
+    fn slot<L: Hash + 'static>(label: &L) -> (TypeId, u64) {
+        let mut hasher = DefaultHasher::new();
+        label.hash(&mut hasher);
+        (TypeId::of::<L>(), hasher.finish())
+    }
+
+    /// Attaches `label` to `key`.
+    pub(crate) fn insert<L: Eq + Hash + Send + Sync + 'static>(&mut self, key: Key, label: L) {
+        let entries = self.by_label.entry(Self::slot(&label)).or_default();
+        let exists = entries.iter()
+            .any(|(value, existing)| *existing == key && value.downcast_ref::<L>() == Some(&label));
+        if !exists {
+            entries.push((Arc::new(label), key));
+        }
+    }
+
+    /// Points labels on multi-bindings at their index after deduplication.
+    ///
+    /// `remapped` maps each trait's original binding indices to new ones, as
+    /// returned by `Registry::dedup_many_instances`.
+    pub(crate) fn remap_multi_bindings(&mut self, remapped: &HashMap<&'static str, Vec<usize>>) {
+        for (_, key) in self.by_label.values_mut().flatten() {
+            if let Key::MultiTrait(name, index) = key {
+                if let Some(&moved) = remapped.get(name).and_then(|indices| indices.get(*index)) {
+                    *index = moved;
+                }
+            }
+        }
+    }
+
+    /// Keys carrying `label`, in [`Key`] order.
+    pub(crate) fn keys_with<L: Eq + Hash + 'static>(&self, label: &L) -> Vec<Key> {
+        let mut keys: Vec<Key> = self.by_label.get(&Self::slot(label))
+            .into_iter()
+            .flatten()
+            .filter(|(value, _)| value.downcast_ref::<L>() == Some(label))
+            .map(|(_, key)| key.clone())
+            .collect();
+        keys.sort();
+        // A deduplicated binding can carry its duplicate's labels too
+        keys.dedup();
+        keys
+    }
+}
@@ -0,0 +1,156 @@
+//! Serializable snapshot of a provider's state for crash reports.
+//!
+//! [`ServiceProvider::dump_state`] collects what is registered, which
+//! singletons have been built, how many scopes are alive and the latest
+//! resolution events into a [`ContainerStateDump`] that can be written out as
+//! JSON alongside a panic message or a failed run's logs.
+
+use serde::Serialize;
+
+use crate::key::Key;
+use crate::lifetime::Lifetime;
+use crate::observer::ResolutionOutcome;
+use crate::ServiceProvider;
+
+/// Point-in-time state of a [`ServiceProvider`].
+///
+/// Produced by [`ServiceProvider::dump_state`]. Every field is plain data so
+/// the dump can outlive the provider it was taken from.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContainerStateDump {
+    /// One entry per registration, sorted by key.
+    pub descriptors: Vec<DescriptorDump>,
+    /// Labels of the singletons that have already been built, sorted.
+    pub warmed_singletons: Vec<String>,
+    /// Scopes created from the provider that have not been dropped yet.
+    pub live_scopes: usize,
+    /// Latest resolutions, oldest first, if an observer keeping a history
+    /// (such as [`RingBufferObserver`](crate::RingBufferObserver)) is attached.
+    pub recent_events: Option<Vec<EventDump>>,
+}
+
+/// A registration as recorded in a [`ContainerStateDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DescriptorDump {
+    /// Label of the service key, e.g. `app::Cache` or `dyn app::Tool#1`.
+    pub service: String,
+    /// The key variant (`Type`, `TraitNamed`, ...).
+    pub key_kind: String,
+    /// `Singleton`, `Scoped` or `Transient`.
+    pub lifetime: String,
+    /// How the registration was made (`Instance`, `Factory`, ...).
+    pub registration_kind: String,
+}
+
+/// A resolution event as recorded in a [`ContainerStateDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct EventDump {
+    /// Label of the resolved service key.
+    pub service: String,
+    /// Time spent in the factory, in microseconds.
+    pub duration_micros: u64,
+    /// `resolved`, or `panicked: <message>`.
+    pub outcome: String,
+}
+
+impl ContainerStateDump {
+    /// Serializes the dump as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("state dump is plain data")
+    }
+}
+
+/// Label for `key` in a dump: the type or trait name, then the service name
+/// in brackets and the multi-binding index after `#`.
+fn key_label(key: &Key) -> String {
+    let mut label = key.display_name().to_string();
+    if let Some(name) = key.service_name() {
+        label.push_str(&format!("[{}]", name));
+    }
+    match key {
+        Key::MultiTrait(_, index) | Key::MultiTraitNamed(_, _, index) => {
+            label.push_str(&format!("#{}", index));
+        }
+        Key::TraitDiscriminated(_, discriminant) => {
+            label.push_str(&format!("[{:?}]", discriminant));
+        }
+        _ => {}
+    }
+    label
+}
+
+impl ServiceProvider {
+    /// Snapshot of the provider's registrations, built singletons, live
+    /// scopes and recent resolutions, for inclusion in a crash report.
+    ///
+    /// Taking the dump never resolves anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct Config;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Config);
+    ///
+    /// let provider = services.build();
+    /// provider.get_required::<Config>();
+    ///
+    /// let dump = provider.dump_state();
+    /// assert_eq!(dump.warmed_singletons.len(), 1);
+    /// assert!(dump.to_json().contains("\"live_scopes\": 0"));
+    /// ```
+    pub fn dump_state(&self) -> ContainerStateDump {
+        let inner = self.inner();
+
+        let mut descriptors: Vec<(Key, DescriptorDump)> = Vec::new();
+        let mut warmed: Vec<Key> = Vec::new();
+        for (key, reg) in inner.registry.iter() {
+            if reg.lifetime == Lifetime::Singleton && reg.cached_singleton().is_some() {
+                warmed.push(key.clone());
+            }
+            descriptors.push((key.clone(), DescriptorDump {
+                service: key_label(key),
+                key_kind: format!("{:?}", key.kind()),
+                lifetime: format!("{:?}", reg.lifetime),
+                registration_kind: format!("{:?}", reg.kind),
+            }));
+        }
+        for (trait_name, regs) in &inner.registry.many {
+            for (index, reg) in regs.iter().enumerate() {
+                let key = Key::MultiTrait(trait_name, index);
+                descriptors.push((key.clone(), DescriptorDump {
+                    service: key_label(&key),
+                    key_kind: format!("{:?}", key.kind()),
+                    lifetime: format!("{:?}", reg.lifetime),
+                    registration_kind: format!("{:?}", reg.kind),
+                }));
+            }
+        }
+        warmed.extend(self.lock_singletons().keys().cloned());
+
+        descriptors.sort_by(|(a, _), (b, _)| a.cmp(b));
+        warmed.sort();
+
+        ContainerStateDump {
+            descriptors: descriptors.into_iter().map(|(_, descriptor)| descriptor).collect(),
+            warmed_singletons: warmed.iter().map(key_label).collect(),
+            live_scopes: self.live_scope_count(),
+            recent_events: inner.observers.recent_events().map(|events| {
+                events.into_iter()
+                    .map(|(key, duration, outcome)| EventDump {
+                        service: key_label(&key),
+                        duration_micros: duration.as_micros() as u64,
+                        outcome: match outcome {
+                            ResolutionOutcome::Resolved => "resolved".to_string(),
+                            ResolutionOutcome::Failed(message) => format!("failed: {}", message),
+                            ResolutionOutcome::Panicked(message) => format!("panicked: {}", message),
+                        },
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
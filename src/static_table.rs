@@ -0,0 +1,113 @@
+//! Allocation-free lookup of statically defined singletons.
+//!
+//! [`StaticServiceTable`] is a parallel API to [`ServiceProvider`](crate::ServiceProvider)
+//! for resource-constrained runtimes. Services are `'static` instances listed
+//! in a `static` array, so there are no factories, no reference counts and no
+//! hashing. Resolution is a binary search that returns a `&'static T` without
+//! allocating.
+
+use std::any::{Any, TypeId};
+
+/// One entry of a static registration manifest.
+///
+/// Built with the `const fn` [`StaticService::new`], so a whole manifest can
+/// be a `static` array.
+#[derive(Clone, Copy)]
+pub struct StaticService {
+    type_id: fn() -> TypeId,
+    type_name: fn() -> &'static str,
+    instance: &'static (dyn Any + Send + Sync),
+}
+
+impl StaticService {
+    /// Registers `instance` as the singleton for `T`.
+    pub const fn new<T: Any + Send + Sync>(instance: &'static T) -> Self {
+        Self {
+            type_id: TypeId::of::<T>,
+            type_name: std::any::type_name::<T>,
+            instance,
+        }
+    }
+
+    /// Name of the registered type.
+    pub fn type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
+}
+
+/// Read-only singleton table with O(log n) resolution and no per-resolution
+/// allocation.
+///
+/// The table sorts its manifest once when created. When a type is listed more
+/// than once, the last entry wins, as with repeated registrations in a
+/// [`ServiceCollection`](crate::ServiceCollection).
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{StaticService, StaticServiceTable};
+///
+/// struct Config { retries: u32 }
+/// struct Clock;
+///
+/// static CONFIG: Config = Config { retries: 3 };
+/// static CLOCK: Clock = Clock;
+/// static MANIFEST: [StaticService; 2] = [
+///     StaticService::new(&CONFIG),
+///     StaticService::new(&CLOCK),
+/// ];
+///
+/// let table = StaticServiceTable::new(&MANIFEST);
+/// assert_eq!(table.get::<Config>().unwrap().retries, 3);
+/// assert!(table.get::<String>().is_none());
+/// ```
+pub struct StaticServiceTable {
+    entries: Box<[(TypeId, &'static (dyn Any + Send + Sync))]>,
+}
+
+impl StaticServiceTable {
+    /// Builds a table from a manifest.
+    pub fn new(manifest: &[StaticService]) -> Self {
+        let mut entries: Vec<_> = manifest.iter()
+            .rev()
+            .map(|service| ((service.type_id)(), service.instance))
+            .collect();
+        // Stable sort over the reversed manifest keeps the last listing first
+        entries.sort_by_key(|(id, _)| *id);
+        entries.dedup_by_key(|(id, _)| *id);
+        Self { entries: entries.into_boxed_slice() }
+    }
+
+    /// Returns the instance registered for `T`, if any.
+    #[inline]
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&'static T> {
+        let id = TypeId::of::<T>();
+        let index = self.entries.binary_search_by_key(&id, |(entry, _)| *entry).ok()?;
+        self.entries[index].1.downcast_ref::<T>()
+    }
+
+    /// Returns the instance registered for `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not in the table.
+    pub fn get_required<T: Any + Send + Sync>(&self) -> &'static T {
+        self.get::<T>()
+            .unwrap_or_else(|| panic!("{} is not in the static service table", std::any::type_name::<T>()))
+    }
+
+    /// Returns true if `T` is in the table.
+    pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+        self.get::<T>().is_some()
+    }
+
+    /// Number of distinct services in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the table holds no services.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
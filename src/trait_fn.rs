@@ -0,0 +1,66 @@
+//! Closures as single-method trait implementations.
+//!
+//! Adapter traits with one method often have implementations that are a
+//! single expression. [`trait_fn!`](crate::trait_fn) declares how a closure
+//! maps onto such a trait, after which
+//! [`ServiceCollection::add_singleton_trait_fn`](crate::ServiceCollection::add_singleton_trait_fn)
+//! registers closures directly, without a named struct per implementation.
+
+use std::sync::Arc;
+
+/// Builds a trait object from a closure of type `F`.
+///
+/// Implemented for `dyn Trait` by [`trait_fn!`](crate::trait_fn); there is
+/// rarely a reason to implement it by hand.
+pub trait TraitFn<F>: Send + Sync {
+    /// Wraps `f` in an implementation of the trait.
+    fn from_fn(f: F) -> Arc<Self>;
+}
+
+/// Declares that closures can implement a single-method trait.
+///
+/// The method signature is written as in the trait. Closures then take the
+/// method's arguments, without `&self`, and return its result.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{trait_fn, Resolver, ServiceCollection};
+///
+/// trait Greeter: Send + Sync {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// trait_fn!(dyn Greeter => fn greet(&self, name: &str) -> String);
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton_trait_fn::<dyn Greeter, _>(|name: &str| format!("Hello, {}!", name));
+///
+/// let provider = services.build();
+/// let greeter = provider.get_required_trait::<dyn Greeter>();
+/// assert_eq!(greeter.greet("Ada"), "Hello, Ada!");
+/// ```
+#[macro_export]
+macro_rules! trait_fn {
+    (dyn $trait:path => fn $method:ident(&self $(, $arg:ident : $ty:ty)* $(,)?) $(-> $ret:ty)?) => {
+        impl<F> $crate::TraitFn<F> for dyn $trait
+        where
+            F: Fn($($ty),*) $(-> $ret)? + Send + Sync + 'static,
+        {
+            fn from_fn(f: F) -> std::sync::Arc<Self> {
+                struct FnImpl<F>(F);
+
+                impl<F> $trait for FnImpl<F>
+                where
+                    F: Fn($($ty),*) $(-> $ret)? + Send + Sync + 'static,
+                {
+                    fn $method(&self $(, $arg: $ty)*) $(-> $ret)? {
+                        (self.0)($($arg),*)
+                    }
+                }
+
+                std::sync::Arc::new(FnImpl(f))
+            }
+        }
+    };
+}
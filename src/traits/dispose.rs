@@ -1,5 +1,13 @@
 //! Disposal traits for resource cleanup.
 
+use std::any::TypeId;
+use std::sync::Arc;
+
+use crate::internal::BoxFutureUnit;
+use crate::provider::WeakProvider;
+use crate::traits::{Resolver, ResolverCore};
+use crate::{DiError, DiResult, Key, Lifetime, ServiceProvider};
+
 /// Trait for synchronous resource disposal.
 ///
 /// Implement this trait for services that need structured teardown (e.g., flushing caches,
@@ -71,4 +79,284 @@ pub trait Dispose: Send + Sync + 'static {
 pub trait AsyncDispose: Send + Sync + 'static {
     /// Perform asynchronous cleanup of resources.
     async fn dispose(&self);
-}
\ No newline at end of file
+}
+
+/// Trait for synchronous disposal that needs other services.
+///
+/// Register with [`Resolver::register_disposer_with_context`]. The hook gets a
+/// [`DisposeContext`] that resolves singletons, for example to flush buffered
+/// data into a sink before shutdown.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{DisposeContext, DisposeWithContext, Resolver, ServiceCollection};
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Default)]
+/// struct Sink(Mutex<Vec<String>>);
+///
+/// #[derive(Default)]
+/// struct Buffer(Mutex<Vec<String>>);
+///
+/// impl DisposeWithContext for Buffer {
+///     fn dispose(&self, ctx: &DisposeContext) {
+///         if let Ok(sink) = ctx.get::<Sink>() {
+///             sink.0.lock().unwrap().append(&mut self.0.lock().unwrap());
+///         }
+///     }
+/// }
+///
+/// struct Recorder(Arc<Buffer>);
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_singleton(Sink::default());
+/// services.add_scoped_factory::<Recorder, _>(|r| {
+///     r.get_required::<Sink>(); // built now, so it is available at teardown
+///     let buffer = Arc::new(Buffer::default());
+///     r.register_disposer_with_context(buffer.clone());
+///     Recorder(buffer)
+/// });
+/// ```
+///
+/// [`Resolver::register_disposer_with_context`]: crate::Resolver::register_disposer_with_context
+pub trait DisposeWithContext: Send + Sync + 'static {
+    /// Perform synchronous cleanup, resolving singletons through `ctx`.
+    fn dispose(&self, ctx: &DisposeContext);
+}
+
+/// Access to singletons from a [`DisposeWithContext`] hook.
+///
+/// Scoped and transient services are unavailable during teardown and fail with
+/// [`DiError::LifetimeNotPermitted`]. Only singletons that were already built
+/// can be resolved; one that was never created fails with
+/// [`DiError::NotBuiltDuringDisposal`] rather than being started while the
+/// provider shuts down, and one whose own disposal hook already ran fails with
+/// [`DiError::AlreadyDisposed`]. The context doesn't keep the provider alive.
+pub struct DisposeContext {
+    provider: Option<WeakProvider>,
+}
+
+impl DisposeContext {
+    pub(crate) fn new(provider: Option<&ServiceProvider>) -> Self {
+        Self { provider: provider.map(ServiceProvider::downgrade) }
+    }
+
+    /// Resolves an already-built singleton of concrete type `T`.
+    pub fn get<T: 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let name = std::any::type_name::<T>();
+        let provider = self.singleton_provider(&Key::Type(TypeId::of::<T>(), name))?;
+        provider.get::<T>()
+    }
+
+    /// Resolves an already-built singleton trait service.
+    pub fn get_trait<T: ?Sized + 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        let provider = self.singleton_provider(&Key::Trait(std::any::type_name::<T>()))?;
+        provider.get_trait::<T>()
+    }
+
+    fn singleton_provider(&self, key: &Key) -> DiResult<ServiceProvider> {
+        let name = key.display_name();
+        let provider = self.provider.as_ref()
+            .and_then(WeakProvider::upgrade)
+            .ok_or(DiError::NotFound(name))?;
+        match provider.cache_status(key) {
+            Some((Lifetime::Singleton, true)) if provider.inner().disposed_keys.lock().unwrap().contains(key) => {
+                Err(DiError::AlreadyDisposed(name))
+            }
+            Some((Lifetime::Singleton, true)) => Ok(provider),
+            Some((Lifetime::Singleton, false)) => Err(DiError::NotBuiltDuringDisposal(name)),
+            Some((lifetime, _)) => Err(DiError::LifetimeNotPermitted { service: name, lifetime }),
+            None => Err(DiError::NotFound(name)),
+        }
+    }
+}
+
+/// Central listener told about every disposal hook as it runs.
+///
+/// Registered with
+/// [`ServiceCollection::on_dispose`](crate::ServiceCollection::on_dispose).
+/// Where [`Dispose`] is implemented per service, a dispose observer sees all
+/// of them, which suits connection managers and similar bookkeeping that
+/// needs to track teardown across the container.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{Dispose, DisposeObserver, Key, Resolver, ServiceCollection};
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Default)]
+/// struct Teardown(Mutex<Vec<&'static str>>);
+/// impl DisposeObserver for Teardown {
+///     fn disposed(&self, key: &Key) {
+///         self.0.lock().unwrap().push(key.display_name());
+///     }
+/// }
+///
+/// struct Connection;
+/// impl Dispose for Connection {
+///     fn dispose(&self) {}
+/// }
+///
+/// # async fn example() {
+/// let teardown = Arc::new(Teardown::default());
+/// let mut services = ServiceCollection::new();
+/// services.on_dispose(teardown.clone());
+/// services.add_scoped_factory::<Connection, _>(|r| {
+///     let conn = Arc::new(Connection);
+///     r.register_disposer(conn.clone());
+///     Connection
+/// });
+///
+/// let provider = services.build();
+/// let scope = provider.create_scope();
+/// scope.get_required::<Connection>();
+/// scope.dispose_all().await;
+/// assert_eq!(*teardown.0.lock().unwrap(), [std::any::type_name::<Connection>()]);
+/// # }
+/// ```
+pub trait DisposeObserver: Send + Sync {
+    /// Called after the disposal hook registered for `key` has run.
+    ///
+    /// The key is that of the concrete type passed to
+    /// [`register_disposer`](crate::Resolver::register_disposer) or its
+    /// async and context variants.
+    fn disposed(&self, key: &Key);
+}
+
+/// Wraps a disposal hook so the provider's dispose observers hear about it
+/// after it runs, and so [`DisposeContext`] refuses `key` from then on.
+pub(crate) fn observed_hook(
+    provider: Option<&ServiceProvider>,
+    key: Key,
+    hook: Box<dyn FnOnce() + Send>,
+) -> Box<dyn FnOnce() + Send> {
+    let Some(provider) = provider else { return hook };
+    let observers = provider.inner().dispose_observers.clone();
+    let disposed = provider.inner().disposed_keys.clone();
+    Box::new(move || {
+        hook();
+        for observer in observers.iter() {
+            observer.disposed(&key);
+        }
+        disposed.lock().unwrap().insert(key);
+    })
+}
+
+/// Synchronous disposal hook for `service`.
+pub(crate) fn sync_hook<T: Dispose>(provider: Option<&ServiceProvider>, service: Arc<T>) -> Box<dyn FnOnce() + Send> {
+    let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+    observed_hook(provider, key, Box::new(move || service.dispose()))
+}
+
+/// Asynchronous disposal hook for `service`.
+pub(crate) fn async_hook<T: AsyncDispose>(
+    provider: Option<&ServiceProvider>,
+    service: Arc<T>,
+) -> Box<dyn FnOnce() -> BoxFutureUnit + Send> {
+    let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+    let observers = provider.map(|p| p.inner().dispose_observers.clone());
+    let disposed = provider.map(|p| p.inner().disposed_keys.clone());
+    Box::new(move || Box::pin(async move {
+        service.dispose().await;
+        for observer in observers.iter().flat_map(|o| o.iter()) {
+            observer.disposed(&key);
+        }
+        if let Some(disposed) = disposed {
+            disposed.lock().unwrap().insert(key);
+        }
+    }))
+}
+
+/// Error reported by a disposal hook that failed during `dispose_all()`.
+///
+/// Disposal hooks don't return errors, so a failure is a hook that panicked.
+/// The panic is caught, recorded here, and the remaining hooks still run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisposeError {
+    /// The panic message of the failing hook
+    pub message: String,
+}
+
+impl DisposeError {
+    /// Builds an error from a caught panic payload.
+    pub(crate) fn from_panic(payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            (*s).to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "disposal hook panicked".to_string()
+        };
+        Self { message }
+    }
+}
+
+impl std::fmt::Display for DisposeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Disposal hook failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for DisposeError {}
+
+/// Summary of a `dispose_all()` call.
+///
+/// Lets callers (and tests) confirm how many disposal hooks actually ran.
+///
+/// # Examples
+///
+/// ```
+/// use ferrous_di::{ServiceCollection, Dispose, Resolver};
+/// use std::sync::Arc;
+///
+/// struct Cache;
+/// impl Dispose for Cache {
+///     fn dispose(&self) {}
+/// }
+///
+/// # async fn example() {
+/// let mut services = ServiceCollection::new();
+/// services.add_scoped_factory::<Cache, _>(|r| {
+///     r.register_disposer(Arc::new(Cache));
+///     Cache
+/// });
+///
+/// let provider = services.build();
+/// let scope = provider.create_scope();
+/// let _cache = scope.get_required::<Cache>();
+///
+/// let summary = scope.dispose_all().await;
+/// assert_eq!(summary.sync_run, 1);
+/// assert!(summary.is_clean());
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisposalSummary {
+    /// Number of synchronous hooks that were run
+    pub sync_run: usize,
+    /// Number of asynchronous hooks that were run
+    pub async_run: usize,
+    /// Failures raised by individual hooks
+    pub errors: Vec<DisposeError>,
+}
+
+impl DisposalSummary {
+    /// Total number of hooks run, sync and async.
+    pub fn total_run(&self) -> usize {
+        self.sync_run + self.async_run
+    }
+
+    /// Returns true if no hook failed.
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Folds another summary into this one.
+    pub(crate) fn merge(&mut self, other: DisposalSummary) {
+        self.sync_run += other.sync_run;
+        self.async_run += other.async_run;
+        self.errors.extend(other.errors);
+    }
+}
@@ -3,5 +3,6 @@
 mod dispose;
 mod resolver;
 
-pub use dispose::{Dispose, AsyncDispose};
-pub use resolver::{Resolver, ResolverCore};
\ No newline at end of file
+pub use dispose::{Dispose, AsyncDispose, DisposeWithContext, DisposeContext, DisposeObserver, DisposalSummary, DisposeError};
+pub(crate) use dispose::{async_hook, observed_hook, sync_hook};
+pub use resolver::{Resolver, ResolverCore, ResolutionInfo};
\ No newline at end of file
@@ -4,6 +4,7 @@ use std::any::TypeId;
 use std::sync::Arc;
 use crate::error::DiResult;
 use crate::key::Key;
+use crate::lifetime::Lifetime;
 use crate::traits::{Dispose, AsyncDispose};
 use crate::internal::BoxFutureUnit;
 
@@ -61,12 +62,73 @@ pub trait ResolverCore: Send + Sync {
         self.resolve_many(key)
     }
 
+    /// Reports the lifetime behind `key` and whether an instance is already cached.
+    ///
+    /// Used by [`Resolver::get_with_info`]. Resolvers without access to a
+    /// registry return `None`, which is the default.
+    fn cache_status(&self, key: &Key) -> Option<(Lifetime, bool)> {
+        let _ = key;
+        None
+    }
+
+    /// Returns the key/value labels of the scope doing the resolving.
+    ///
+    /// Scopes always have labels (possibly empty); the root provider and
+    /// other resolvers return `None`, which is the default.
+    fn scope_labels(&self) -> Option<&crate::ScopeLabels> {
+        None
+    }
+
+    /// Returns the deadline of the scope doing the resolving, if it has one.
+    ///
+    /// Set with [`Scope::with_deadline`](crate::Scope::with_deadline). Long
+    /// running async factories can check it to give up early.
+    fn deadline(&self) -> Option<std::time::Instant> {
+        None
+    }
+
+    /// Returns the id of the scope doing the resolving.
+    ///
+    /// Matches [`Scope::id`](crate::Scope::id). The root provider and other
+    /// resolvers return `None`, which is the default.
+    fn scope_id(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the root provider this resolver belongs to.
+    ///
+    /// Used to hand disposal hooks a [`DisposeContext`](crate::DisposeContext).
+    /// Resolvers not backed by a provider return `None`, which is the default.
+    fn provider(&self) -> Option<&crate::ServiceProvider> {
+        None
+    }
+
+    /// Returns another handle to the scope doing the resolving, sharing its
+    /// scoped instances and disposal bag.
+    ///
+    /// Used by [`Resolver::get_factory`] to keep building in the scope the
+    /// handle came from. The root provider and other resolvers return
+    /// `None`, which is the default.
+    fn share_scope(&self) -> Option<crate::Scope> {
+        None
+    }
+
     /// Registers a synchronous disposal hook.
     ///
     /// Used internally by factories to register disposal callbacks that will be
     /// executed when the containing scope or provider is disposed.
     fn push_sync_disposer(&self, f: Box<dyn FnOnce() + Send>);
 
+    /// Registers a synchronous disposal hook that runs before hooks of lower
+    /// priority in the same scope or provider.
+    ///
+    /// Resolvers without their own disposal bag ignore the priority, which is
+    /// the default.
+    fn push_sync_disposer_with_priority(&self, priority: i32, f: Box<dyn FnOnce() + Send>) {
+        let _ = priority;
+        self.push_sync_disposer(f);
+    }
+
     /// Registers an asynchronous disposal hook.
     ///
     /// Used internally by factories to register async disposal callbacks that will be
@@ -74,6 +136,17 @@ pub trait ResolverCore: Send + Sync {
     fn push_async_disposer(&self, f: Box<dyn FnOnce() -> BoxFutureUnit + Send>);
 }
 
+/// Details about a single resolution, returned by [`Resolver::get_with_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionInfo {
+    /// Registered lifetime of the service
+    pub lifetime: Lifetime,
+    /// Whether an already-created instance was returned
+    pub cache_hit: bool,
+    /// Time spent resolving, including any factory calls
+    pub duration: std::time::Duration,
+}
+
 /// High-level resolver interface with generic methods for type-safe service resolution.
 ///
 /// This trait provides the main API that users interact with for resolving services.
@@ -150,9 +223,12 @@ pub trait Resolver: ResolverCore {
     
     /// Resolves a single trait implementation.
     ///
-    /// Returns the most recently registered implementation for the trait `T`.
-    /// If multiple implementations are registered, this returns the last one.
-    /// For accessing all implementations, use [`get_all_trait`](Self::get_all_trait).
+    /// Returns the single-binding registration for the trait `T`, replacing
+    /// earlier single bindings. Without one, a lone multi-binding is used; if
+    /// several named or multi-bound registrations could match instead, this
+    /// fails with [`DiError::AmbiguousRegistration`](crate::DiError::AmbiguousRegistration)
+    /// rather than picking one. For accessing all implementations, use
+    /// [`get_all_trait`](Self::get_all_trait).
     ///
     /// # Type Parameters
     ///
@@ -259,7 +335,156 @@ pub trait Resolver: ResolverCore {
         }
         Ok(results)
     }
+
+    /// Resolves trait `T`, requiring that exactly one implementation is registered.
+    ///
+    /// [`get_trait`](Self::get_trait) returns the single binding of `T` even
+    /// when multi-bindings of the same trait exist, and falls back to a lone
+    /// multi-binding when there is no single binding. Either way a logically
+    /// singular trait that was also registered through the multi-binding API
+    /// goes unnoticed. This counts the single binding and every multi-binding
+    /// of `T` first.
+    ///
+    /// The count reads the registry of [`provider`](ResolverCore::provider),
+    /// so resolvers that don't expose one skip the check and behave exactly
+    /// like `get_trait`. Unlike [`get_required_trait`](Self::get_required_trait),
+    /// "required" here refers to the singular contract: violations are
+    /// returned as errors rather than panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiError::ExpectedSingleImplementation`](crate::DiError::ExpectedSingleImplementation)
+    /// if more than one registration exists, and the errors of `get_trait`
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{DiError, Lifetime, Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// trait Clock: Send + Sync {}
+    /// struct SystemClock;
+    /// impl Clock for SystemClock {}
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation::<dyn Clock>(Arc::new(SystemClock), Lifetime::Singleton);
+    /// services.add_trait_implementation::<dyn Clock>(Arc::new(SystemClock), Lifetime::Singleton);
+    ///
+    /// let provider = services.build();
+    /// assert!(matches!(
+    ///     provider.get_required_single_trait::<dyn Clock>(),
+    ///     Err(DiError::ExpectedSingleImplementation { found: 2, .. })
+    /// ));
+    /// ```
+    fn get_required_single_trait<T: ?Sized + 'static + Send + Sync>(&self) -> DiResult<Arc<T>>
+    where
+        Arc<T>: 'static,
+    {
+        let trait_name = std::any::type_name::<T>();
+        if let Some(provider) = self.provider() {
+            let registry = &provider.inner().registry;
+            let found = usize::from(registry.contains_key(&Key::Trait(trait_name)))
+                + registry.many.get(trait_name).map_or(0, Vec::len);
+            if found > 1 {
+                return Err(crate::error::DiError::ExpectedSingleImplementation { trait_name, found });
+            }
+        }
+        self.get_trait::<T>()
+    }
     
+    /// Resolves a concrete service type along with details about the resolution.
+    ///
+    /// Handy for one-off investigations where attaching an observer is
+    /// overkill: it reports the registered lifetime, whether a cached instance
+    /// was returned, and how long resolution took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{ServiceCollection, Resolver, Lifetime};
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_factory::<String, _>(|_| "config".to_string());
+    /// let provider = services.build();
+    ///
+    /// let (_, first) = provider.get_with_info::<String>().unwrap();
+    /// let (_, second) = provider.get_with_info::<String>().unwrap();
+    /// assert_eq!(first.lifetime, Lifetime::Singleton);
+    /// assert!(!first.cache_hit);
+    /// assert!(second.cache_hit);
+    /// ```
+    fn get_with_info<T: 'static + Send + Sync>(&self) -> DiResult<(Arc<T>, ResolutionInfo)> {
+        let key = crate::key::key_of_type::<T>();
+        let status = self.cache_status(&key);
+        let start = std::time::Instant::now();
+        let value = self.get::<T>()?;
+        let duration = start.elapsed();
+
+        let (lifetime, cache_hit) = status
+            .ok_or(crate::error::DiError::NotFound(std::any::type_name::<T>()))?;
+        Ok((value, ResolutionInfo { lifetime, cache_hit, duration }))
+    }
+
+    /// Builds a fresh `T` with some of its dependencies replaced for this call.
+    ///
+    /// `T`'s factory runs once, ignoring its lifetime and any cached instance,
+    /// and its own requests for keys in `overrides` receive the supplied
+    /// instances. Everything else, including the dependencies of dependencies,
+    /// resolves normally, and the result is not cached, so other resolutions
+    /// never see the overrides. See [`OverrideSet`](crate::OverrideSet).
+    fn get_with_overrides<T: 'static + Send + Sync>(&self, overrides: crate::OverrideSet) -> DiResult<Arc<T>>
+    where
+        Self: Sized,
+    {
+        crate::overrides::resolve_with_overrides::<T>(self, &overrides)
+    }
+
+    /// Returns a handle that creates instances of `T` on demand.
+    ///
+    /// This is the "inject a factory" pattern: a service that needs many `T`s
+    /// (one worker per item, say) holds the handle instead of a resolver. For
+    /// transient registrations every call builds a fresh `T` whose singleton
+    /// and scoped dependencies were resolved once, when the handle was
+    /// created, and are shared by all instances; transient dependencies are
+    /// built anew from the scope the handle came from (or the root provider),
+    /// and their disposal hooks are registered there. For singleton and
+    /// scoped registrations the handle always returns the resolved instance.
+    ///
+    /// Creating the handle runs a transient factory once to discover its
+    /// dependencies, and that instance is returned by the first call.
+    ///
+    /// # Errors
+    ///
+    /// Each call of the handle returns the error of a failed construction
+    /// instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// struct Database;
+    /// struct Worker { db: Arc<Database> }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Database);
+    /// services.add_transient_factory::<Worker, _>(|r| Worker { db: r.get_required::<Database>() });
+    ///
+    /// let provider = services.build();
+    /// let make_worker = provider.get_factory::<Worker>().unwrap();
+    /// let (a, b) = (make_worker().unwrap(), make_worker().unwrap());
+    /// assert!(!Arc::ptr_eq(&a, &b));
+    /// assert!(Arc::ptr_eq(&a.db, &b.db));
+    /// ```
+    fn get_factory<T: 'static + Send + Sync>(&self) -> DiResult<crate::ServiceFactory<T>>
+    where
+        Self: Sized,
+    {
+        crate::overrides::factory_handle::<T>(self)
+    }
+
     /// Resolves a concrete service type, panicking on failure.
     ///
     /// This is a convenience method that calls [`get`](Self::get) and panics if
@@ -381,7 +606,46 @@ pub trait Resolver: ResolverCore {
     /// });
     /// ```
     fn register_disposer<T: Dispose>(&self, service: Arc<T>) {
-        self.push_sync_disposer(Box::new(move || service.dispose()));
+        self.push_sync_disposer(crate::traits::sync_hook(self.provider(), service));
+    }
+
+    /// Registers a service for synchronous disposal ahead of lower-priority
+    /// services, whatever order they were resolved in.
+    ///
+    /// Hooks registered with [`register_disposer`](Self::register_disposer)
+    /// have priority 0. Within one priority, hooks run in reverse
+    /// registration order as usual. Async hooks still run before all sync
+    /// hooks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Dispose, ServiceCollection, Resolver};
+    /// use std::sync::Arc;
+    ///
+    /// struct Writer;
+    /// impl Dispose for Writer {
+    ///     fn dispose(&self) { /* close the file */ }
+    /// }
+    /// struct Buffer;
+    /// impl Dispose for Buffer {
+    ///     fn dispose(&self) { /* flush into the writer */ }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_factory::<Buffer, _>(|r| {
+    ///     // Flushed before the writer closes, even though it is resolved first
+    ///     r.register_disposer_with_priority(Arc::new(Buffer), 10);
+    ///     r.get_required::<Writer>();
+    ///     Buffer
+    /// });
+    /// services.add_scoped_factory::<Writer, _>(|r| {
+    ///     r.register_disposer(Arc::new(Writer));
+    ///     Writer
+    /// });
+    /// ```
+    fn register_disposer_with_priority<T: Dispose>(&self, service: Arc<T>, priority: i32) {
+        self.push_sync_disposer_with_priority(priority, crate::traits::sync_hook(self.provider(), service));
     }
 
     /// Registers a service for asynchronous disposal.
@@ -421,9 +685,19 @@ pub trait Resolver: ResolverCore {
     /// });
     /// ```
     fn register_async_disposer<T: AsyncDispose>(&self, service: Arc<T>) {
-        self.push_async_disposer(Box::new(move || Box::pin(async move {
-            service.dispose().await;
-        })));
+        self.push_async_disposer(crate::traits::async_hook(self.provider(), service));
+    }
+
+    /// Registers a service for synchronous disposal with access to singletons.
+    ///
+    /// Like [`register_disposer`](Self::register_disposer), but the hook
+    /// receives a [`DisposeContext`](crate::DisposeContext) for flushing to
+    /// other services during teardown.
+    fn register_disposer_with_context<T: crate::DisposeWithContext>(&self, service: Arc<T>) {
+        let context = crate::DisposeContext::new(self.provider());
+        let key = Key::Type(TypeId::of::<T>(), std::any::type_name::<T>());
+        let hook = Box::new(move || service.dispose(&context));
+        self.push_sync_disposer(crate::traits::observed_hook(self.provider(), key, hook));
     }
     
     // Named service resolution methods continue...
@@ -451,6 +725,21 @@ pub trait Resolver: ResolverCore {
             .map_err(|_| crate::error::DiError::TypeMismatch(std::any::type_name::<T>()))
     }
     
+    /// Acquires an instance from a pool registered with
+    /// [`ServiceCollection::add_pooled`](crate::ServiceCollection::add_pooled).
+    ///
+    /// Returns an idle instance when one exists, otherwise the least recently
+    /// acquired one. The instance goes back to the pool when the returned
+    /// `Arc` (and every clone of it) is dropped.
+    fn get_pooled<T: 'static + Send + Sync>(&self) -> DiResult<Arc<T>> {
+        self.get::<crate::pool::ServicePool<T>>()
+            .map(|pool| pool.acquire())
+            .map_err(|e| match e {
+                crate::error::DiError::NotFound(_) => crate::error::DiError::NotFound(std::any::type_name::<T>()),
+                other => other,
+            })
+    }
+
     /// Resolves a named concrete service type, panicking on failure.
     fn get_named_required<T: 'static + Send + Sync>(&self, name: &'static str) -> Arc<T> {
         self.get_named::<T>(name)
@@ -477,4 +766,20 @@ pub trait Resolver: ResolverCore {
         self.get_named_trait::<T>(name)
             .unwrap_or_else(|e| panic!("Failed to resolve named trait {} ({}): {:?}", std::any::type_name::<T>(), name, e))
     }
+
+    /// Resolves a trait implementation registered under a typed discriminant.
+    ///
+    /// See [`ServiceCollection::add_discriminated_trait`](crate::ServiceCollection::add_discriminated_trait).
+    fn get_discriminated_trait<T, D>(&self, kind: D) -> DiResult<Arc<T>>
+    where
+        T: ?Sized + 'static + Send + Sync,
+        D: std::hash::Hash + Eq + Send + Sync + 'static,
+        Arc<T>: 'static,
+    {
+        let key = Key::TraitDiscriminated(std::any::type_name::<T>(), crate::key::Discriminant::new(kind));
+        let any = self.resolve_any_internal(&key)?;
+        any.downcast::<Arc<T>>()
+            .map(|boxed| (*boxed).clone())
+            .map_err(|_| crate::error::DiError::TypeMismatch(std::any::type_name::<T>()))
+    }
 }
\ No newline at end of file
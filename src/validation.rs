@@ -5,9 +5,11 @@
 //! where configuration errors can cause agent failures in production.
 
 use std::any::{TypeId, type_name};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::marker::PhantomData;
-use crate::{ServiceCollection, Lifetime};
+use crate::{DiError, Key, Lifetime, ServiceCollection, ServiceProvider};
+use crate::internal::{record_dependency_edges, with_circular_catch};
+use crate::traits::ResolverCore;
 
 /// Compile-time validation context for DI registrations.
 ///
@@ -46,6 +48,7 @@ pub struct ValidationBuilder<State = Initial> {
     registrations: HashMap<TypeId, LifetimeInfo>,
     dependencies: HashMap<TypeId, Vec<TypeId>>,
     trait_registrations: HashMap<&'static str, Vec<TypeId>>, 
+    init_origins: HashMap<TypeId, u64>,
     _state: PhantomData<State>,
 }
 
@@ -124,6 +127,12 @@ pub enum ValidationWarning {
         trait_name: &'static str,
         implementations: Vec<&'static str>,
     },
+    /// Singleton first initialized inside a non-root scope while depending on a scoped service
+    SingletonInitializedInScope {
+        singleton: &'static str,
+        scope_id: u64,
+        scoped: &'static str,
+    },
 }
 
 impl ValidationBuilder<Initial> {
@@ -133,6 +142,7 @@ impl ValidationBuilder<Initial> {
             registrations: HashMap::new(),
             dependencies: HashMap::new(),
             trait_registrations: HashMap::new(),
+            init_origins: HashMap::new(),
             _state: PhantomData,
         }
     }
@@ -202,6 +212,20 @@ impl ValidationBuilder<Initial> {
         self
     }
 
+    /// Imports the scope each registered singleton was first initialized in.
+    ///
+    /// With origins available, runtime validation warns about singletons that
+    /// were created inside a non-root scope while depending on scoped services.
+    pub fn with_init_origins(mut self, provider: &crate::ServiceProvider) -> Self {
+        for (type_id, info) in &self.registrations {
+            let key = crate::Key::Type(*type_id, info.type_name);
+            if let Some(scope_id) = provider.singleton_init_origin(&key) {
+                self.init_origins.insert(*type_id, scope_id);
+            }
+        }
+        self
+    }
+
     /// Performs validation and transitions to validated state.
     pub fn validate(self) -> ValidationBuilder<Validated> {
         // For compile-time validation, we'll use a simpler approach
@@ -210,6 +234,7 @@ impl ValidationBuilder<Initial> {
             registrations: self.registrations,
             dependencies: self.dependencies,
             trait_registrations: self.trait_registrations,
+            init_origins: self.init_origins,
             _state: PhantomData,
         }
     }
@@ -233,6 +258,15 @@ impl ValidationBuilder<Initial> {
                                 scoped: dep_info.type_name,
                                 scoped_id: dep_id,
                             });
+
+                            // Initialized inside a scope (WARNING): it may have captured that scope's state
+                            if let Some(&scope_id) = self.init_origins.get(service_id) {
+                                warnings.push(ValidationWarning::SingletonInitializedInScope {
+                                    singleton: service_info.type_name,
+                                    scope_id,
+                                    scoped: dep_info.type_name,
+                                });
+                            }
                         }
                         
                         // Check singleton → transient dependency (WARNING)
@@ -486,6 +520,9 @@ impl ValidationResult {
             ValidationWarning::MultipleTraitImplementations { trait_name, implementations } => {
                 format!("Trait '{}' has multiple implementations: {}", trait_name, implementations.join(", "))
             }
+            ValidationWarning::SingletonInitializedInScope { singleton, scope_id, scoped } => {
+                format!("Singleton '{}' was first initialized in scope {} while depending on scoped '{}' - it may hold that scope's state", singleton, scope_id, scoped)
+            }
         }
     }
 }
@@ -526,4 +563,598 @@ impl ServiceCollection {
     pub fn validate(&self) -> ValidationResult {
         self.create_validator().validate_runtime()
     }
-}
\ No newline at end of file
+
+    /// Marks a service as expensive to create (connection pools, clients, caches).
+    ///
+    /// Expensive services should almost always be singletons; marking them lets
+    /// [`ServiceProvider::validate_lifetimes`] flag transients that pull them in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::ServiceCollection;
+    ///
+    /// struct DbPool;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(DbPool);
+    /// services.mark_expensive::<DbPool>();
+    /// ```
+    pub fn mark_expensive<T: ?Sized + 'static>(&mut self) -> &mut Self {
+        self.expensive.insert(type_name::<T>());
+        self
+    }
+
+    /// Enables the unused-service lint, run when the collection is built.
+    ///
+    /// Every registration, multi-bindings included, is resolved once and its
+    /// dependency edges recorded. Services that no other registration depends
+    /// on, that aren't prewarmed, and that aren't marked with
+    /// [`mark_root`](Self::mark_root) are reported as
+    /// [`DiError::UnusedService`] by [`ServiceProvider::build_warnings`].
+    ///
+    /// The lint resolves in a throwaway copy of the provider, so factories run
+    /// at build time but the built provider's singletons are not created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{DiError, ServiceCollection};
+    ///
+    /// struct App;
+    /// struct Leftover;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(App);
+    /// services.add_singleton(Leftover);
+    /// services.mark_root::<App>().lint_unused();
+    ///
+    /// let provider = services.build();
+    /// assert!(matches!(provider.build_warnings(), [DiError::UnusedService(_)]));
+    /// ```
+    pub fn lint_unused(&mut self) -> &mut Self {
+        self.lint_unused = true;
+        self
+    }
+
+    /// Enables the trait cast check, run when the collection is built.
+    ///
+    /// Trait registrations are stored type-erased and keyed by the trait's
+    /// name, so two registrations whose trait types print the same (such as
+    /// `dyn Handler<'static>` and `dyn for<'a> Handler<'a>`) share a key but
+    /// can't be downcast to each other. With the check enabled, every trait
+    /// registration is built once by a throwaway copy of the provider, so
+    /// neither the registration nor its dependencies end up cached in the
+    /// real one. A registration whose instance has a different erased type
+    /// than the first one built for the same trait, or whose factory itself
+    /// fails with a type mismatch, is reported as [`DiError::TypeMismatch`]
+    /// by [`ServiceProvider::build_warnings`]. Factories that fail otherwise
+    /// or panic are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Lifetime, ServiceCollection};
+    /// use std::sync::Arc;
+    ///
+    /// trait Tool: Send + Sync {}
+    /// struct Search;
+    /// impl Tool for Search {}
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_trait_implementation(Arc::new(Search) as Arc<dyn Tool>, Lifetime::Singleton);
+    /// services.validate_trait_casts();
+    ///
+    /// assert!(services.build().build_warnings().is_empty());
+    /// ```
+    pub fn validate_trait_casts(&mut self) -> &mut Self {
+        self.check_trait_casts = true;
+        self
+    }
+
+    /// Marks a service as an entry point the application resolves directly.
+    ///
+    /// Roots are never reported by [`lint_unused`](Self::lint_unused), even
+    /// though no other registration depends on them.
+    pub fn mark_root<T: ?Sized + 'static>(&mut self) -> &mut Self {
+        self.roots.insert(type_name::<T>());
+        self
+    }
+}
+
+/// Lifetime lints that need a built provider.
+impl ServiceProvider {
+    /// Flags transient services whose factories resolve a service marked with
+    /// [`ServiceCollection::mark_expensive`].
+    ///
+    /// Each transient is resolved once in a throwaway copy of the provider
+    /// while its dependency edges are recorded, so factories run as they
+    /// would at runtime but this provider's singletons are not created.
+    /// Findings are reported as [`DiError::SuspectTransient`]; they are
+    /// warnings and do not stop the provider from being used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{DiError, Resolver, ServiceCollection};
+    ///
+    /// struct DbPool;
+    /// struct Repository;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_transient_factory::<DbPool, _>(|_| DbPool);
+    /// services.add_transient_factory::<Repository, _>(|r| {
+    ///     let _pool = r.get_required::<DbPool>();
+    ///     Repository
+    /// });
+    /// services.mark_expensive::<DbPool>();
+    ///
+    /// let findings = services.build().validate_lifetimes();
+    /// assert!(matches!(findings[0], DiError::SuspectTransient { .. }));
+    /// ```
+    pub fn validate_lifetimes(&self) -> Vec<DiError> {
+        let expensive = &self.inner().expensive;
+        if expensive.is_empty() {
+            return Vec::new();
+        }
+
+        let transients: Vec<Key> = self.inner().registry.iter()
+            .filter(|(_, reg)| reg.lifetime == Lifetime::Transient)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        self.with_shadow_scope(|scope| {
+            let mut seen = HashSet::new();
+            let mut findings = Vec::new();
+            for key in transients {
+                let service = key.display_name();
+                let (_, edges) = record_dependency_edges(|| scope.resolve_any(&key));
+                for (dependent, dependency) in edges {
+                    if dependent == service
+                        && expensive.contains(dependency)
+                        && seen.insert((service, dependency))
+                    {
+                        findings.push(DiError::SuspectTransient { service, expensive_dep: dependency });
+                    }
+                }
+            }
+            findings
+        })
+    }
+
+    /// Flags singletons whose resolver-aware decorator resolves a scoped service.
+    ///
+    /// Decoration keeps the registration's lifetime, so a decorator added with
+    /// [`ServiceCollection::decorate_with`] or
+    /// [`ServiceCollection::decorate_trait_with`] that pulls in scoped state
+    /// only works while the singleton happens to be built inside a scope.
+    /// Each decorated singleton is built once with and once without its
+    /// decorators in a throwaway copy of the provider while dependency edges
+    /// are recorded; scoped services only the decorated build asks for are
+    /// reported as [`DiError::DecoratorLifetimeConflict`]. Neither the
+    /// decorated singletons nor their dependencies are created in this
+    /// provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{key_of_type, DiError, ServiceCollection, ServiceDecorator};
+    /// use ferrous_di::traits::ResolverCore;
+    /// use std::sync::Arc;
+    ///
+    /// struct Cache;
+    /// struct RequestContext;
+    /// struct Tracing;
+    ///
+    /// impl ServiceDecorator<Cache> for Tracing {
+    ///     fn decorate(&self, original: Arc<Cache>, resolver: &dyn ResolverCore) -> Arc<Cache> {
+    ///         let _ = resolver.resolve_any(&key_of_type::<RequestContext>());
+    ///         original
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Cache);
+    /// services.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    /// services.decorate_with::<Cache, _>(Tracing);
+    ///
+    /// let findings = services.build().validate_decorators();
+    /// assert!(matches!(findings[..], [DiError::DecoratorLifetimeConflict { .. }]));
+    /// ```
+    pub fn validate_decorators(&self) -> Vec<DiError> {
+        let registry = &self.inner().registry;
+        let lifetimes: HashMap<&'static str, Lifetime> = registry.iter()
+            .map(|(key, reg)| (key.display_name(), reg.lifetime))
+            .collect();
+
+        self.with_shadow_scope(|scope| {
+            let ctx = crate::registration::ResolverContext::new(scope);
+            let mut findings = Vec::new();
+            for (key, reg) in scope.root.inner().registry.iter() {
+                let Some(undecorated) = &reg.undecorated_ctor else { continue };
+                if reg.lifetime != Lifetime::Singleton {
+                    continue;
+                }
+                let service = key.display_name();
+                let dependencies_of = |ctor: &crate::registration::Ctor| -> BTreeSet<&'static str> {
+                    let (_, edges) = record_dependency_edges(|| with_circular_catch(service, || ctor(&ctx)));
+                    edges.into_iter()
+                        .filter(|(dependent, _)| *dependent == service)
+                        .map(|(_, dependency)| dependency)
+                        .collect()
+                };
+                let own = dependencies_of(undecorated);
+                for dependency in dependencies_of(&reg.ctor) {
+                    if !own.contains(dependency) && lifetimes.get(dependency) == Some(&Lifetime::Scoped) {
+                        findings.push(DiError::DecoratorLifetimeConflict { service, scoped_dep: dependency });
+                    }
+                }
+            }
+            findings
+        })
+    }
+
+    /// Lint findings collected while the provider was built.
+    ///
+    /// Empty unless a lint such as [`ServiceCollection::lint_unused`] was
+    /// enabled. Findings are warnings; the provider is fully usable.
+    pub fn build_warnings(&self) -> &[DiError] {
+        &self.inner().build_warnings
+    }
+
+    /// Scoped services that no other registration depends on.
+    ///
+    /// A scoped service is normally pulled in by the handlers and transients
+    /// that run inside a scope; one with no incoming dependency edges is only
+    /// reachable by resolving it directly and is often left over from a
+    /// refactor. Every registration is resolved once in a throwaway copy of
+    /// the provider to record the edges, so this provider's singletons and
+    /// scope statistics are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct RequestContext;
+    /// struct Handler;
+    /// struct StaleCache;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    /// services.add_transient_factory::<Handler, _>(|r| {
+    ///     let _ctx = r.get_required::<RequestContext>();
+    ///     Handler
+    /// });
+    /// services.add_scoped_factory::<StaleCache, _>(|_| StaleCache);
+    ///
+    /// let unreachable = services.build().unreachable_scoped_services();
+    /// assert_eq!(unreachable.len(), 1);
+    /// assert_eq!(unreachable[0].display_name(), std::any::type_name::<StaleCache>());
+    /// ```
+    pub fn unreachable_scoped_services(&self) -> Vec<Key> {
+        let used = self.dependency_targets();
+        let mut unreachable: Vec<Key> = self.inner().registry.iter()
+            .filter(|(key, reg)| reg.lifetime == Lifetime::Scoped && !used.contains(key.display_name()))
+            .map(|(key, _)| key.clone())
+            .collect();
+        unreachable.sort();
+        unreachable
+    }
+
+    /// Groups of singleton keys that hold separate instances of the same
+    /// concrete type.
+    ///
+    /// Registering the same type under two keys (say, unnamed and named) builds
+    /// two instances; if they are meant to be one, an alias or a shared factory
+    /// saves the second. Every singleton registered under a concrete type is
+    /// resolved to compare instances, so they get created as a side effect.
+    /// Trait registrations are not inspected since their concrete type isn't
+    /// recorded. Groups and the keys within them are sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::ServiceCollection;
+    ///
+    /// struct HttpClient;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_factory::<HttpClient, _>(|_| HttpClient);
+    /// services.add_named_singleton_factory::<HttpClient, _>("uploads", |_| HttpClient);
+    ///
+    /// let groups = services.build().find_duplicate_instances();
+    /// assert_eq!(groups.len(), 1);
+    /// assert_eq!(groups[0].len(), 2);
+    /// ```
+    pub fn find_duplicate_instances(&self) -> Vec<Vec<Key>> {
+        let singletons: Vec<(TypeId, Key)> = self.inner().registry.iter()
+            .filter(|(_, reg)| reg.lifetime == Lifetime::Singleton)
+            .filter_map(|(key, _)| match key {
+                Key::Type(id, _) | Key::TypeNamed(id, _, _) => Some((*id, key.clone())),
+                _ => None,
+            })
+            .collect();
+
+        let mut by_type: HashMap<TypeId, Vec<(Key, *const ())>> = HashMap::new();
+        for (id, key) in singletons {
+            if let Ok(instance) = self.resolve_any(&key) {
+                let data = std::sync::Arc::as_ptr(&instance) as *const ();
+                by_type.entry(id).or_default().push((key, data));
+            }
+        }
+
+        let mut groups: Vec<Vec<Key>> = by_type.into_values()
+            .filter(|entries| entries.iter().any(|(_, data)| *data != entries[0].1))
+            .map(|entries| {
+                let mut keys: Vec<Key> = entries.into_iter().map(|(key, _)| key).collect();
+                keys.sort();
+                keys
+            })
+            .collect();
+        groups.sort();
+        groups
+    }
+
+    /// Builds a collection holding only `T` and the services it depends on,
+    /// for handing off a trimmed reproduction of a resolution problem.
+    ///
+    /// The dependency closure is found by resolving `T` once in a throwaway
+    /// provider built from fresh copies of every registration, so factories in
+    /// the closure run and this provider's caches are left untouched. Whatever
+    /// they create is disposed afterwards, async disposers included. If `T`
+    /// fails to resolve, the closure covers everything reached before the
+    /// failure. Registration metadata such as tags and checkpoint hooks is not
+    /// copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Resolver, ServiceCollection};
+    ///
+    /// struct Config;
+    /// struct Repository;
+    /// struct Metrics;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton(Config);
+    /// services.add_singleton(Metrics);
+    /// services.add_transient_factory::<Repository, _>(|r| {
+    ///     let _config = r.get_required::<Config>();
+    ///     Repository
+    /// });
+    ///
+    /// let repro = services.build().minimal_collection_for::<Repository>();
+    /// assert_eq!(repro.get_service_descriptors().len(), 2);
+    /// assert!(repro.build().get::<Repository>().is_ok());
+    /// ```
+    pub fn minimal_collection_for<T: ?Sized + 'static>(&self) -> ServiceCollection {
+        let registry = &self.inner().registry;
+        let name = type_name::<T>();
+
+        let keys: Vec<Key> = registry.iter()
+            .filter(|(key, _)| key.display_name() == name)
+            .map(|(key, _)| key.clone())
+            .collect();
+        let (_, edges) = self.with_shadow_scope(|scope| record_dependency_edges(|| {
+            for key in &keys {
+                let _ = scope.resolve_any(key);
+            }
+            if registry.many.contains_key(name) {
+                let _ = scope.resolve_many(&Key::Trait(name));
+            }
+        }));
+
+        let mut closure: HashSet<&str> = edges.into_iter().flat_map(|(a, b)| [a, b]).collect();
+        closure.insert(name);
+        ServiceCollection::from_registry(registry.fresh_subset(|service| closure.contains(service)))
+    }
+
+    /// Every registered service, ordered so that dependencies come before the
+    /// services that depend on them.
+    ///
+    /// Each factory is run once in a throwaway provider built from fresh copies
+    /// of every registration, recording the services it requests directly, so
+    /// this provider's caches are left untouched. Every factory still runs for
+    /// real, transients included, so side effects such as I/O or counters
+    /// happen once per call. A factory that panics keeps the dependencies it
+    /// requested before panicking. Dependencies are recorded by
+    /// service name, so a dependency on one named registration orders the
+    /// service after every registration of that type or trait. Services with
+    /// no ordering constraint between them are sorted by [`Key`]. Services on
+    /// a dependency cycle are appended at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrous_di::{Key, Resolver, ServiceCollection};
+    ///
+    /// struct Config;
+    /// struct Database;
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_factory::<Database, _>(|r| {
+    ///     let _config = r.get_required::<Config>();
+    ///     Database
+    /// });
+    /// services.add_singleton(Config);
+    ///
+    /// let order: Vec<&str> = services.build()
+    ///     .initialization_order()
+    ///     .iter()
+    ///     .map(Key::display_name)
+    ///     .collect();
+    /// assert_eq!(order, [std::any::type_name::<Config>(), std::any::type_name::<Database>()]);
+    /// ```
+    pub fn initialization_order(&self) -> Vec<Key> {
+        self.with_shadow_scope(|scope| {
+            let registry = &scope.root.inner().registry;
+            let mut factories: BTreeMap<Key, &crate::registration::Registration> = registry.iter()
+                .map(|(key, reg)| (key.clone(), reg))
+                .collect();
+            for (name, regs) in registry.many.iter() {
+                for (index, reg) in regs.iter().enumerate() {
+                    factories.insert(Key::MultiTrait(name, index), reg);
+                }
+            }
+            let mut by_name: HashMap<&'static str, Vec<Key>> = HashMap::new();
+            for key in factories.keys() {
+                by_name.entry(key.display_name()).or_default().push(key.clone());
+            }
+
+            let ctx = crate::registration::ResolverContext::new(scope);
+            let mut dependencies: BTreeMap<Key, BTreeSet<Key>> = BTreeMap::new();
+            for (key, reg) in &factories {
+                let service = key.display_name();
+                let (_, edges) = record_dependency_edges(|| {
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        with_circular_catch(service, || (reg.ctor)(&ctx))
+                    }))
+                });
+                let deps = dependencies.entry(key.clone()).or_default();
+                for (_, dependency) in edges.into_iter().filter(|(dependent, _)| *dependent == service) {
+                    deps.extend(by_name.get(dependency).into_iter().flatten().cloned());
+                }
+                deps.remove(key);
+            }
+            topological_order(dependencies)
+        })
+    }
+
+    /// The reverse of [`initialization_order`](Self::initialization_order):
+    /// every service comes before the services it depends on.
+    pub fn shutdown_order(&self) -> Vec<Key> {
+        let mut order = self.initialization_order();
+        order.reverse();
+        order
+    }
+
+    /// Names of every service some registration depends on.
+    ///
+    /// Resolves each single binding and each multi-binding group once in a
+    /// shadow scope (see [`with_shadow_scope`](Self::with_shadow_scope))
+    /// while recording dependency edges.
+    fn dependency_targets(&self) -> HashSet<&'static str> {
+        self.with_shadow_scope(|scope| {
+            let registry = &scope.root.inner().registry;
+            let (_, edges) = record_dependency_edges(|| {
+                for (key, _) in registry.iter() {
+                    let _ = scope.resolve_any(key);
+                }
+                for &name in registry.many.keys() {
+                    let _ = scope.resolve_many(&Key::Trait(name));
+                }
+            });
+            edges.into_iter().map(|(_, dependency)| dependency).collect()
+        })
+    }
+
+    /// Runs `f` against a scope of a throwaway provider built from fresh
+    /// copies of every registration, then disposes everything it created.
+    ///
+    /// Graph queries run factories this way so that this provider's cached
+    /// singletons, scope statistics, singleton origins and disposal bags are
+    /// left untouched.
+    fn with_shadow_scope<T>(&self, f: impl FnOnce(&crate::Scope) -> T) -> T {
+        let shadow = ServiceCollection::from_registry(self.inner().registry.fresh_subset(|_| true)).build();
+        let scope = shadow.create_scope();
+        let result = f(&scope);
+        std::mem::take(&mut *scope.scoped_disposers.lock().unwrap()).run_all_detached();
+        std::mem::take(&mut *shadow.inner().root_disposers.lock().unwrap()).run_all_detached();
+        result
+    }
+
+    /// Trait registrations that build a different erased type than the first
+    /// registration of the same trait, or fail with a type mismatch.
+    ///
+    /// Each registration's constructor is run in a
+    /// [shadow scope](Self::with_shadow_scope), so this provider's cached
+    /// singletons are neither read nor filled. Factories that fail or panic
+    /// are skipped, since they say nothing about the cast.
+    pub(crate) fn find_trait_cast_mismatches(&self) -> Vec<DiError> {
+        self.with_shadow_scope(|scope| {
+            let registry = &scope.root.inner().registry;
+            let mut entries: Vec<(Key, &crate::registration::Registration)> = registry.iter()
+                .filter(|(key, _)| matches!(key, Key::Trait(_) | Key::TraitNamed(..) | Key::TraitDiscriminated(..)))
+                .map(|(key, reg)| (key.clone(), reg))
+                .chain(registry.many.iter().flat_map(|(&name, regs)| {
+                    regs.iter().enumerate().map(move |(index, reg)| (Key::MultiTrait(name, index), reg))
+                }))
+                .collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let ctx = crate::registration::ResolverContext::new(scope);
+            let mut expected: HashMap<&'static str, TypeId> = HashMap::new();
+            let mut findings = Vec::new();
+            for (key, reg) in entries {
+                let name = key.display_name();
+                let built = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    with_circular_catch(name, || (reg.ctor)(&ctx))
+                }));
+                match built {
+                    Ok(Ok(value)) => {
+                        let actual = value.as_ref().type_id();
+                        if *expected.entry(name).or_insert(actual) != actual {
+                            findings.push(DiError::TypeMismatch(name));
+                        }
+                    }
+                    Ok(Err(err @ DiError::TypeMismatch(_))) => findings.push(err),
+                    Ok(Err(_)) | Err(_) => {}
+                }
+            }
+            findings
+        })
+    }
+
+    /// Services that nothing depends on and that aren't prewarmed or roots.
+    pub(crate) fn find_unused_services(&self, roots: &HashSet<&'static str>) -> Vec<DiError> {
+        let keys: Vec<Key> = self.inner().registry.iter()
+            .map(|(key, _)| key.clone())
+            .collect();
+        let used = self.dependency_targets();
+
+        let prewarm = &self.inner().prewarm;
+        let mut unused: Vec<&'static str> = keys.iter()
+            .filter(|key| !prewarm.contains(key))
+            .map(|key| key.display_name())
+            .filter(|name| !used.contains(name) && !roots.contains(name))
+            .collect();
+        unused.sort_unstable();
+        unused.dedup();
+        unused.into_iter().map(DiError::UnusedService).collect()
+    }
+}
+
+/// Orders `dependencies` so each key comes after the keys it depends on.
+///
+/// Kahn's algorithm, taking the smallest ready key each step; keys left on a
+/// cycle are appended at the end.
+fn topological_order(dependencies: BTreeMap<Key, BTreeSet<Key>>) -> Vec<Key> {
+    let mut dependents: BTreeMap<&Key, Vec<&Key>> = BTreeMap::new();
+    let mut pending: BTreeMap<&Key, usize> = BTreeMap::new();
+    for (key, deps) in &dependencies {
+        pending.insert(key, deps.len());
+        for dep in deps {
+            dependents.entry(dep).or_default().push(key);
+        }
+    }
+    let mut ready: BTreeSet<&Key> = pending.iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(key, _)| *key)
+        .collect();
+    let mut order = Vec::with_capacity(pending.len());
+    while let Some(key) = ready.pop_first() {
+        pending.remove(key);
+        order.push(key.clone());
+        for dependent in dependents.get(key).into_iter().flatten() {
+            if let Some(count) = pending.get_mut(dependent) {
+                *count -= 1;
+                if *count == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+    }
+    order.extend(pending.into_keys().cloned());
+    order
+}
@@ -397,6 +397,44 @@ fn test_named_traits() {
     assert_eq!(postgres_required.connection_string(), "postgresql://localhost");
 }
 
+#[test]
+fn test_discriminated_trait_resolution() {
+    trait Backend: Send + Sync {
+        fn device(&self) -> &str;
+    }
+
+    struct GpuBackend;
+    impl Backend for GpuBackend {
+        fn device(&self) -> &str { "cuda:0" }
+    }
+
+    struct CpuBackend;
+    impl Backend for CpuBackend {
+        fn device(&self) -> &str { "cpu" }
+    }
+
+    #[derive(Hash, PartialEq, Eq)]
+    enum BackendKind { Gpu, Cpu, Tpu }
+
+    // A second enum with the same layout must not collide with BackendKind
+    #[derive(Hash, PartialEq, Eq)]
+    enum OtherKind { Gpu }
+
+    let mut services = ServiceCollection::new();
+    services.add_discriminated_trait::<dyn Backend, _>(BackendKind::Gpu, Arc::new(GpuBackend));
+    services.add_discriminated_trait::<dyn Backend, _>(BackendKind::Cpu, Arc::new(CpuBackend));
+
+    let provider = services.build();
+
+    let gpu = provider.get_discriminated_trait::<dyn Backend, _>(BackendKind::Gpu).unwrap();
+    let cpu = provider.get_discriminated_trait::<dyn Backend, _>(BackendKind::Cpu).unwrap();
+    assert_eq!(gpu.device(), "cuda:0");
+    assert_eq!(cpu.device(), "cpu");
+
+    assert!(provider.get_discriminated_trait::<dyn Backend, _>(BackendKind::Tpu).is_err());
+    assert!(provider.get_discriminated_trait::<dyn Backend, _>(OtherKind::Gpu).is_err());
+}
+
 #[test]
 fn test_named_services_in_descriptors() {
     let mut services = ServiceCollection::new();
@@ -585,4 +623,405 @@ fn test_all_features_together() {
     // Named trait resolution
     let http_client = provider.get_named_trait_required::<dyn ApiClient>("http");
     assert_eq!(http_client.name(), "HttpClient");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_register_upcast_preserves_identity() {
+    trait Closeable: Send + Sync {
+        fn close(&self) -> &'static str;
+    }
+    trait ApiClient: Closeable {
+        fn endpoint(&self) -> &'static str;
+    }
+
+    struct HttpClient;
+    impl Closeable for HttpClient {
+        fn close(&self) -> &'static str { "closed" }
+    }
+    impl ApiClient for HttpClient {
+        fn endpoint(&self) -> &'static str { "https://api" }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_trait::<dyn ApiClient>(Arc::new(HttpClient));
+    services.register_upcast::<dyn ApiClient, dyn Closeable>(|client| client);
+
+    let provider = services.build();
+    let client = provider.get_required_trait::<dyn ApiClient>();
+    let closeable = provider.get_required_trait::<dyn Closeable>();
+
+    assert_eq!(client.endpoint(), "https://api");
+    assert_eq!(closeable.close(), "closed");
+    // Same underlying instance, only the vtable differs
+    assert!(std::ptr::addr_eq(Arc::as_ptr(&client), Arc::as_ptr(&closeable)));
+}
+
+#[test]
+fn test_gated_singleton_follows_runtime_flag() {
+    use ferrous_di::{DiError, FeatureFlags};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct Flags { beta_search: AtomicBool }
+    impl FeatureFlags for Flags {
+        fn is_enabled(&self, flag: &str) -> bool {
+            flag == "beta-search" && self.beta_search.load(Ordering::SeqCst)
+        }
+    }
+
+    struct BetaSearch;
+
+    let flags = Arc::new(Flags { beta_search: AtomicBool::new(false) });
+    let mut services = ServiceCollection::new();
+    services.add_singleton_trait::<dyn FeatureFlags>(flags.clone());
+    services.add_singleton_gated::<BetaSearch, _>("beta-search", |_| BetaSearch);
+    let provider = services.build();
+    let scope = provider.create_scope();
+
+    assert!(matches!(provider.get::<BetaSearch>(), Err(DiError::FeatureDisabled("beta-search"))));
+    assert!(matches!(scope.get::<BetaSearch>(), Err(DiError::FeatureDisabled("beta-search"))));
+
+    flags.beta_search.store(true, Ordering::SeqCst);
+    let first = provider.get::<BetaSearch>().unwrap();
+    let from_scope = scope.get::<BetaSearch>().unwrap();
+    assert!(Arc::ptr_eq(&first, &from_scope));
+
+    flags.beta_search.store(false, Ordering::SeqCst);
+    assert!(matches!(provider.get::<BetaSearch>(), Err(DiError::FeatureDisabled("beta-search"))));
+}
+
+#[test]
+fn test_get_with_info_reports_cache_hits() {
+    struct Expensive;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<Expensive, _>(|_| Expensive);
+    services.add_scoped_factory::<String, _>(|_| "request".to_string());
+    services.add_transient_factory::<u32, _>(|_| 7);
+    let provider = services.build();
+
+    let (first, info) = provider.get_with_info::<Expensive>().unwrap();
+    assert_eq!(info.lifetime, Lifetime::Singleton);
+    assert!(!info.cache_hit);
+
+    let (second, info) = provider.get_with_info::<Expensive>().unwrap();
+    assert!(info.cache_hit);
+    assert!(Arc::ptr_eq(&first, &second));
+
+    let scope = provider.create_scope();
+    let (_, info) = scope.get_with_info::<String>().unwrap();
+    assert_eq!((info.lifetime, info.cache_hit), (Lifetime::Scoped, false));
+    let (_, info) = scope.get_with_info::<String>().unwrap();
+    assert!(info.cache_hit);
+
+    let (_, info) = scope.get_with_info::<u32>().unwrap();
+    assert_eq!((info.lifetime, info.cache_hit), (Lifetime::Transient, false));
+}
+
+#[test]
+fn test_aliases_share_the_target_singleton() {
+    trait Storage: Send + Sync {
+        fn bucket(&self) -> &str;
+    }
+    trait BlobStore: Storage {}
+
+    struct S3Store;
+    impl Storage for S3Store {
+        fn bucket(&self) -> &str { "artifacts" }
+    }
+    impl BlobStore for S3Store {}
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<S3Store, _>(|_| S3Store);
+    services.add_alias::<dyn BlobStore, S3Store>(|store| store);
+    services.add_trait_alias::<dyn Storage, dyn BlobStore>(|store| store);
+    let provider = services.build();
+
+    let concrete = provider.get_required::<S3Store>();
+    let new_name = provider.get_required_trait::<dyn BlobStore>();
+    let old_name = provider.get_required_trait::<dyn Storage>();
+
+    assert_eq!(old_name.bucket(), "artifacts");
+    assert!(std::ptr::addr_eq(Arc::as_ptr(&concrete), Arc::as_ptr(&new_name)));
+    assert!(std::ptr::addr_eq(Arc::as_ptr(&new_name), Arc::as_ptr(&old_name)));
+}
+
+#[test]
+fn test_aliases_take_the_target_lifetime() {
+    trait Session: Send + Sync {}
+    struct RequestSession;
+    impl Session for RequestSession {}
+
+    let mut services = ServiceCollection::new();
+    services.add_scoped_factory::<RequestSession, _>(|_| RequestSession);
+    services.add_alias::<dyn Session, RequestSession>(|session| session);
+
+    let alias = services.get_service_descriptors().into_iter()
+        .find(|d| d.key == Key::Trait(std::any::type_name::<dyn Session>()))
+        .unwrap();
+    assert_eq!(alias.lifetime, Lifetime::Scoped);
+
+    let provider = services.build();
+    assert!(matches!(provider.get_trait::<dyn Session>(), Err(ferrous_di::DiError::WrongLifetime(_))));
+
+    let scope = provider.create_scope();
+    let concrete = scope.get_required::<RequestSession>();
+    let aliased = scope.get_required_trait::<dyn Session>();
+    assert!(std::ptr::addr_eq(Arc::as_ptr(&concrete), Arc::as_ptr(&aliased)));
+
+    let other = provider.create_scope().get_required_trait::<dyn Session>();
+    assert!(!std::ptr::addr_eq(Arc::as_ptr(&aliased), Arc::as_ptr(&other)));
+}
+
+#[test]
+fn test_services_with_label_returns_labeled_keys() {
+    use ferrous_di::key_of_type;
+
+    #[derive(PartialEq, Eq, Hash)]
+    enum Tier {
+        Critical,
+        Experimental,
+    }
+
+    struct Database;
+    struct Auth;
+    struct Recommendations;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Database).label(Tier::Critical);
+    services.add_singleton_factory::<Auth, _>(|_| Auth).label(Tier::Critical);
+    services.add_transient_factory::<Recommendations, _>(|_| Recommendations).label(Tier::Experimental);
+
+    let provider = services.build();
+    let mut expected = vec![key_of_type::<Database>(), key_of_type::<Auth>()];
+    expected.sort();
+    assert_eq!(provider.services_with_label(Tier::Critical), expected);
+    assert_eq!(provider.services_with_label(Tier::Experimental), vec![key_of_type::<Recommendations>()]);
+    // Labels of a different type never match
+    assert!(provider.services_with_label("Critical").is_empty());
+}
+
+#[test]
+fn test_key_rewriter_routes_fraction_to_canary() {
+    use ferrous_di::{DiError, KeyRewriter};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    trait Recommender: Send + Sync {
+        fn model(&self) -> &'static str;
+    }
+    struct Stable;
+    impl Recommender for Stable {
+        fn model(&self) -> &'static str { "stable" }
+    }
+    struct Canary;
+    impl Recommender for Canary {
+        fn model(&self) -> &'static str { "canary" }
+    }
+
+    // Sends every tenth resolution to the canary
+    struct CanaryRollout {
+        resolutions: AtomicUsize,
+    }
+    impl KeyRewriter for CanaryRollout {
+        fn rewrite(&self, key: &Key) -> Option<Key> {
+            match key {
+                Key::Trait(name) if *name == std::any::type_name::<dyn Recommender>() => {
+                    let n = self.resolutions.fetch_add(1, Ordering::Relaxed);
+                    (n % 10 == 0).then_some(Key::TraitNamed(name, "canary"))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_trait::<dyn Recommender>(Arc::new(Stable));
+    services.add_named_singleton_trait::<dyn Recommender>("canary", Arc::new(Canary));
+    services.add_key_rewriter(Arc::new(CanaryRollout { resolutions: AtomicUsize::new(0) }));
+    let provider = services.build();
+    let scope = provider.create_scope();
+
+    let mut canary = 0;
+    for i in 0..100u32 {
+        let recommender = if i % 2 == 0 {
+            provider.get_required_trait::<dyn Recommender>()
+        } else {
+            scope.get_required_trait::<dyn Recommender>()
+        };
+        if recommender.model() == "canary" {
+            canary += 1;
+        }
+    }
+    assert_eq!(canary, 10);
+
+    // Rewrites that come back around are rejected instead of spinning
+    struct Swap;
+    impl KeyRewriter for Swap {
+        fn rewrite(&self, key: &Key) -> Option<Key> {
+            match key {
+                Key::TraitNamed(name, "blue") => Some(Key::TraitNamed(name, "green")),
+                Key::TraitNamed(name, "green") => Some(Key::TraitNamed(name, "blue")),
+                _ => None,
+            }
+        }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_named_singleton_trait::<dyn Recommender>("blue", Arc::new(Stable));
+    services.add_key_rewriter(Arc::new(Swap));
+    let provider = services.build();
+    match provider.get_named_trait::<dyn Recommender>("blue") {
+        Err(DiError::KeyRewriteLoop(path)) => assert_eq!(path.len(), 3),
+        other => panic!("expected KeyRewriteLoop, got {:?}", other.map(|r| r.model())),
+    }
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_key_rewriter_applies_to_async_and_contextual_resolution() {
+    use ferrous_di::{KeyRewriter, ResolutionArgs, ResolverCore};
+
+    struct Endpoint(&'static str);
+
+    struct PinToCanary;
+    impl KeyRewriter for PinToCanary {
+        fn rewrite(&self, key: &Key) -> Option<Key> {
+            match key {
+                Key::Type(id, name) if *id == TypeId::of::<Endpoint>() => Some(Key::TypeNamed(*id, name, "canary")),
+                _ => None,
+            }
+        }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_async::<Endpoint, _>(|_resolver: &dyn ResolverCore| async {
+        Ok(Arc::new(Endpoint("stable")))
+    });
+    services.add_named_singleton("canary", Endpoint("canary"));
+    services.add_key_rewriter(Arc::new(PinToCanary));
+    let provider = services.build();
+    let scope = provider.create_scope();
+
+    assert_eq!(scope.get_async::<Endpoint>().await.unwrap().0, "canary");
+    assert_eq!(scope.get_with_context::<Endpoint>(ResolutionArgs::new()).unwrap().0, "canary");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_resolve_into_populates_all_fields() {
+    use ferrous_di::ResolveInto;
+
+    struct Config { url: &'static str }
+    struct Metrics;
+    trait Store: Send + Sync {
+        fn name(&self) -> &str;
+    }
+    struct MemoryStore;
+    impl Store for MemoryStore {
+        fn name(&self) -> &str { "memory" }
+    }
+
+    #[derive(ResolveInto)]
+    struct Services {
+        config: Arc<Config>,
+        metrics: Arc<Metrics>,
+        store: Arc<dyn Store>,
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Config { url: "postgres://localhost" });
+    services.add_singleton(Metrics);
+    services.add_singleton_trait::<dyn Store>(Arc::new(MemoryStore));
+    let provider = services.build();
+
+    let resolved = Services::resolve_from(&provider).unwrap();
+    assert_eq!(resolved.config.url, "postgres://localhost");
+    assert!(Arc::ptr_eq(&resolved.metrics, &provider.get_required::<Metrics>()));
+    assert_eq!(resolved.store.name(), "memory");
+
+    let empty = ServiceCollection::new().build();
+    assert!(Services::resolve_from(&empty).is_err());
+}
+
+#[test]
+fn test_singleton_memory_report_lists_reported_sizes() {
+    use ferrous_di::SizeHint;
+
+    struct ModelWeights(Vec<u8>);
+    impl SizeHint for ModelWeights {
+        fn size_bytes(&self) -> usize { self.0.len() }
+    }
+    struct ResponseCache { entries: usize }
+    impl SizeHint for ResponseCache {
+        fn size_bytes(&self) -> usize { self.entries * 64 }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(ModelWeights(vec![0; 4096]));
+    services.add_singleton_factory::<ResponseCache, _>(|_| ResponseCache { entries: 16 });
+    services.track_memory::<ModelWeights>().track_memory::<ResponseCache>();
+    let provider = services.build();
+
+    // Singletons that haven't been built yet aren't reported
+    assert!(provider.singleton_memory_report().is_empty());
+    assert_eq!(provider.singleton_memory_total(), 0);
+
+    provider.get_required::<ModelWeights>();
+    provider.get_required::<ResponseCache>();
+    let report = provider.singleton_memory_report();
+    let sizes: Vec<(&str, usize)> = report.iter().map(|(key, size)| (key.display_name(), *size)).collect();
+    assert_eq!(sizes, vec![
+        (std::any::type_name::<ModelWeights>(), 4096),
+        (std::any::type_name::<ResponseCache>(), 1024),
+    ]);
+    assert_eq!(provider.singleton_memory_total(), 5120);
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn test_dump_state_serializes_expected_sections() {
+    trait Tool: Send + Sync {}
+    struct Search;
+    impl Tool for Search {}
+    struct Config;
+    struct RequestId;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Config);
+    services.add_named_singleton("port", 8080u16);
+    services.add_scoped_factory::<RequestId, _>(|_| RequestId);
+    services.add_trait_implementation(Arc::new(Search) as Arc<dyn Tool>, Lifetime::Singleton);
+    let provider = services.build();
+
+    provider.get_required::<Config>();
+    let _scope = provider.create_scope();
+
+    let dump = provider.dump_state();
+    assert!(dump.recent_events.is_none());
+    assert_eq!(dump.descriptors.len(), 4);
+    assert_eq!(dump.warmed_singletons, vec![std::any::type_name::<Config>().to_string()]);
+    assert_eq!(dump.live_scopes, 1);
+
+    let json: serde_json::Value = serde_json::from_str(&dump.to_json()).unwrap();
+    assert_eq!(json["live_scopes"], 1);
+    assert_eq!(json["warmed_singletons"].as_array().unwrap().len(), 1);
+    let services: Vec<&str> = json["descriptors"].as_array().unwrap().iter()
+        .map(|d| d["service"].as_str().unwrap())
+        .collect();
+    assert!(services.contains(&"u16[port]"));
+    assert!(services.iter().any(|s| s.ends_with("Tool#0")));
+    let scoped = json["descriptors"].as_array().unwrap().iter()
+        .find(|d| d["service"].as_str().unwrap().ends_with("RequestId"))
+        .unwrap();
+    assert_eq!(scoped["lifetime"], "Scoped");
+
+    // With a ring buffer attached, its events are included
+    let mut services = ServiceCollection::new();
+    services.add_observer(Arc::new(ferrous_di::RingBufferObserver::new(8)));
+    services.add_singleton(Config);
+    let provider = services.build();
+    provider.get_required::<Config>();
+    let json: serde_json::Value = serde_json::from_str(&provider.dump_state().to_json()).unwrap();
+    let events = json["recent_events"].as_array().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["outcome"], "resolved");
+}
@@ -1,6 +1,6 @@
 //! Tests for agent-focused features like decoration, observers, prewarm, and capabilities.
 
-use ferrous_di::{ServiceCollection, DiObserver, LoggingObserver, Resolver, ScopeLocal, ToolCapability, ToolSelectionCriteria, CapabilityRequirement, ValidationBuilder, Lifetime, ValidationError, FastSingletonCache};
+use ferrous_di::{ServiceCollection, DiError, DiObserver, LoggingObserver, Resolver, ScopeLocal, ToolCapability, ToolSelectionCriteria, CapabilityRequirement, ValidationBuilder, Lifetime, ValidationError, FastSingletonCache};
 use std::sync::{Arc, atomic::{AtomicU32, Ordering}};
 
 #[test]
@@ -91,6 +91,76 @@ fn test_observer_basic() {
     assert_eq!(counter.load(Ordering::Relaxed), 4); // +2 more
 }
 
+#[test]
+fn test_observer_names_lists_registered_observers() {
+    struct TracingExporter {
+        name: &'static str,
+    }
+
+    impl DiObserver for TracingExporter {
+        fn resolving(&self, _key: &ferrous_di::Key) {}
+        fn resolved(&self, _key: &ferrous_di::Key, _duration: std::time::Duration) {}
+        fn factory_panic(&self, _key: &ferrous_di::Key, _message: &str) {}
+
+        fn name(&self) -> &'static str {
+            self.name
+        }
+    }
+
+    assert_eq!(ServiceCollection::new().build().observer_count(), 0);
+
+    let mut services = ServiceCollection::new();
+    services.add_observer(Arc::new(TracingExporter { name: "jaeger" }));
+    services.add_observer(Arc::new(TracingExporter { name: "stdout" }));
+    services.add_observer(Arc::new(LoggingObserver::new()));
+
+    let provider = services.build();
+    assert_eq!(provider.observer_count(), 3);
+    assert_eq!(
+        provider.observer_names(),
+        ["jaeger", "stdout", std::any::type_name::<LoggingObserver>()]
+    );
+}
+
+#[test]
+fn test_deprecated_service_warns_once() {
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct DeprecationRecorder {
+        warnings: Mutex<Vec<(String, String)>>,
+    }
+
+    impl DiObserver for DeprecationRecorder {
+        fn resolving(&self, _key: &ferrous_di::Key) {}
+        fn resolved(&self, _key: &ferrous_di::Key, _duration: std::time::Duration) {}
+        fn factory_panic(&self, _key: &ferrous_di::Key, _message: &str) {}
+
+        fn deprecated_resolution(&self, key: &ferrous_di::Key, message: &str) {
+            self.warnings.lock().unwrap().push((key.display_name().to_string(), message.to_string()));
+        }
+    }
+
+    struct LegacyClient;
+
+    let recorder = Arc::new(DeprecationRecorder::default());
+    let mut services = ServiceCollection::new();
+    services.deprecate::<LegacyClient>("use HttpClient instead");
+    services.add_transient_factory::<LegacyClient, _>(|_| LegacyClient);
+    services.add_singleton(1u8);
+    services.add_observer(recorder.clone());
+
+    let provider = services.build();
+    let _ = provider.get_required::<LegacyClient>();
+    let _ = provider.create_scope().get_required::<LegacyClient>();
+    let _ = provider.get_required::<u8>();
+
+    let warnings = recorder.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].0, std::any::type_name::<LegacyClient>());
+    assert_eq!(warnings[0].1, "use HttpClient instead");
+}
+
 #[test]
 fn test_prewarm_marks_services() {
     let mut services = ServiceCollection::new();
@@ -103,6 +173,33 @@ fn test_prewarm_marks_services() {
     // When ready() is fully implemented: provider.ready().await
 }
 
+#[tokio::test]
+async fn test_ready_reports_cold_and_warm_services() {
+    let mut services = ServiceCollection::new();
+    services.add_singleton(42usize);
+    services.add_singleton_factory::<String, _>(|_| "cold".to_string());
+    services.prewarm::<usize>();
+    services.prewarm::<String>();
+
+    let provider = services.build();
+    let _ = provider.get_required::<usize>();
+
+    let report = provider.ready().await.unwrap();
+    assert!(report.all_ready());
+    assert_eq!(report.services.len(), 2);
+    assert_eq!(report.cold_count(), 1);
+    assert_eq!(report.warm_count(), 1);
+
+    let usize_result = report.services.iter().find(|r| r.key.display_name().contains("usize")).unwrap();
+    assert!(!usize_result.was_cold);
+    let string_result = report.services.iter().find(|r| r.key.display_name().contains("String")).unwrap();
+    assert!(string_result.was_cold);
+
+    // A second pass finds everything cached
+    let again = provider.ready().await.unwrap();
+    assert_eq!(again.cold_count(), 0);
+}
+
 #[test] 
 fn test_multiple_decorations_compose() {
     trait Calculator: Send + Sync {
@@ -464,4 +561,533 @@ fn test_validation_circular_dependency() {
         }
         _ => panic!("Expected CircularDependency error"),
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_validate_lifetimes_flags_transient_using_expensive_service() {
+    use ferrous_di::{DiError, ResolverCore};
+
+    struct Settings;
+    struct DbPool;
+    struct Repository { _pool: Arc<DbPool>, _settings: Arc<Settings> }
+    struct Cache { _pool: Arc<DbPool> }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<Settings, _>(|_| Settings);
+    services.add_transient_factory::<DbPool, _>(|_| DbPool);
+    services.add_transient_factory::<Repository, _>(|r| Repository {
+        _pool: r.get_required::<DbPool>(),
+        _settings: r.get_required::<Settings>(),
+    });
+    services.add_singleton_factory::<Cache, _>(|r| Cache { _pool: r.get_required::<DbPool>() });
+    services.mark_expensive::<DbPool>();
+
+    let provider = services.build();
+    let findings = provider.validate_lifetimes();
+
+    assert_eq!(findings.len(), 1);
+    match &findings[0] {
+        DiError::SuspectTransient { service, expensive_dep } => {
+            assert_eq!(*service, std::any::type_name::<Repository>());
+            assert_eq!(*expensive_dep, std::any::type_name::<DbPool>());
+        }
+        other => panic!("unexpected finding: {other:?}"),
+    }
+    // Dependencies were built in a throwaway copy of the provider
+    let key = ferrous_di::key_of_type::<Settings>();
+    assert_eq!(provider.cache_status(&key), Some((Lifetime::Singleton, false)));
+}
+
+#[test]
+fn test_validate_decorators_flags_singleton_decorator_using_scoped_service() {
+    use ferrous_di::{key_of_type, DiError, ServiceDecorator};
+    use ferrous_di::traits::ResolverCore;
+
+    struct Settings;
+    struct Cache { _settings: Arc<Settings> }
+    struct RequestContext;
+
+    struct RequestTagging;
+    impl ServiceDecorator<Cache> for RequestTagging {
+        fn decorate(&self, original: Arc<Cache>, resolver: &dyn ResolverCore) -> Arc<Cache> {
+            let _ = resolver.resolve_any(&key_of_type::<RequestContext>());
+            original
+        }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<Settings, _>(|_| Settings);
+    services.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    services.add_singleton_factory::<Cache, _>(|r| Cache { _settings: r.get_required::<Settings>() });
+    services.decorate_with::<Cache, _>(RequestTagging);
+
+    let provider = services.build();
+    let findings = provider.validate_decorators();
+
+    match &findings[..] {
+        [DiError::DecoratorLifetimeConflict { service, scoped_dep }] => {
+            assert_eq!(*service, std::any::type_name::<Cache>());
+            assert_eq!(*scoped_dep, std::any::type_name::<RequestContext>());
+        }
+        other => panic!("unexpected findings: {other:?}"),
+    }
+    // Neither the singleton nor its dependencies were built here
+    assert_eq!(provider.cache_status(&key_of_type::<Cache>()), Some((Lifetime::Singleton, false)));
+    assert_eq!(provider.cache_status(&key_of_type::<Settings>()), Some((Lifetime::Singleton, false)));
+}
+
+#[test]
+fn test_lint_unused_flags_service_nothing_depends_on() {
+    use ferrous_di::DiError;
+
+    struct Config;
+    struct Handler { _config: Arc<Config> }
+    struct Metrics;
+    struct Orphan;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Config);
+    services.add_scoped_factory::<Handler, _>(|r| Handler { _config: r.get_required::<Config>() });
+    services.add_singleton(Metrics);
+    services.add_transient_factory::<Orphan, _>(|_| Orphan);
+    services.prewarm::<Metrics>();
+    services.mark_root::<Handler>().lint_unused();
+
+    let provider = services.build();
+    match provider.build_warnings() {
+        [DiError::UnusedService(name)] => assert_eq!(*name, std::any::type_name::<Orphan>()),
+        other => panic!("unexpected findings: {other:?}"),
+    }
+}
+
+#[test]
+fn test_lint_unused_counts_multi_binding_deps_without_touching_provider() {
+    use ferrous_di::{DiError, ResolverCore};
+
+    trait Plugin: Send + Sync {}
+    struct Formatter;
+    struct Markdown { _formatter: Arc<Formatter> }
+    impl Plugin for Markdown {}
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<Formatter, _>(|_| Formatter);
+    services.add_trait_factory::<dyn Plugin, _>(Lifetime::Singleton, |r| {
+        Arc::new(Markdown { _formatter: r.get_required::<Formatter>() }) as Arc<dyn Plugin>
+    });
+    services.lint_unused();
+
+    let provider = services.build();
+    assert!(!provider.build_warnings().iter().any(|warning| {
+        matches!(warning, DiError::UnusedService(name) if *name == std::any::type_name::<Formatter>())
+    }));
+    // The lint ran factories in a shadow provider, so nothing is cached here
+    let key = ferrous_di::key_of_type::<Formatter>();
+    assert_eq!(provider.cache_status(&key), Some((Lifetime::Singleton, false)));
+    assert_eq!(provider.live_scope_count(), 0);
+}
+
+#[test]
+fn test_unreachable_scoped_services_reports_orphans_only() {
+    struct Session;
+    struct Handler { _session: Arc<Session> }
+    struct OrphanTracker;
+
+    let mut services = ServiceCollection::new();
+    services.add_scoped_factory::<Session, _>(|_| Session);
+    services.add_scoped_factory::<Handler, _>(|r| Handler { _session: r.get_required::<Session>() });
+    services.add_scoped_factory::<OrphanTracker, _>(|_| OrphanTracker);
+
+    let provider = services.build();
+    let unreachable: Vec<_> = provider.unreachable_scoped_services()
+        .iter()
+        .map(|key| key.display_name())
+        .collect();
+
+    assert!(unreachable.contains(&std::any::type_name::<OrphanTracker>()));
+    assert!(!unreachable.contains(&std::any::type_name::<Session>()));
+    // The probe scope belongs to a shadow provider, not this one
+    assert_eq!(provider.scoped_slot_stats().typically_used, 0);
+}
+
+#[test]
+fn test_initialize_in_order_honors_declared_order() {
+    use std::any::TypeId;
+    use std::sync::Mutex;
+
+    struct HandlerA;
+    struct HandlerB;
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut services = ServiceCollection::new();
+    let log_a = log.clone();
+    services.add_singleton_factory::<HandlerA, _>(move |_| {
+        log_a.lock().unwrap().push("A");
+        HandlerA
+    });
+    let log_b = log.clone();
+    services.add_singleton_factory::<HandlerB, _>(move |_| {
+        log_b.lock().unwrap().push("B");
+        HandlerB
+    });
+    services.init_order(&[TypeId::of::<HandlerB>(), TypeId::of::<HandlerA>()]);
+
+    let provider = services.build();
+    assert!(log.lock().unwrap().is_empty());
+
+    provider.initialize_in_order().unwrap();
+    assert_eq!(*log.lock().unwrap(), vec!["B", "A"]);
+
+    // Already initialized singletons are not re-created
+    provider.initialize_in_order().unwrap();
+    let _ = provider.get_required::<HandlerA>();
+    assert_eq!(log.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_initialize_in_order_errors_name_the_type() {
+    use std::any::TypeId;
+
+    struct PerRequest;
+    struct Unregistered;
+
+    let mut services = ServiceCollection::new();
+    services.add_transient_factory::<PerRequest, _>(|_| PerRequest);
+    services.init_order(&[TypeId::of::<PerRequest>()]);
+    match services.build().initialize_in_order() {
+        Err(DiError::WrongLifetime(message)) => assert!(message.contains("PerRequest"), "{message}"),
+        other => panic!("expected WrongLifetime, got {:?}", other.err()),
+    }
+
+    let mut services = ServiceCollection::new();
+    services.init_order(&[TypeId::of::<Unregistered>()]);
+    assert!(matches!(
+        services.build().initialize_in_order(),
+        Err(DiError::NotFound(message)) if message.contains("init_order")
+    ));
+}
+
+#[cfg(feature = "cost-estimation")]
+#[test]
+fn test_estimate_plan_cost_sums_step_estimates() {
+    use ferrous_di::CostModel;
+    use serde_json::json;
+
+    struct SearchTool;
+    impl ToolCapability for SearchTool {
+        fn name(&self) -> &str { "search" }
+        fn description(&self) -> &str { "Web search" }
+        fn version(&self) -> &str { "1.0.0" }
+        fn capabilities(&self) -> Vec<&str> { vec!["web_search"] }
+        fn requires(&self) -> Vec<&str> { vec![] }
+        fn cost_model(&self) -> Option<CostModel> {
+            Some(CostModel::fixed(0.01).with_estimator(|input| {
+                input["limit"].as_f64().unwrap_or(0.0) * 0.001
+            }))
+        }
+    }
+
+    struct LlmTool;
+    impl ToolCapability for LlmTool {
+        fn name(&self) -> &str { "llm" }
+        fn description(&self) -> &str { "Text generation" }
+        fn version(&self) -> &str { "1.0.0" }
+        fn capabilities(&self) -> Vec<&str> { vec!["generate"] }
+        fn requires(&self) -> Vec<&str> { vec![] }
+        fn estimated_cost(&self) -> Option<f64> { Some(0.2) }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_tool_singleton(SearchTool);
+    services.add_tool_singleton(LlmTool);
+    let provider = services.build();
+
+    let plan = vec![
+        ("search".to_string(), json!({ "limit": 10 })),
+        ("llm".to_string(), json!({ "prompt": "summarize" })),
+        ("search".to_string(), json!({ "limit": 5 })),
+        ("unknown".to_string(), json!({})),
+    ];
+
+    let expected = (0.01 + 10.0 * 0.001) + 0.2 + (0.01 + 5.0 * 0.001);
+    assert!((provider.estimate_plan_cost(&plan) - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_add_singleton_trait_decorated_wraps_only_its_registration() {
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> String;
+    }
+
+    struct English;
+    impl Greeter for English {
+        fn greet(&self) -> String { "hello".to_string() }
+    }
+
+    struct French;
+    impl Greeter for French {
+        fn greet(&self) -> String { "bonjour".to_string() }
+    }
+
+    struct Shouting(Arc<dyn Greeter>);
+    impl Greeter for Shouting {
+        fn greet(&self) -> String { self.0.greet().to_uppercase() }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_trait_decorated::<dyn Greeter, _>(Arc::new(English), |inner| {
+        Arc::new(Shouting(inner))
+    });
+    services.add_trait_implementation(Arc::new(French) as Arc<dyn Greeter>, Lifetime::Singleton);
+
+    let provider = services.build();
+    let greeter = provider.get_required_trait::<dyn Greeter>();
+    assert_eq!(greeter.greet(), "HELLO");
+    assert!(Arc::ptr_eq(&greeter, &provider.get_required_trait::<dyn Greeter>()));
+
+    let others = provider.get_all_trait::<dyn Greeter>().unwrap();
+    assert_eq!(others.len(), 1);
+    assert_eq!(others[0].greet(), "bonjour");
+}
+
+#[test]
+fn test_minimal_collection_for_keeps_only_dependency_closure() {
+    struct Config;
+    struct Database { _config: Arc<Config> }
+    struct Repository { _db: Arc<Database> }
+    struct Mailer { _config: Arc<Config> }
+    struct Metrics;
+    struct Scheduler { _metrics: Arc<Metrics> }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Config);
+    services.add_singleton_factory::<Database, _>(|r| Database { _config: r.get_required::<Config>() });
+    services.add_scoped_factory::<Repository, _>(|r| Repository { _db: r.get_required::<Database>() });
+    services.add_transient_factory::<Mailer, _>(|r| Mailer { _config: r.get_required::<Config>() });
+    services.add_singleton(Metrics);
+    services.add_singleton_factory::<Scheduler, _>(|r| Scheduler { _metrics: r.get_required::<Metrics>() });
+
+    let provider = services.build();
+    // Cached singletons in the original provider must not hide their dependencies
+    provider.create_scope().get_required::<Repository>();
+
+    let minimal = provider.minimal_collection_for::<Repository>();
+    let mut names: Vec<_> = minimal.get_service_descriptors()
+        .iter()
+        .map(|d| d.type_name())
+        .collect();
+    names.sort_unstable();
+    let mut expected = vec![
+        std::any::type_name::<Config>(),
+        std::any::type_name::<Database>(),
+        std::any::type_name::<Repository>(),
+    ];
+    expected.sort_unstable();
+    assert_eq!(names, expected);
+
+    let repro = minimal.build();
+    assert!(repro.create_scope().get::<Repository>().is_ok());
+    assert!(repro.get::<Scheduler>().is_err());
+}
+
+#[test]
+fn test_minimal_collection_for_disposes_shadow_singletons() {
+    use ferrous_di::ResolverCore;
+
+    struct Pool;
+
+    let disposed = Arc::new(AtomicU32::new(0));
+    let mut services = ServiceCollection::new();
+    let counter = disposed.clone();
+    services.add_singleton_factory::<Pool, _>(move |r| {
+        let counter = counter.clone();
+        r.push_sync_disposer(Box::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+        Pool
+    });
+
+    let provider = services.build();
+    let _ = provider.minimal_collection_for::<Pool>();
+    // The shadow singleton was torn down; the real one was never built
+    assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    assert!(!provider.get_with_info::<Pool>().unwrap().1.cache_hit);
+}
+
+#[test]
+fn test_find_duplicate_instances_groups_same_concrete_type() {
+    use ferrous_di::Key;
+
+    struct HttpClient;
+    struct Config;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<HttpClient, _>(|_| HttpClient);
+    services.add_named_singleton_factory::<HttpClient, _>("uploads", |_| HttpClient);
+    services.add_singleton(Config);
+    // Transients are expected to differ and are not reported
+    services.add_named_transient_factory::<Config, _>("draft", |_| Config);
+
+    let groups = services.build().find_duplicate_instances();
+    assert_eq!(groups.len(), 1);
+
+    let client = std::any::type_name::<HttpClient>();
+    assert!(groups[0].iter().all(|key| key.display_name() == client));
+    assert!(groups[0].iter().any(|key| matches!(key, Key::Type(..))));
+    assert!(groups[0].iter().any(|key| key.service_name() == Some("uploads")));
+}
+
+#[test]
+fn test_initialization_order_puts_dependencies_first() {
+    use ferrous_di::Key;
+
+    struct C;
+    struct B { _c: Arc<C> }
+    struct A { _b: Arc<B> }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<A, _>(|r| A { _b: r.get_required::<B>() });
+    services.add_scoped_factory::<B, _>(|r| B { _c: r.get_required::<C>() });
+    services.add_singleton(C);
+
+    let provider = services.build();
+    // A cached dependency must still produce an edge
+    provider.get_required::<C>();
+
+    let names = |order: Vec<Key>| order.iter().map(Key::display_name).collect::<Vec<_>>();
+    let (a, b, c) = (
+        std::any::type_name::<A>(),
+        std::any::type_name::<B>(),
+        std::any::type_name::<C>(),
+    );
+    assert_eq!(names(provider.initialization_order()), vec![c, b, a]);
+    assert_eq!(names(provider.shutdown_order()), vec![a, b, c]);
+}
+
+#[test]
+fn test_initialization_order_keeps_edges_of_panicking_factories() {
+    use ferrous_di::Key;
+
+    struct Settings;
+    struct Flaky;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<Flaky, _>(|r| {
+        let _settings = r.get_required::<Settings>();
+        panic!("flaky factory");
+    });
+    services.add_singleton(Settings);
+
+    let order: Vec<&str> = services.build().initialization_order().iter().map(Key::display_name).collect();
+    assert_eq!(order, vec![std::any::type_name::<Settings>(), std::any::type_name::<Flaky>()]);
+}
+
+#[tokio::test]
+async fn test_prewarm_labeled_warms_only_labeled_services() {
+    #[derive(PartialEq, Eq, Hash)]
+    enum Group { Database }
+
+    struct Pool;
+    struct Migrations;
+    struct Cache;
+
+    let built = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let log = |name: &'static str| {
+        let built = built.clone();
+        move || built.lock().unwrap().push(name)
+    };
+
+    let mut services = ServiceCollection::new();
+    let record = log("pool");
+    services.add_singleton_factory::<Pool, _>(move |_| { record(); Pool }).label(Group::Database);
+    let record = log("migrations");
+    services.add_singleton_factory::<Migrations, _>(move |_| { record(); Migrations }).label(Group::Database);
+    let record = log("cache");
+    services.add_singleton_factory::<Cache, _>(move |_| { record(); Cache });
+
+    let provider = services.build();
+    let report = provider.prewarm_labeled(Group::Database).await;
+    assert!(report.all_ready());
+    assert_eq!(report.services.len(), 2);
+    assert_eq!(report.cold_count(), 2);
+
+    let mut warmed = built.lock().unwrap().clone();
+    warmed.sort_unstable();
+    assert_eq!(warmed, vec!["migrations", "pool"]);
+
+    // Warmed services are cached; the unlabeled one is still cold
+    assert!(provider.get_with_info::<Pool>().unwrap().1.cache_hit);
+    assert!(!provider.get_with_info::<Cache>().unwrap().1.cache_hit);
+    assert_eq!(provider.prewarm_labeled(Group::Database).await.cold_count(), 0);
+}
+
+#[tokio::test]
+async fn test_prewarm_labeled_builds_only_the_labeled_multi_binding() {
+    #[derive(PartialEq, Eq, Hash)]
+    struct Critical;
+
+    trait Plugin: Send + Sync {}
+    struct Audit;
+    impl Plugin for Audit {}
+    struct Metrics;
+    impl Plugin for Metrics {}
+
+    let built = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut services = ServiceCollection::new();
+    let log = built.clone();
+    services.add_trait_factory::<dyn Plugin, _>(Lifetime::Singleton, move |_| {
+        log.lock().unwrap().push("audit");
+        Arc::new(Audit)
+    });
+    let log = built.clone();
+    services.add_trait_factory::<dyn Plugin, _>(Lifetime::Singleton, move |_| {
+        log.lock().unwrap().push("metrics");
+        Arc::new(Metrics)
+    }).label(Critical);
+
+    let provider = services.build();
+    let report = provider.prewarm_labeled(Critical).await;
+    assert!(report.all_ready());
+    assert_eq!(report.cold_count(), 1);
+    assert_eq!(*built.lock().unwrap(), vec!["metrics"]);
+
+    // The warmed instance is reused once every binding is resolved
+    assert_eq!(provider.get_all_trait::<dyn Plugin>().unwrap().len(), 2);
+    assert_eq!(*built.lock().unwrap(), vec!["metrics", "audit"]);
+}
+
+#[test]
+fn test_ring_buffer_observer_keeps_latest_events() {
+    use ferrous_di::{Key, ResolutionOutcome, RingBufferObserver};
+
+    struct A;
+    struct B;
+    struct C;
+    struct D;
+    struct E;
+
+    let recent = Arc::new(RingBufferObserver::new(3));
+    let mut services = ServiceCollection::new();
+    services.add_observer(recent.clone());
+    services.add_transient_factory::<A, _>(|_| A);
+    services.add_transient_factory::<B, _>(|_| B);
+    services.add_transient_factory::<C, _>(|_| C);
+    services.add_transient_factory::<D, _>(|_| D);
+    services.add_transient_factory::<E, _>(|_| E);
+    let provider = services.build();
+
+    provider.get_required::<A>();
+    provider.get_required::<B>();
+    provider.get_required::<C>();
+    provider.get_required::<D>();
+    provider.get_required::<E>();
+
+    let events = recent.snapshot();
+    let names: Vec<&str> = events.iter().map(|(key, _, _)| key.display_name()).collect();
+    assert_eq!(names, [
+        std::any::type_name::<C>(),
+        std::any::type_name::<D>(),
+        std::any::type_name::<E>(),
+    ]);
+    assert!(events.iter().all(|(_, _, outcome)| *outcome == ResolutionOutcome::Resolved));
+    assert!(matches!(events[2].0, Key::Type(..)));
+}
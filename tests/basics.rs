@@ -140,4 +140,302 @@ fn test_complex_dependency_graph() {
     assert_eq!(c.b.a.value, 100);
     // A is singleton, so should be same instance
     assert!(Arc::ptr_eq(&c.a, &c.b.a));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_eager_singleton_factory_runs_during_build() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Config;
+    struct BrokenClient;
+
+    let built = Arc::new(AtomicUsize::new(0));
+    let counter = built.clone();
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton_eager_factory::<Config, _>(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Config
+    });
+    let sp = sc.build();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    sp.get_required::<Config>();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton_eager_factory::<BrokenClient, _>(|_| panic!("missing API key"));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sc.build()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_get_with_overrides_swaps_dependency_for_one_call() {
+    use ferrous_di::OverrideSet;
+
+    struct Database { url: &'static str }
+    struct UserService { db: Arc<Database> }
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton(Database { url: "postgres://prod" });
+    sc.add_singleton_factory::<UserService, _>(|r| UserService {
+        db: r.get_required::<Database>(),
+    });
+    let sp = sc.build();
+
+    let overrides = OverrideSet::new().with(Database { url: "stub://" });
+    let stubbed = sp.get_with_overrides::<UserService>(overrides).unwrap();
+    assert_eq!(stubbed.db.url, "stub://");
+
+    // The singleton cache and other dependents still see the real database
+    let real = sp.get_required::<UserService>();
+    assert_eq!(real.db.url, "postgres://prod");
+    assert!(!Arc::ptr_eq(&stubbed, &real));
+    assert_eq!(sp.get_required::<Database>().url, "postgres://prod");
+
+    let scope = sp.create_scope();
+    let from_scope = scope.get_with_overrides::<UserService>(OverrideSet::new()).unwrap();
+    assert_eq!(from_scope.db.url, "postgres://prod");
+}
+
+#[test]
+fn test_get_factory_builds_fresh_instances_with_shared_dependencies() {
+    struct Database;
+    struct RequestContext;
+    struct Worker { db: Arc<Database>, request: Arc<RequestContext> }
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton(Database);
+    sc.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    sc.add_transient_factory::<Worker, _>(|r| Worker {
+        db: r.get_required::<Database>(),
+        request: r.get_required::<RequestContext>(),
+    });
+    let sp = sc.build();
+    let scope = sp.create_scope();
+
+    let make_worker = scope.get_factory::<Worker>().unwrap();
+    let workers: Vec<Arc<Worker>> = (0..3).map(|_| make_worker().unwrap()).collect();
+
+    assert!(!Arc::ptr_eq(&workers[0], &workers[1]));
+    assert!(!Arc::ptr_eq(&workers[1], &workers[2]));
+    assert!(!Arc::ptr_eq(&workers[0], &workers[2]));
+
+    let db = sp.get_required::<Database>();
+    let request = scope.get_required::<RequestContext>();
+    for worker in &workers {
+        assert!(Arc::ptr_eq(&worker.db, &db));
+        assert!(Arc::ptr_eq(&worker.request, &request));
+    }
+
+    // Non-transient registrations hand back the resolved instance
+    let db_factory = sp.get_factory::<Database>().unwrap();
+    assert!(Arc::ptr_eq(&db_factory().unwrap(), &db));
+    assert!(sp.get_factory::<String>().is_err());
+}
+
+#[tokio::test]
+async fn test_get_factory_rebuilds_nested_transients_in_originating_scope() {
+    struct RequestContext;
+    struct Helper { request: Arc<RequestContext> }
+    struct Worker { helper: Arc<Helper> }
+
+    let disposed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let counter = disposed.clone();
+    let mut sc = ServiceCollection::new();
+    sc.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    sc.add_transient_factory::<Helper, _>(move |r| {
+        let counter = counter.clone();
+        ferrous_di::ResolverCore::push_sync_disposer(r, Box::new(move || {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }));
+        Helper { request: r.get_required::<RequestContext>() }
+    });
+    sc.add_transient_factory::<Worker, _>(|r| Worker { helper: r.get_required::<Helper>() });
+    let sp = sc.build();
+    let scope = sp.create_scope();
+
+    let make_worker = scope.get_factory::<Worker>().unwrap();
+    let first = make_worker().unwrap();
+    let second = make_worker().unwrap();
+    let request = scope.get_required::<RequestContext>();
+    assert!(Arc::ptr_eq(&first.helper.request, &request));
+    assert!(Arc::ptr_eq(&second.helper.request, &request));
+
+    // Hooks of later instances belong to the scope, not the root
+    sp.dispose_all().await;
+    assert_eq!(disposed.load(std::sync::atomic::Ordering::SeqCst), 0);
+    scope.dispose_all().await;
+    assert_eq!(disposed.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_assert_on_build_fails_build_with_message() {
+    struct PoolConfig { size: usize }
+    struct WorkerConfig { workers: usize }
+
+    fn check(provider: &ferrous_di::ServiceProvider) -> Result<(), String> {
+        let pool = provider.get_required::<PoolConfig>();
+        let workers = provider.get_required::<WorkerConfig>();
+        if pool.size < workers.workers {
+            return Err(format!("pool size {} is below worker count {}", pool.size, workers.workers));
+        }
+        Ok(())
+    }
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton(PoolConfig { size: 8 });
+    sc.add_singleton(WorkerConfig { workers: 4 });
+    sc.assert_on_build(check);
+    assert_eq!(sc.build().get_required::<PoolConfig>().size, 8);
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton(PoolConfig { size: 2 });
+    sc.assert_on_build(check);
+    // Registered after the assertion; assertions still see it
+    sc.add_singleton(WorkerConfig { workers: 4 });
+
+    let panic = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sc.build())) {
+        Ok(_) => panic!("build should fail the assertion"),
+        Err(panic) => panic,
+    };
+    let message = panic.downcast_ref::<String>().expect("formatted panic message");
+    assert!(message.contains("Build assertion failed"), "{}", message);
+    assert!(message.contains("pool size 2 is below worker count 4"), "{}", message);
+}
+
+trait Logger: Send + Sync {
+    fn log(&self, message: &str);
+}
+ferrous_di::trait_fn!(dyn Logger => fn log(&self, message: &str));
+
+#[test]
+fn test_closure_registered_as_trait_impl() {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let sink = lines.clone();
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton_trait_fn::<dyn Logger, _>(move |message: &str| {
+        sink.lock().unwrap().push(message.to_string());
+    });
+    let sp = sc.build();
+
+    let logger = sp.get_required_trait::<dyn Logger>();
+    logger.log("first");
+    sp.get_required_trait::<dyn Logger>().log("second");
+
+    assert!(Arc::ptr_eq(&logger, &sp.get_required_trait::<dyn Logger>()));
+    assert_eq!(*lines.lock().unwrap(), vec!["first", "second"]);
+}
+
+#[test]
+fn test_with_fallback_delegates_unregistered_services_to_parent() {
+    struct Database { url: &'static str }
+    struct Logger { prefix: &'static str }
+    struct PluginTool { db: Arc<Database>, logger: Arc<Logger> }
+
+    let mut host = ServiceCollection::new();
+    host.add_singleton_factory::<Database, _>(|_| Database { url: "postgres://host" });
+    host.add_singleton(Logger { prefix: "host" });
+    let host = host.build();
+
+    let mut plugin = ServiceCollection::new();
+    plugin.add_singleton(Logger { prefix: "plugin" });
+    plugin.add_transient_factory::<PluginTool, _>(|r| PluginTool {
+        db: r.get_required::<Database>(),
+        logger: r.get_required::<Logger>(),
+    });
+    let plugin = plugin.build().with_fallback(host.clone());
+
+    // The plugin's own registration wins
+    assert_eq!(plugin.get_required::<Logger>().prefix, "plugin");
+    assert_eq!(host.get_required::<Logger>().prefix, "host");
+
+    // Everything else comes from the host, sharing its singletons
+    let tool = plugin.create_scope().get_required::<PluginTool>();
+    assert_eq!(tool.db.url, "postgres://host");
+    assert_eq!(tool.logger.prefix, "plugin");
+    assert!(Arc::ptr_eq(&tool.db, &host.get_required::<Database>()));
+    assert!(Arc::ptr_eq(&plugin.get_required::<Database>(), &host.get_required::<Database>()));
+
+    // Missing from both is still NotFound
+    assert!(matches!(plugin.get::<String>(), Err(ferrous_di::DiError::NotFound(_))));
+    assert!(host.get::<PluginTool>().is_err());
+}
+
+#[test]
+fn test_with_fallback_covers_multi_bindings_and_existing_clones() {
+    trait Tool: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+    struct Search;
+    impl Tool for Search {
+        fn name(&self) -> &'static str { "search" }
+    }
+    struct Shell;
+    impl Tool for Shell {
+        fn name(&self) -> &'static str { "shell" }
+    }
+
+    let mut host = ServiceCollection::new();
+    host.add_trait_implementation(Arc::new(Search) as Arc<dyn Tool>, ferrous_di::Lifetime::Singleton);
+    let host = host.build();
+
+    let bare = ServiceCollection::new().build();
+    let earlier_clone = bare.clone();
+    let _bare = bare.with_fallback(host.clone());
+
+    // Clones taken before the call fall back too, for all() as well
+    let tools = earlier_clone.get_all_trait::<dyn Tool>().unwrap();
+    assert_eq!(tools.iter().map(|t| t.name()).collect::<Vec<_>>(), vec!["search"]);
+
+    // Local multi-bindings replace the parent's rather than extend them
+    let mut plugin = ServiceCollection::new();
+    plugin.add_trait_implementation(Arc::new(Shell) as Arc<dyn Tool>, ferrous_di::Lifetime::Singleton);
+    let plugin = plugin.build().with_fallback(host);
+    let tools = plugin.create_scope().get_all_trait::<dyn Tool>().unwrap();
+    assert_eq!(tools.iter().map(|t| t.name()).collect::<Vec<_>>(), vec!["shell"]);
+}
+
+#[test]
+#[should_panic(expected = "fallback would form a cycle")]
+fn test_with_fallback_rejects_cycles() {
+    let a = ServiceCollection::new().build();
+    let b = ServiceCollection::new().build().with_fallback(a.clone());
+    let c = ServiceCollection::new().build().with_fallback(b);
+    let _ = a.with_fallback(c);
+}
+
+#[test]
+fn test_null_object_trait_is_fallback_only() {
+    trait Logger: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+    struct NullLogger;
+    impl Logger for NullLogger {
+        fn name(&self) -> &'static str { "null" }
+    }
+    struct ConsoleLogger;
+    impl Logger for ConsoleLogger {
+        fn name(&self) -> &'static str { "console" }
+    }
+
+    // Only the null object: resolution falls back to it
+    let mut sc = ServiceCollection::new();
+    sc.add_null_object_trait::<dyn Logger>(Arc::new(NullLogger));
+    let sp = sc.build();
+    assert_eq!(sp.get_required_trait::<dyn Logger>().name(), "null");
+
+    // A real logger wins, even when registered after the null object
+    let mut sc = ServiceCollection::new();
+    sc.add_null_object_trait::<dyn Logger>(Arc::new(NullLogger));
+    sc.add_singleton_trait(Arc::new(ConsoleLogger) as Arc<dyn Logger>);
+    let sp = sc.build();
+    assert_eq!(sp.get_required_trait::<dyn Logger>().name(), "console");
+
+    // So does an implementation added as a multi-binding
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation(Arc::new(ConsoleLogger) as Arc<dyn Logger>, ferrous_di::Lifetime::Singleton);
+    sc.add_null_object_trait::<dyn Logger>(Arc::new(NullLogger));
+    let sp = sc.build();
+    assert_eq!(sp.get_required_trait::<dyn Logger>().name(), "console");
+}
@@ -219,4 +219,408 @@ async fn test_scoped_disposal_isolation() {
     // Only scope1's service should be disposed
     assert_eq!(order.len(), 1);
     assert!(order[0].starts_with("scoped-"));
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_dispose_all_reports_summary() {
+    struct Handle;
+    impl Dispose for Handle {
+        fn dispose(&self) {}
+    }
+
+    struct A;
+    struct B;
+    struct C;
+
+    let mut sc = ServiceCollection::new();
+    sc.add_scoped_factory::<A, _>(|r| {
+        r.register_disposer(Arc::new(Handle));
+        A
+    });
+    sc.add_scoped_factory::<B, _>(|r| {
+        r.register_disposer(Arc::new(Handle));
+        B
+    });
+    sc.add_scoped_factory::<C, _>(|r| {
+        r.register_disposer(Arc::new(Handle));
+        C
+    });
+
+    let provider = sc.build();
+    let scope = provider.create_scope();
+    let _a = scope.get_required::<A>();
+    let _b = scope.get_required::<B>();
+    let _c = scope.get_required::<C>();
+
+    let summary = scope.dispose_all().await;
+    assert_eq!(summary.sync_run, 3);
+    assert_eq!(summary.async_run, 0);
+    assert!(summary.errors.is_empty());
+
+    // Nothing left to run the second time
+    let again = scope.dispose_all().await;
+    assert_eq!(again.total_run(), 0);
+}
+
+#[tokio::test]
+async fn test_dispose_all_records_panicking_hook() {
+    struct Faulty;
+    impl Dispose for Faulty {
+        fn dispose(&self) {
+            panic!("flush failed");
+        }
+    }
+    struct Healthy(Arc<Mutex<bool>>);
+    impl Dispose for Healthy {
+        fn dispose(&self) {
+            *self.0.lock().unwrap() = true;
+        }
+    }
+
+    let disposed = Arc::new(Mutex::new(false));
+    let disposed_clone = disposed.clone();
+
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton_factory::<String, _>(move |r| {
+        r.register_disposer(Arc::new(Healthy(disposed_clone.clone())));
+        r.register_disposer(Arc::new(Faulty));
+        "svc".to_string()
+    });
+
+    let provider = sc.build();
+    let _ = provider.get_required::<String>();
+
+    let summary = provider.dispose_all().await;
+    assert_eq!(summary.sync_run, 2);
+    assert_eq!(summary.errors.len(), 1);
+    assert_eq!(summary.errors[0].message, "flush failed");
+    assert!(*disposed.lock().unwrap());
+}
+
+#[tokio::test]
+async fn test_dispose_all_scopes_disposes_tracked_scopes_then_root() {
+    struct Recorder(&'static str, Arc<Mutex<Vec<String>>>);
+    impl Dispose for Recorder {
+        fn dispose(&self) {
+            self.1.lock().unwrap().push(self.0.to_string());
+        }
+    }
+
+    struct RequestId;
+    struct Pool;
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let scoped_order = order.clone();
+    let root_order = order.clone();
+    let next_id = Arc::new(Mutex::new(0));
+
+    let mut sc = ServiceCollection::new();
+    sc.add_scoped_factory::<RequestId, _>(move |r| {
+        let mut id = next_id.lock().unwrap();
+        *id += 1;
+        let name = ["scope-1", "scope-2", "scope-3"][*id as usize - 1];
+        r.register_disposer(Arc::new(Recorder(name, scoped_order.clone())));
+        RequestId
+    });
+    sc.add_singleton_factory::<Pool, _>(move |r| {
+        r.register_disposer(Arc::new(Recorder("root", root_order.clone())));
+        Pool
+    });
+
+    let provider = sc.build();
+    let _pool = provider.get_required::<Pool>();
+
+    let scopes: Vec<_> = (0..3).map(|_| provider.create_scope()).collect();
+    for scope in &scopes {
+        provider.register_scope_for_tracking(scope);
+        scope.get_required::<RequestId>();
+    }
+
+    let summary = provider.dispose_all_scopes().await;
+    assert_eq!(summary.sync_run, 4);
+    assert_eq!(*order.lock().unwrap(), ["scope-1", "scope-2", "scope-3", "root"]);
+
+    // Tracked scopes no longer hand out their disposed instances
+    assert!(scopes[0].is_disposed());
+    assert!(matches!(scopes[0].get::<RequestId>(), Err(ferrous_di::DiError::ScopeDisposed(_))));
+
+    // Tracking is weak: a dropped scope is simply skipped
+    let dropped = provider.create_scope();
+    provider.register_scope_for_tracking(&dropped);
+    drop(dropped);
+    assert_eq!(provider.dispose_all_scopes().await.total_run(), 0);
+}
+
+#[tokio::test]
+async fn test_dispose_with_context_flushes_to_singleton_sink() {
+    use ferrous_di::{DiError, DisposeContext, DisposeWithContext, Lifetime};
+
+    #[derive(Default)]
+    struct Sink(Mutex<Vec<String>>);
+    struct RequestLog;
+    struct Unused;
+
+    #[derive(Default)]
+    struct Buffer {
+        pending: Mutex<Vec<String>>,
+        errors: Mutex<Vec<DiError>>,
+    }
+
+    impl DisposeWithContext for Buffer {
+        fn dispose(&self, ctx: &DisposeContext) {
+            match ctx.get::<Sink>() {
+                Ok(sink) => sink.0.lock().unwrap().append(&mut self.pending.lock().unwrap()),
+                Err(e) => self.errors.lock().unwrap().push(e),
+            }
+            self.errors.lock().unwrap().extend(ctx.get::<RequestLog>().err());
+            self.errors.lock().unwrap().extend(ctx.get::<Unused>().err());
+        }
+    }
+
+    struct Recorder(Arc<Buffer>);
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Sink::default());
+    services.add_scoped_factory::<RequestLog, _>(|_| RequestLog);
+    services.add_singleton_factory::<Unused, _>(|_| Unused);
+    services.add_scoped_factory::<Recorder, _>(|r| {
+        r.get_required::<Sink>();
+        let buffer = Arc::new(Buffer::default());
+        r.register_disposer_with_context(buffer.clone());
+        Recorder(buffer)
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+    let recorder = scope.get_required::<Recorder>();
+    recorder.0.pending.lock().unwrap().extend(["a".to_string(), "b".to_string()]);
+
+    scope.dispose_all().await;
+
+    assert_eq!(*provider.get_required::<Sink>().0.lock().unwrap(), ["a", "b"]);
+    let errors = recorder.0.errors.lock().unwrap();
+    assert!(matches!(errors[0], DiError::LifetimeNotPermitted { lifetime: Lifetime::Scoped, .. }));
+    assert!(matches!(errors[1], DiError::NotBuiltDuringDisposal(_)));
+    assert_eq!(errors.len(), 2);
+}
+
+#[tokio::test]
+async fn test_dispose_with_context_rejects_already_disposed_singleton() {
+    use ferrous_di::{DiError, DisposeContext, DisposeWithContext};
+
+    struct Sink;
+    impl Dispose for Sink {
+        fn dispose(&self) {}
+    }
+
+    #[derive(Default)]
+    struct Flusher(Mutex<Vec<DiError>>);
+    impl DisposeWithContext for Flusher {
+        fn dispose(&self, ctx: &DisposeContext) {
+            self.0.lock().unwrap().extend(ctx.get::<Sink>().err());
+        }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<Sink, _>(|r| {
+        r.register_disposer(Arc::new(Sink));
+        Sink
+    });
+    services.add_singleton_factory::<Arc<Flusher>, _>(|r| {
+        let flusher = Arc::new(Flusher::default());
+        // Registered before the sink's hook, so it runs after it
+        r.register_disposer_with_context(flusher.clone());
+        r.get_required::<Sink>();
+        flusher
+    });
+
+    let provider = services.build();
+    let flusher = provider.get_required::<Arc<Flusher>>();
+    provider.dispose_all().await;
+
+    let errors = flusher.0.lock().unwrap();
+    assert!(matches!(errors[..], [DiError::AlreadyDisposed(_)]), "{:?}", errors);
+}
+
+#[tokio::test]
+async fn test_dispose_observer_notified_for_each_hook_in_order() {
+    use ferrous_di::{DisposeObserver, Key};
+
+    #[derive(Default)]
+    struct Teardown(Mutex<Vec<&'static str>>);
+    impl DisposeObserver for Teardown {
+        fn disposed(&self, key: &Key) {
+            self.0.lock().unwrap().push(key.display_name());
+        }
+    }
+
+    struct Pool;
+    impl Dispose for Pool {
+        fn dispose(&self) {}
+    }
+    struct Session;
+    impl Dispose for Session {
+        fn dispose(&self) {}
+    }
+    struct Stream;
+    #[async_trait]
+    impl AsyncDispose for Stream {
+        async fn dispose(&self) {}
+    }
+
+    let teardown = Arc::new(Teardown::default());
+    let mut services = ServiceCollection::new();
+    services.on_dispose(teardown.clone());
+    services.add_scoped_factory::<Pool, _>(|r| {
+        r.register_disposer(Arc::new(Pool));
+        Pool
+    });
+    services.add_scoped_factory::<Session, _>(|r| {
+        r.get_required::<Pool>();
+        r.register_disposer(Arc::new(Session));
+        Session
+    });
+    services.add_scoped_factory::<Stream, _>(|r| {
+        r.register_async_disposer(Arc::new(Stream));
+        Stream
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+    scope.get_required::<Session>();
+    scope.get_required::<Stream>();
+    scope.dispose_all().await;
+
+    // Async hooks run first, then sync hooks in reverse registration order
+    assert_eq!(
+        *teardown.0.lock().unwrap(),
+        [
+            std::any::type_name::<Stream>(),
+            std::any::type_name::<Session>(),
+            std::any::type_name::<Pool>(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_disposed_scope_rejects_scoped_resolution() {
+    use ferrous_di::DiError;
+
+    struct Connection;
+    impl Dispose for Connection {
+        fn dispose(&self) {}
+    }
+    struct Settings;
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Settings);
+    services.add_scoped_factory::<Connection, _>(|r| {
+        r.register_disposer(Arc::new(Connection));
+        Connection
+    });
+    let provider = services.build();
+
+    let scope = provider.create_scope();
+    scope.get_required::<Connection>();
+    assert!(!scope.is_disposed());
+    scope.dispose_all().await;
+    assert!(scope.is_disposed());
+
+    // The disposed instance is not handed out again
+    assert!(matches!(scope.get::<Connection>(), Err(DiError::ScopeDisposed(_))));
+    // Singletons are still reachable by default
+    assert!(scope.get::<Settings>().is_ok());
+
+    let strict = provider.create_scope().with_singletons_after_dispose(false);
+    strict.dispose_all().await;
+    assert!(matches!(strict.get::<Settings>(), Err(DiError::ScopeDisposed(_))));
+    assert!(matches!(strict.get::<Connection>(), Err(DiError::ScopeDisposed(_))));
+
+    // Other scopes are unaffected
+    assert!(provider.create_scope().get::<Connection>().is_ok());
+}
+
+#[tokio::test]
+async fn test_disposer_priority_overrides_resolution_order() {
+    struct Named(&'static str, Arc<Mutex<Vec<&'static str>>>);
+    impl Dispose for Named {
+        fn dispose(&self) {
+            self.1.lock().unwrap().push(self.0);
+        }
+    }
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut services = ServiceCollection::new();
+    let hooks = log.clone();
+    services.add_scoped_factory::<u8, _>(move |r| {
+        r.register_disposer(Arc::new(Named("writer", hooks.clone())));
+        r.register_disposer_with_priority(Arc::new(Named("buffer", hooks.clone())), 10);
+        r.register_disposer_with_priority(Arc::new(Named("metrics", hooks.clone())), -5);
+        r.register_disposer(Arc::new(Named("socket", hooks.clone())));
+        r.register_disposer_with_priority(Arc::new(Named("encoder", hooks.clone())), 10);
+        0
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+    scope.get_required::<u8>();
+    let summary = scope.dispose_all().await;
+    assert_eq!(summary.sync_run, 5);
+
+    // Highest priority first; reverse registration order within a priority
+    assert_eq!(*log.lock().unwrap(), ["encoder", "buffer", "socket", "writer", "metrics"]);
+}
+
+#[tokio::test]
+async fn test_take_scoped_hands_disposal_to_caller() {
+    struct Channel {
+        name: &'static str,
+        log: Arc<Mutex<Vec<&'static str>>>,
+    }
+    impl Dispose for Channel {
+        fn dispose(&self) {
+            self.log.lock().unwrap().push(self.name);
+        }
+    }
+    struct Buffer(Arc<Mutex<Vec<&'static str>>>);
+    impl Dispose for Buffer {
+        fn dispose(&self) {
+            self.0.lock().unwrap().push("buffer");
+        }
+    }
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut services = ServiceCollection::new();
+    let hooks = log.clone();
+    services.add_scoped_factory::<Buffer, _>(move |r| {
+        r.register_disposer(Arc::new(Buffer(hooks.clone())));
+        Buffer(hooks.clone())
+    });
+    let hooks = log.clone();
+    services.add_scoped_factory::<Channel, _>(move |r| {
+        r.get_required::<Buffer>();
+        r.register_disposer(Arc::new(Channel { name: "channel", log: hooks.clone() }));
+        Channel { name: "channel", log: hooks.clone() }
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+    let channel = scope.take_scoped::<Channel>().unwrap();
+
+    // The scope still disposes the dependency, but not the taken channel
+    let summary = scope.dispose_all().await;
+    assert_eq!(summary.sync_run, 1);
+    assert_eq!(*log.lock().unwrap(), ["buffer"]);
+
+    channel.dispose();
+    assert_eq!(*log.lock().unwrap(), ["buffer", "channel"]);
+
+    // Only scoped services can be taken
+    let mut services = ServiceCollection::new();
+    services.add_singleton(0u8);
+    let provider = services.build();
+    assert!(matches!(
+        provider.create_scope().take_scoped::<u8>(),
+        Err(ferrous_di::DiError::WrongLifetime(_))
+    ));
+}
@@ -190,9 +190,11 @@ fn test_single_binding_fallback_to_multi() {
     
     let sp = sc.build();
     
-    // get_trait should return the last multi-binding
-    let single = sp.get_trait::<dyn Service>().unwrap();
-    assert_eq!(single.value(), 20);
+    // get_trait refuses to pick one of several multi-bindings
+    assert!(matches!(
+        sp.get_trait::<dyn Service>(),
+        Err(ferrous_di::DiError::AmbiguousRegistration { .. })
+    ));
     
     // get_all_trait should return all
     let all = sp.get_all_trait::<dyn Service>().unwrap();
@@ -247,4 +249,420 @@ fn test_multi_binding_with_dependencies() {
     assert_eq!(processors.len(), 2);
     assert_eq!(processors[0].process("hello"), ">>: hello");
     assert_eq!(processors[1].process("hello"), "HELLO");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_get_all_trait_grouped_by_tag() {
+    trait Tool: Send + Sync {
+        fn name(&self) -> &str;
+    }
+
+    struct Named(&'static str);
+    impl Tool for Named {
+        fn name(&self) -> &str { self.0 }
+    }
+
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation_tagged::<dyn Tool>(Arc::new(Named("read_file")), Lifetime::Singleton, &[("category", "read")]);
+    sc.add_trait_implementation_tagged::<dyn Tool>(Arc::new(Named("write_file")), Lifetime::Singleton, &[("category", "write")]);
+    sc.add_trait_implementation_tagged::<dyn Tool>(Arc::new(Named("list_dir")), Lifetime::Singleton, &[("category", "read")]);
+    sc.add_trait_implementation(Arc::new(Named("untagged")) as Arc<dyn Tool>, Lifetime::Singleton);
+
+    let provider = sc.build();
+    let groups = provider.get_all_trait_grouped::<dyn Tool>("category").unwrap();
+
+    assert_eq!(groups.len(), 2);
+    let read: Vec<_> = groups["read"].iter().map(|t| t.name()).collect();
+    let write: Vec<_> = groups["write"].iter().map(|t| t.name()).collect();
+    assert_eq!(read, ["read_file", "list_dir"]);
+    assert_eq!(write, ["write_file"]);
+}
+
+#[test]
+fn test_resolve_trait_stream_is_lazy() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    trait Tool: Send + Sync {
+        fn id(&self) -> usize;
+    }
+
+    struct Numbered(usize);
+    impl Tool for Numbered {
+        fn id(&self) -> usize { self.0 }
+    }
+
+    static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+    let mut sc = ServiceCollection::new();
+    for id in 0..5 {
+        sc.add_trait_factory::<dyn Tool, _>(Lifetime::Singleton, move |_| {
+            CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+            Arc::new(Numbered(id)) as Arc<dyn Tool>
+        });
+    }
+
+    let provider = sc.build();
+    let first_two: Vec<usize> = provider.resolve_trait_stream::<dyn Tool>()
+        .take(2)
+        .map(|tool| tool.unwrap().id())
+        .collect();
+
+    assert_eq!(first_two, [0, 1]);
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 2);
+
+    // Singletons resolved by the stream are shared with get_all_trait
+    let all = provider.get_all_trait::<dyn Tool>().unwrap();
+    assert_eq!(all.len(), 5);
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn test_find_trait_stops_at_first_match() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    trait Tool: Send + Sync {
+        fn name(&self) -> &str;
+    }
+
+    struct Named(&'static str);
+    impl Tool for Named {
+        fn name(&self) -> &str { self.0 }
+    }
+
+    static LAST_BUILT: AtomicBool = AtomicBool::new(false);
+
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_factory::<dyn Tool, _>(Lifetime::Singleton, |_| Arc::new(Named("search")) as Arc<dyn Tool>);
+    sc.add_trait_factory::<dyn Tool, _>(Lifetime::Singleton, |_| Arc::new(Named("fs.read")) as Arc<dyn Tool>);
+    sc.add_trait_factory::<dyn Tool, _>(Lifetime::Singleton, |_| {
+        LAST_BUILT.store(true, Ordering::SeqCst);
+        Arc::new(Named("http")) as Arc<dyn Tool>
+    });
+
+    let provider = sc.build();
+    let tool = provider.find_trait::<dyn Tool>(|t| t.name() == "fs.read").unwrap();
+    assert_eq!(tool.name(), "fs.read");
+    assert!(!LAST_BUILT.load(Ordering::SeqCst));
+
+    assert!(provider.find_trait::<dyn Tool>(|t| t.name() == "missing").is_none());
+    assert!(LAST_BUILT.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_ambiguous_trait_lookup_lists_candidates() {
+    use ferrous_di::DiError;
+
+    trait Store: Send + Sync {}
+    struct MemoryStore;
+    impl Store for MemoryStore {}
+
+    let mut sc = ServiceCollection::new();
+    sc.add_named_singleton_trait::<dyn Store>("primary", Arc::new(MemoryStore));
+    sc.add_trait_implementation(Arc::new(MemoryStore) as Arc<dyn Store>, Lifetime::Singleton);
+    let sp = sc.build();
+
+    let name = std::any::type_name::<dyn Store>();
+    match sp.get_trait::<dyn Store>() {
+        Err(DiError::AmbiguousRegistration { trait_name, candidates }) => {
+            assert_eq!(trait_name, name);
+            assert_eq!(candidates, [format!("{name} (named \"primary\")"), format!("{name}[0]")]);
+        }
+        Err(other) => panic!("expected ambiguity, got {other:?}"),
+        Ok(_) => panic!("expected ambiguity, got a silent pick"),
+    }
+
+    // Disambiguating APIs still work
+    assert!(sp.get_named_trait::<dyn Store>("primary").is_ok());
+    assert_eq!(sp.get_all_trait::<dyn Store>().unwrap().len(), 1);
+
+    // A lone multi-binding is still a valid fallback
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation(Arc::new(MemoryStore) as Arc<dyn Store>, Lifetime::Singleton);
+    assert!(sc.build().get_trait::<dyn Store>().is_ok());
+}
+
+#[test]
+fn test_get_named_map_keys_implementations_by_name() {
+    trait Handler: Send + Sync {
+        fn id(&self) -> &str;
+    }
+
+    struct Named(&'static str);
+    impl Handler for Named {
+        fn id(&self) -> &str { self.0 }
+    }
+
+    let mut sc = ServiceCollection::new();
+    sc.add_named_singleton_trait::<dyn Handler>("ping", Arc::new(Named("ping-handler")));
+    sc.add_named_singleton_trait_factory::<dyn Handler, _>("echo", |_| Arc::new(Named("echo-handler")) as Arc<dyn Handler>);
+    sc.add_named_transient_trait_factory::<dyn Handler, _>("status", |_| Arc::new(Named("status-handler")) as Arc<dyn Handler>);
+    // Unnamed and multi-bindings are not part of the map
+    sc.add_singleton_trait::<dyn Handler>(Arc::new(Named("default")));
+    sc.add_trait_implementation(Arc::new(Named("extra")) as Arc<dyn Handler>, Lifetime::Singleton);
+
+    let provider = sc.build();
+    let handlers = provider.get_named_map::<dyn Handler>().unwrap();
+
+    assert_eq!(handlers.len(), 3);
+    assert_eq!(handlers["ping"].id(), "ping-handler");
+    assert_eq!(handlers["echo"].id(), "echo-handler");
+    assert_eq!(handlers["status"].id(), "status-handler");
+}
+
+#[test]
+fn test_get_trait_top_returns_newest_first() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    trait Layer: Send + Sync {
+        fn name(&self) -> &str;
+    }
+
+    struct Named(&'static str);
+    impl Layer for Named {
+        fn name(&self) -> &str { self.0 }
+    }
+
+    static BUILT: AtomicUsize = AtomicUsize::new(0);
+
+    let mut sc = ServiceCollection::new();
+    for name in ["one", "two", "three", "four", "five"] {
+        sc.add_trait_factory::<dyn Layer, _>(Lifetime::Singleton, move |_| {
+            BUILT.fetch_add(1, Ordering::SeqCst);
+            Arc::new(Named(name)) as Arc<dyn Layer>
+        });
+    }
+
+    let provider = sc.build();
+    let top: Vec<String> = provider.get_trait_top::<dyn Layer>(2).unwrap()
+        .iter()
+        .map(|layer| layer.name().to_string())
+        .collect();
+    assert_eq!(top, ["five", "four"]);
+    assert_eq!(BUILT.load(Ordering::SeqCst), 2);
+
+    assert_eq!(provider.get_trait_top::<dyn Layer>(10).unwrap().len(), 5);
+    assert!(provider.get_trait_top::<dyn Layer>(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_panicking_factory_does_not_poison_other_resolutions() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    trait Hook: Send + Sync {}
+
+    trait Plugin: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+    struct Healthy;
+    impl Plugin for Healthy {
+        fn name(&self) -> &'static str { "healthy" }
+    }
+
+    struct Flaky;
+
+    let failed_once = Arc::new(AtomicBool::new(false));
+    let flag = failed_once.clone();
+
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_factory::<dyn Hook, _>(Lifetime::Singleton, |_| -> Arc<dyn Hook> {
+        panic!("hook factory failed")
+    });
+    sc.add_trait_factory::<dyn Plugin, _>(Lifetime::Singleton, |_| Arc::new(Healthy) as Arc<dyn Plugin>);
+    sc.add_singleton_factory::<Flaky, _>(move |_| {
+        if !flag.swap(true, Ordering::SeqCst) {
+            panic!("first attempt failed");
+        }
+        Flaky
+    });
+    let sp = sc.build();
+
+    let hooks = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sp.get_all_trait::<dyn Hook>()));
+    assert!(hooks.is_err());
+    let plugins = sp.get_all_trait::<dyn Plugin>().unwrap();
+    assert_eq!(plugins[0].name(), "healthy");
+
+    // The singleton's slot was locked when its factory panicked; the retry recovers
+    let flaky = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sp.get::<Flaky>()));
+    assert!(flaky.is_err());
+    assert!(sp.get::<Flaky>().is_ok());
+    assert!(failed_once.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_get_required_single_trait_rejects_multiple_registrations() {
+    use ferrous_di::DiError;
+
+    trait Clock: Send + Sync {
+        fn now(&self) -> u64;
+    }
+    struct FixedClock(u64);
+    impl Clock for FixedClock {
+        fn now(&self) -> u64 { self.0 }
+    }
+
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation::<dyn Clock>(Arc::new(FixedClock(1)), Lifetime::Singleton);
+    sc.add_trait_implementation::<dyn Clock>(Arc::new(FixedClock(2)), Lifetime::Singleton);
+    let sp = sc.build();
+
+    match sp.get_required_single_trait::<dyn Clock>() {
+        Err(DiError::ExpectedSingleImplementation { trait_name, found }) => {
+            assert_eq!(trait_name, std::any::type_name::<dyn Clock>());
+            assert_eq!(found, 2);
+        }
+        other => panic!("expected ExpectedSingleImplementation, got {:?}", other.map(|c| c.now())),
+    }
+
+    // A single binding shadows the multi-binding in the lenient lookup
+    let mut sc = ServiceCollection::new();
+    sc.add_singleton_trait::<dyn Clock>(Arc::new(FixedClock(3)));
+    sc.add_trait_implementation::<dyn Clock>(Arc::new(FixedClock(4)), Lifetime::Singleton);
+    let sp = sc.build();
+    assert_eq!(sp.get_required_trait::<dyn Clock>().now(), 3);
+    assert!(matches!(
+        sp.get_required_single_trait::<dyn Clock>(),
+        Err(DiError::ExpectedSingleImplementation { found: 2, .. })
+    ));
+
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation::<dyn Clock>(Arc::new(FixedClock(7)), Lifetime::Singleton);
+    let sp = sc.build();
+    assert_eq!(sp.get_required_single_trait::<dyn Clock>().unwrap().now(), 7);
+    assert_eq!(sp.create_scope().get_required_single_trait::<dyn Clock>().unwrap().now(), 7);
+}
+
+#[test]
+fn test_dedup_multi_bindings_collapses_shared_instance() {
+    trait Tool: Send + Sync {}
+    struct Search;
+    impl Tool for Search {}
+    struct Calculator;
+    impl Tool for Calculator {}
+
+    let shared: Arc<dyn Tool> = Arc::new(Search);
+    let register = |sc: &mut ServiceCollection| {
+        sc.add_trait_implementation(shared.clone(), Lifetime::Singleton);
+        sc.add_trait_implementation(Arc::new(Calculator) as Arc<dyn Tool>, Lifetime::Singleton);
+        sc.add_trait_implementation(shared.clone(), Lifetime::Singleton);
+    };
+
+    let mut plain = ServiceCollection::new();
+    register(&mut plain);
+    assert_eq!(plain.build().get_all_trait::<dyn Tool>().unwrap().len(), 3);
+
+    let mut deduped = ServiceCollection::new();
+    register(&mut deduped);
+    deduped.dedup_multi_bindings();
+    let tools = deduped.build().get_all_trait::<dyn Tool>().unwrap();
+    assert_eq!(tools.len(), 2);
+    assert!(Arc::ptr_eq(&tools[0], &shared));
+    assert!(!Arc::ptr_eq(&tools[1], &shared));
+}
+
+#[test]
+fn test_dedup_multi_bindings_moves_labels_with_their_bindings() {
+    use ferrous_di::Key;
+
+    trait Tool: Send + Sync {}
+    struct Search;
+    impl Tool for Search {}
+    struct Calculator;
+    impl Tool for Calculator {}
+
+    #[derive(PartialEq, Eq, Hash)]
+    enum Source { Core, Math, Plugin }
+
+    let shared: Arc<dyn Tool> = Arc::new(Search);
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation(shared.clone(), Lifetime::Singleton).label(Source::Core);
+    sc.add_trait_implementation(shared.clone(), Lifetime::Singleton).label(Source::Plugin);
+    sc.add_trait_implementation(Arc::new(Calculator) as Arc<dyn Tool>, Lifetime::Singleton).label(Source::Math);
+    sc.dedup_multi_bindings();
+    let sp = sc.build();
+
+    let tool = std::any::type_name::<dyn Tool>();
+    assert_eq!(sp.services_with_label(Source::Core), vec![Key::MultiTrait(tool, 0)]);
+    assert_eq!(sp.services_with_label(Source::Plugin), vec![Key::MultiTrait(tool, 0)]);
+    assert_eq!(sp.services_with_label(Source::Math), vec![Key::MultiTrait(tool, 1)]);
+}
+
+#[test]
+fn test_add_trait_implementations_registers_in_order() {
+    trait Tool: Send + Sync {
+        fn name(&self) -> &str;
+    }
+    struct NamedTool(&'static str);
+    impl Tool for NamedTool {
+        fn name(&self) -> &str { self.0 }
+    }
+
+    let names = ["search", "calculator", "browser", "shell", "editor"];
+    let mut sc = ServiceCollection::new();
+    let count = sc.add_trait_implementations::<dyn Tool>(
+        names.iter().map(|&name| Arc::new(NamedTool(name)) as Arc<dyn Tool>),
+        Lifetime::Singleton,
+    );
+    assert_eq!(count, 5);
+
+    let sp = sc.build();
+    let tools = sp.get_all_trait::<dyn Tool>().unwrap();
+    let resolved: Vec<&str> = tools.iter().map(|tool| tool.name()).collect();
+    assert_eq!(resolved, names);
+}
+
+#[test]
+fn test_validate_trait_casts_catches_misregistered_factory() {
+    use ferrous_di::DiError;
+
+    trait Handler<'a>: Send + Sync {}
+    struct Length;
+    impl Handler<'_> for Length {}
+
+    // Both trait types are named "dyn Handler<'_>", so they share a key but
+    // store different erased types
+    let mut sc = ServiceCollection::new();
+    sc.add_trait_implementation(Arc::new(Length) as Arc<dyn for<'a> Handler<'a>>, Lifetime::Singleton);
+    sc.add_trait_factory::<dyn Handler<'static>, _>(Lifetime::Transient, |_| Arc::new(Length));
+    sc.validate_trait_casts();
+
+    let sp = sc.build();
+    match sp.build_warnings() {
+        [DiError::TypeMismatch(name)] => {
+            assert_eq!(*name, std::any::type_name::<dyn for<'a> Handler<'a>>());
+        }
+        other => panic!("unexpected findings: {other:?}"),
+    }
+    assert!(matches!(sp.get_all_trait::<dyn for<'a> Handler<'a>>(), Err(DiError::TypeMismatch(_))));
+}
+
+#[test]
+fn test_validate_trait_casts_leaves_provider_untouched_and_skips_panics() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    trait Tool: Send + Sync {}
+    struct Search;
+    impl Tool for Search {}
+    struct Index;
+
+    let built = Arc::new(AtomicUsize::new(0));
+    let mut sc = ServiceCollection::new();
+    let counter = built.clone();
+    sc.add_singleton_factory::<Index, _>(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Index
+    });
+    sc.add_trait_factory::<dyn Tool, _>(Lifetime::Singleton, |r| {
+        r.get_required::<Index>();
+        Arc::new(Search)
+    });
+    sc.add_trait_factory::<dyn Tool, _>(Lifetime::Singleton, |_| panic!("misconfigured tool"));
+    sc.validate_trait_casts();
+
+    let sp = sc.build();
+    assert!(sp.build_warnings().is_empty());
+
+    // The check built Index in a throwaway provider; the real one builds its own
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    sp.get_required::<Index>();
+    assert_eq!(built.load(Ordering::SeqCst), 2);
+}
@@ -227,4 +227,415 @@ fn test_mixed_lifetimes_in_scope() {
     // Same singleton instance
     assert!(Arc::ptr_eq(&t1.scoped.singleton, &t2.scoped.singleton));
     assert_eq!(t1.scoped.singleton.value, "shared");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_singleton_init_origin_records_scope() {
+    use ferrous_di::{key_of_type, Lifetime, ValidationBuilder, ValidationWarning};
+
+    struct ScopedCache;
+    struct RootConfig;
+    struct RequestState;
+
+    let mut collection = ServiceCollection::new();
+    collection.add_singleton_factory::<ScopedCache, _>(|_| ScopedCache);
+    collection.add_singleton_factory::<RootConfig, _>(|_| RootConfig);
+    collection.add_scoped_factory::<RequestState, _>(|_| RequestState);
+    let provider = collection.build();
+
+    let _ = provider.get_required::<RootConfig>();
+    let scope = provider.create_scope();
+    let _ = scope.get_required::<ScopedCache>();
+    let other = provider.create_scope();
+    let _ = other.get_required::<ScopedCache>();
+
+    assert_ne!(scope.id(), other.id());
+    assert_eq!(provider.singleton_init_origin(&key_of_type::<ScopedCache>()), Some(scope.id()));
+    assert_eq!(provider.singleton_init_origin(&key_of_type::<RootConfig>()), None);
+
+    let result = ValidationBuilder::new()
+        .register::<ScopedCache>(Lifetime::Singleton)
+        .register::<RequestState>(Lifetime::Scoped)
+        .depends_on::<ScopedCache, RequestState>()
+        .with_init_origins(&provider)
+        .validate_runtime();
+    assert!(result.warnings.iter().any(|w| matches!(
+        w,
+        ValidationWarning::SingletonInitializedInScope { scope_id, .. } if *scope_id == scope.id()
+    )));
+}
+
+#[test]
+fn test_live_scope_count_tracks_outstanding_scopes() {
+    let provider = ServiceCollection::new().build();
+    assert_eq!(provider.live_scope_count(), 0);
+
+    let scopes: Vec<_> = (0..5).map(|_| provider.create_scope()).collect();
+    assert_eq!(provider.live_scope_count(), 5);
+
+    let mut scopes = scopes.into_iter();
+    drop(scopes.next());
+    drop(scopes.next());
+    let remaining: Vec<_> = scopes.collect();
+    assert_eq!(provider.live_scope_count(), 3);
+
+    let child = remaining[0].create_child();
+    assert_eq!(provider.live_scope_count(), 4);
+
+    drop(child);
+    drop(remaining);
+    assert_eq!(provider.live_scope_count(), 0);
+}
+
+#[test]
+fn test_get_with_context_passes_args_to_factory() {
+    use ferrous_di::ResolutionArgs;
+
+    struct RequestId(String);
+    struct RequestLogger { prefix: String }
+
+    let mut services = ServiceCollection::new();
+    services.add_transient_factory::<RequestLogger, _>(|r| RequestLogger {
+        prefix: match r.arg::<RequestId>() {
+            Some(id) => format!("[{}]", id.0),
+            None => "[-]".to_string(),
+        },
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+
+    let args = ResolutionArgs::new().with(RequestId("req-7".to_string()));
+    let logger = scope.get_with_context::<RequestLogger>(args).unwrap();
+    assert_eq!(logger.prefix, "[req-7]");
+
+    // Args don't leak into later resolutions
+    let plain = scope.get_required::<RequestLogger>();
+    assert_eq!(plain.prefix, "[-]");
+}
+
+#[test]
+fn test_get_with_context_hides_args_from_dependencies() {
+    use ferrous_di::ResolutionArgs;
+
+    struct RequestId(String);
+    struct SharedClient { captured: Option<String> }
+    struct Handler { request: String, client: Arc<SharedClient> }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton_factory::<SharedClient, _>(|r| SharedClient {
+        captured: r.arg::<RequestId>().map(|id| id.0.clone()),
+    });
+    services.add_transient_factory::<Handler, _>(|r| Handler {
+        request: r.arg::<RequestId>().map_or_else(String::new, |id| id.0.clone()),
+        client: r.get_required::<SharedClient>(),
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+
+    let handler = scope
+        .get_with_context::<Handler>(ResolutionArgs::new().with(RequestId("req-1".to_string())))
+        .unwrap();
+    assert_eq!(handler.request, "req-1");
+    // The singleton was built inside the call but never saw the per-call args
+    assert_eq!(handler.client.captured, None);
+
+    let next = scope
+        .get_with_context::<Handler>(ResolutionArgs::new().with(RequestId("req-2".to_string())))
+        .unwrap();
+    assert_eq!(next.request, "req-2");
+    assert!(Arc::ptr_eq(&handler.client, &next.client));
+}
+
+#[test]
+fn test_restricted_scope_rejects_disallowed_lifetimes() {
+    use ferrous_di::{DiError, Lifetime};
+
+    struct Settings;
+    struct Session;
+    struct Scratch;
+
+    let built = Arc::new(Mutex::new(0));
+    let built_clone = built.clone();
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Settings);
+    services.add_scoped_factory::<Session, _>(|_| Session);
+    services.add_transient_factory::<Scratch, _>(move |_| {
+        *built_clone.lock().unwrap() += 1;
+        Scratch
+    });
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+    let restricted = scope.restricted(&[Lifetime::Scoped, Lifetime::Singleton]);
+
+    assert!(restricted.get::<Settings>().is_ok());
+    let session = restricted.get_required::<Session>();
+    assert!(Arc::ptr_eq(&session, &scope.get_required::<Session>()));
+
+    match restricted.get::<Scratch>() {
+        Err(DiError::LifetimeNotPermitted { lifetime, .. }) => assert_eq!(lifetime, Lifetime::Transient),
+        other => panic!("expected LifetimeNotPermitted, got {:?}", other.err()),
+    }
+    assert_eq!(*built.lock().unwrap(), 0);
+}
+
+#[test]
+fn test_memoize_caches_transient_per_scope() {
+    struct Embedder;
+
+    let runs = Arc::new(Mutex::new(0));
+    let runs_clone = runs.clone();
+
+    let mut services = ServiceCollection::new();
+    services.add_transient_factory::<Embedder, _>(move |_| {
+        *runs_clone.lock().unwrap() += 1;
+        Embedder
+    });
+
+    let provider = services.build();
+    let scope1 = provider.create_scope();
+    let scope2 = provider.create_scope();
+
+    let first = scope1.memoize::<Embedder>().unwrap();
+    let second = scope1.memoize::<Embedder>().unwrap();
+    let other = scope2.memoize::<Embedder>().unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!Arc::ptr_eq(&first, &other));
+    assert_eq!(*runs.lock().unwrap(), 2);
+}
+
+#[test]
+fn test_scoped_slot_stats_track_max_utilization() {
+    struct A;
+    struct B;
+    struct C;
+    struct D;
+
+    let mut services = ServiceCollection::new();
+    services.add_scoped_factory::<A, _>(|_| A);
+    services.add_scoped_factory::<B, _>(|_| B);
+    services.add_scoped_factory::<C, _>(|_| C);
+    services.add_scoped_factory::<D, _>(|_| D);
+    let provider = services.build();
+
+    assert_eq!(provider.scoped_slot_stats().typically_used, 0);
+
+    {
+        let scope = provider.create_scope();
+        scope.get_required::<A>();
+    }
+    {
+        let scope = provider.create_scope();
+        scope.get_required::<A>();
+        scope.get_required::<B>();
+        scope.get_required::<C>();
+    }
+    let live = provider.create_scope();
+    live.get_required::<A>();
+    live.get_required::<B>();
+    live.get_required::<C>();
+    live.get_required::<D>();
+
+    // Only dropped scopes are counted
+    let stats = provider.scoped_slot_stats();
+    assert_eq!(stats.total_slots, 4);
+    assert_eq!(stats.typically_used, 3);
+
+    drop(live);
+    assert_eq!(provider.scoped_slot_stats().typically_used, 4);
+}
+
+#[test]
+fn test_scoped_factory_derives_id_from_scope_id() {
+    struct RequestId(String);
+
+    let mut sc = ServiceCollection::new();
+    sc.add_scoped_factory::<RequestId, _>(|r| {
+        RequestId(format!("req-{}", r.scope_id().expect("resolved inside a scope")))
+    });
+    sc.add_singleton_factory::<Option<u64>, _>(|r| r.scope_id());
+    let sp = sc.build();
+
+    let scope1 = sp.create_scope();
+    let scope2 = sp.create_scope();
+    let id1 = scope1.get_required::<RequestId>();
+    let id2 = scope2.get_required::<RequestId>();
+
+    assert_ne!(id1.0, id2.0);
+    assert_eq!(id1.0, format!("req-{}", scope1.id()));
+    assert_eq!(id2.0, format!("req-{}", scope2.id()));
+    // Stable for the lifetime of the scope
+    assert_eq!(scope1.get_required::<RequestId>().0, id1.0);
+
+    // No scope when resolving from the root provider
+    assert_eq!(*sp.get_required::<Option<u64>>(), None);
+}
+
+#[test]
+fn test_scoped_from_root_policy_error_rejects() {
+    use ferrous_di::{DiError, ScopedFromRootPolicy};
+
+    struct RequestContext;
+
+    let mut sc = ServiceCollection::new();
+    sc.add_scoped_factory::<RequestContext, _>(|_| RequestContext);
+    sc.scoped_from_root_policy(ScopedFromRootPolicy::Error);
+    let sp = sc.build();
+
+    assert_eq!(sp.scoped_from_root_policy(), ScopedFromRootPolicy::Error);
+    assert!(matches!(sp.get::<RequestContext>(), Err(DiError::WrongLifetime(_))));
+}
+
+#[test]
+fn test_scoped_from_root_policy_ephemeral_scope_builds_and_disposes() {
+    use ferrous_di::{Dispose, ScopedFromRootPolicy};
+
+    struct Connection {
+        id: usize,
+        closed: Arc<Mutex<Vec<usize>>>,
+    }
+    impl Dispose for Connection {
+        fn dispose(&self) {
+            self.closed.lock().unwrap().push(self.id);
+        }
+    }
+
+    let closed = Arc::new(Mutex::new(Vec::new()));
+    let next_id = Arc::new(Mutex::new(0));
+    let mut sc = ServiceCollection::new();
+    {
+        let closed = closed.clone();
+        sc.add_scoped_factory::<Connection, _>(move |r| {
+            let mut next = next_id.lock().unwrap();
+            *next += 1;
+            let id = *next;
+            r.register_disposer(Arc::new(Connection { id, closed: closed.clone() }));
+            Connection { id, closed: closed.clone() }
+        });
+    }
+    sc.scoped_from_root_policy(ScopedFromRootPolicy::EphemeralScope);
+    let sp = sc.build();
+
+    // Each root resolution gets its own throwaway scope
+    let first = sp.get_required::<Connection>();
+    let second = sp.get_required::<Connection>();
+    assert_eq!((first.id, second.id), (1, 2));
+    assert!(closed.lock().unwrap().is_empty());
+
+    // The first scope is disposed once its instance is gone
+    drop(first);
+    let _third = sp.get_required::<Connection>();
+    assert_eq!(*closed.lock().unwrap(), vec![1]);
+
+    // Scopes still in use are disposed with the provider
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(sp.dispose_all());
+    assert_eq!(*closed.lock().unwrap(), vec![1, 3, 2]);
+}
+
+#[test]
+fn test_scoped_from_root_ephemeral_scope_leaves_async_disposers_to_dispose_all() {
+    use ferrous_di::{AsyncDispose, ScopedFromRootPolicy};
+
+    struct Stream {
+        closed: Arc<Mutex<Vec<&'static str>>>,
+    }
+    #[async_trait::async_trait]
+    impl AsyncDispose for Stream {
+        async fn dispose(&self) {
+            self.closed.lock().unwrap().push("stream");
+        }
+    }
+
+    struct Session;
+
+    let closed = Arc::new(Mutex::new(Vec::new()));
+    let mut sc = ServiceCollection::new();
+    {
+        let closed = closed.clone();
+        sc.add_scoped_factory::<Session, _>(move |r| {
+            r.register_async_disposer(Arc::new(Stream { closed: closed.clone() }));
+            Session
+        });
+    }
+    sc.scoped_from_root_policy(ScopedFromRootPolicy::EphemeralScope);
+    let sp = sc.build();
+
+    // Reaping can't await the first scope's async hook, so it waits
+    drop(sp.get_required::<Session>());
+    let _live = sp.get_required::<Session>();
+    assert!(closed.lock().unwrap().is_empty());
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(sp.dispose_all());
+    assert_eq!(*closed.lock().unwrap(), vec!["stream"; 2]);
+}
+
+#[test]
+fn test_scoped_from_root_ephemeral_scope_bounds_pending_async_disposers() {
+    use ferrous_di::{AsyncDispose, ScopedFromRootPolicy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Stream(Arc<AtomicUsize>);
+    #[async_trait::async_trait]
+    impl AsyncDispose for Stream {
+        async fn dispose(&self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct Session;
+
+    let closed = Arc::new(AtomicUsize::new(0));
+    let mut sc = ServiceCollection::new();
+    {
+        let closed = closed.clone();
+        sc.add_scoped_factory::<Session, _>(move |r| {
+            r.register_async_disposer(Arc::new(Stream(closed.clone())));
+            Session
+        });
+    }
+    sc.scoped_from_root_policy(ScopedFromRootPolicy::EphemeralScope);
+    let sp = sc.build();
+
+    // Finished scopes wait for dispose_all only up to a backlog, then get reaped
+    for _ in 0..200 {
+        drop(sp.get_required::<Session>());
+    }
+    let reaped = closed.load(Ordering::SeqCst);
+    assert!(reaped >= 100, "only {reaped} async disposers ran");
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(sp.dispose_all());
+    assert_eq!(closed.load(Ordering::SeqCst), 200);
+}
+
+#[test]
+fn test_factory_with_lifetime_caches_per_chosen_lifetime() {
+    use ferrous_di::Lifetime;
+
+    struct TenantCache;
+
+    fn register(lifetime: Lifetime) -> ferrous_di::ServiceProvider {
+        let mut sc = ServiceCollection::new();
+        sc.add_factory_with_lifetime::<TenantCache, _>(lifetime, |_| TenantCache);
+        sc.build()
+    }
+
+    // Scoped: shared within a scope, fresh per scope
+    let scoped = register(Lifetime::Scoped);
+    let (a, b) = (scoped.create_scope(), scoped.create_scope());
+    assert!(Arc::ptr_eq(&a.get_required::<TenantCache>(), &a.get_required::<TenantCache>()));
+    assert!(!Arc::ptr_eq(&a.get_required::<TenantCache>(), &b.get_required::<TenantCache>()));
+
+    // Singleton: one instance across the root and every scope
+    let singleton = register(Lifetime::Singleton);
+    let (a, b) = (singleton.create_scope(), singleton.create_scope());
+    let root = singleton.get_required::<TenantCache>();
+    assert!(Arc::ptr_eq(&root, &a.get_required::<TenantCache>()));
+    assert!(Arc::ptr_eq(&root, &b.get_required::<TenantCache>()));
+}
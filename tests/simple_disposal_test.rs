@@ -85,4 +85,92 @@ fn test_scope_drop_without_registration() {
     drop(scope);
     
     assert_eq!(DISPOSAL_COUNT.load(Ordering::SeqCst), 0);
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_child_scope_disposes_before_parent() {
+    use std::sync::Mutex;
+
+    struct Outer(Arc<Mutex<Vec<&'static str>>>);
+    impl Dispose for Outer {
+        fn dispose(&self) {
+            self.0.lock().unwrap().push("outer");
+        }
+    }
+    struct Inner(Arc<Mutex<Vec<&'static str>>>);
+    impl Dispose for Inner {
+        fn dispose(&self) {
+            self.0.lock().unwrap().push("inner");
+        }
+    }
+    struct Dropped(Arc<Mutex<Vec<&'static str>>>);
+    impl Dispose for Dropped {
+        fn dispose(&self) {
+            self.0.lock().unwrap().push("dropped");
+        }
+    }
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut services = ServiceCollection::new();
+    let o = order.clone();
+    services.add_scoped_factory::<Outer, _>(move |_| Outer(o.clone()));
+    let o = order.clone();
+    services.add_scoped_factory::<Inner, _>(move |_| Inner(o.clone()));
+    let o = order.clone();
+    services.add_scoped_factory::<Dropped, _>(move |_| Dropped(o.clone()));
+
+    let provider = services.build();
+    let scope = provider.create_scope();
+    let seen = order.clone();
+    scope.using(|resolver| async move {
+        resolver.get_disposable::<Outer>()?;
+
+        let child = resolver.child_scope();
+        child.get_disposable::<Inner>()?;
+        let summary = child.finish().await;
+        assert_eq!(summary.sync_run, 1);
+        assert_eq!(*seen.lock().unwrap(), vec!["inner"]);
+
+        {
+            let child = resolver.child_scope();
+            child.get_disposable::<Dropped>()?;
+        }
+        assert_eq!(*seen.lock().unwrap(), vec!["inner", "dropped"]);
+        Ok::<(), ferrous_di::DiError>(())
+    }).await.unwrap();
+
+    assert_eq!(*order.lock().unwrap(), vec!["inner", "dropped", "outer"]);
+}
+
+#[tokio::test]
+async fn test_get_all_trait_disposable_disposes_every_implementation() {
+    use ferrous_di::Lifetime;
+    use std::sync::Mutex;
+
+    trait Tool: Dispose + Send + Sync {}
+    struct Named(&'static str, Arc<Mutex<Vec<&'static str>>>);
+    impl Dispose for Named {
+        fn dispose(&self) {
+            self.1.lock().unwrap().push(self.0);
+        }
+    }
+    impl Tool for Named {}
+
+    let disposed = Arc::new(Mutex::new(Vec::new()));
+    let mut services = ServiceCollection::new();
+    for name in ["search", "fetch", "shell"] {
+        let log = disposed.clone();
+        services.add_trait_factory::<dyn Tool, _>(Lifetime::Scoped, move |_| Arc::new(Named(name, log.clone())) as Arc<dyn Tool>);
+    }
+
+    let provider = services.build();
+    let seen = disposed.clone();
+    provider.create_scope().using(|resolver| async move {
+        let tools = resolver.get_all_trait_disposable::<dyn Tool>()?;
+        assert_eq!(tools.len(), 3);
+        assert!(seen.lock().unwrap().is_empty());
+        Ok::<(), ferrous_di::DiError>(())
+    }).await.unwrap();
+
+    assert_eq!(*disposed.lock().unwrap(), vec!["shell", "fetch", "search"]);
+}
@@ -0,0 +1,65 @@
+use ferrous_di::{StaticService, StaticServiceTable};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts every allocation made by the test binary.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Config {
+    retries: u32,
+}
+struct Clock {
+    epoch: u64,
+}
+struct Limits {
+    max_tokens: usize,
+}
+
+static CONFIG: Config = Config { retries: 3 };
+static OVERRIDDEN: Config = Config { retries: 5 };
+static CLOCK: Clock = Clock { epoch: 1_700_000_000 };
+static LIMITS: Limits = Limits { max_tokens: 4096 };
+
+static MANIFEST: [StaticService; 4] = [
+    StaticService::new(&CONFIG),
+    StaticService::new(&CLOCK),
+    StaticService::new(&LIMITS),
+    StaticService::new(&OVERRIDDEN),
+];
+
+#[test]
+fn test_static_table_resolves_without_allocating() {
+    let table = StaticServiceTable::new(&MANIFEST);
+    assert_eq!(table.len(), 3);
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let mut total = 0u64;
+    for _ in 0..1000 {
+        total += u64::from(table.get_required::<Config>().retries);
+        total += table.get_required::<Clock>().epoch % 10;
+        total += table.get_required::<Limits>().max_tokens as u64;
+        assert!(table.get::<String>().is_none());
+    }
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+
+    assert_eq!(after, before, "resolution allocated");
+    // The later manifest entry for Config wins
+    assert_eq!(total, 1000 * (5 + 4096));
+    assert!(std::ptr::eq(table.get_required::<Config>(), &OVERRIDDEN));
+}
@@ -0,0 +1,46 @@
+use ferrous_di::{Lifetime, Resolver, TypedModule};
+use std::sync::Arc;
+
+trait Clock: Send + Sync {
+    fn now(&self) -> u64;
+}
+
+struct FixedClock;
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        7
+    }
+}
+
+struct Config {
+    url: &'static str,
+}
+struct Database {
+    url: &'static str,
+}
+struct AuditLog {
+    db: Arc<Database>,
+    clock: Arc<dyn Clock>,
+}
+
+#[test]
+fn test_typed_module_resolves_proven_dependencies() {
+    let mut module = TypedModule::new();
+    let config = module.add_instance(Config { url: "postgres://test" });
+    let clock = module.add_trait_instance::<dyn Clock>(Arc::new(FixedClock));
+    let db = module.add_with(Lifetime::Singleton, (config,), |(config,)| Database { url: config.url });
+    module.add_with(Lifetime::Scoped, (db, clock), |(db, clock)| AuditLog { db, clock });
+
+    let provider = module.into_collection().build();
+    let scope = provider.create_scope();
+    let log = scope.get_required::<AuditLog>();
+    assert_eq!(log.db.url, "postgres://test");
+    assert_eq!(log.clock.now(), 7);
+    assert!(Arc::ptr_eq(&log.db, &provider.get_required::<Database>()));
+}
+
+#[test]
+fn test_typed_module_rejects_missing_registration_at_compile_time() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/typed_module_*.rs");
+}
@@ -0,0 +1,15 @@
+use ferrous_di::{Lifetime, TypedModule};
+use std::sync::Arc;
+
+struct Config;
+struct Database;
+struct UserService {
+    db: Arc<Database>,
+}
+
+fn main() {
+    let mut module = TypedModule::new();
+    let config = module.add_instance(Config);
+    // `Database` was never registered, so there is no token proving it
+    module.add_with(Lifetime::Transient, (config,), |(db,)| UserService { db });
+}
@@ -1,7 +1,7 @@
 /// Unit tests for ServiceDescriptor methods
 /// These tests specifically target mutations found by cargo-mutants
 
-use ferrous_di::{ServiceDescriptor, Key, Lifetime};
+use ferrous_di::{ServiceDescriptor, Key, Lifetime, RegistrationKind, ServiceCollection};
 use std::any::TypeId;
 
 #[test]
@@ -12,6 +12,7 @@ fn test_service_descriptor_service_name_unnamed() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.service_name(), None);
@@ -30,6 +31,7 @@ fn test_service_descriptor_service_name_named() {
         impl_type_id: Some(TypeId::of::<u32>()),
         impl_type_name: Some("u32"),
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.service_name(), Some("database_port"));
@@ -49,6 +51,7 @@ fn test_service_descriptor_service_name_empty_string() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.service_name(), Some(""));
@@ -68,6 +71,7 @@ fn test_service_descriptor_type_name() {
         impl_type_id: Some(TypeId::of::<String>()),
         impl_type_name: Some("alloc::string::String"),
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.type_name(), "alloc::string::String");
@@ -86,6 +90,7 @@ fn test_service_descriptor_type_name_trait() {
         impl_type_id: None,
         impl_type_name: Some("MyDebugImpl"),
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.type_name(), "dyn core::fmt::Debug");
@@ -103,6 +108,7 @@ fn test_service_descriptor_type_name_named() {
         impl_type_id: Some(TypeId::of::<u32>()),
         impl_type_name: Some("u32"),
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.type_name(), "u32");
@@ -120,6 +126,7 @@ fn test_service_descriptor_is_named_false() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.is_named(), false);
@@ -137,6 +144,7 @@ fn test_service_descriptor_is_named_true() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert_eq!(descriptor.is_named(), true);
@@ -154,6 +162,7 @@ fn test_service_descriptor_is_named_trait() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let named_trait = ServiceDescriptor {
@@ -162,6 +171,7 @@ fn test_service_descriptor_is_named_trait() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert!(!unnamed_trait.is_named());
@@ -180,6 +190,7 @@ fn test_service_descriptor_is_named_multi_trait() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let named_multi = ServiceDescriptor {
@@ -188,6 +199,7 @@ fn test_service_descriptor_is_named_multi_trait() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert!(!unnamed_multi.is_named());
@@ -205,6 +217,7 @@ fn test_service_descriptor_debug() {
         impl_type_id: Some(TypeId::of::<String>()),
         impl_type_name: Some("alloc::string::String"),
         has_metadata: true,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let debug_str = format!("{:?}", descriptor);
@@ -223,6 +236,7 @@ fn test_service_descriptor_clone() {
         impl_type_id: Some(TypeId::of::<u32>()),
         impl_type_name: Some("u32"),
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let cloned = descriptor.clone();
@@ -243,6 +257,7 @@ fn test_service_descriptor_all_lifetimes() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let scoped = ServiceDescriptor {
@@ -251,6 +266,7 @@ fn test_service_descriptor_all_lifetimes() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let transient = ServiceDescriptor {
@@ -259,6 +275,7 @@ fn test_service_descriptor_all_lifetimes() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     // All should have same key methods but different lifetimes
@@ -280,6 +297,7 @@ fn test_service_descriptor_metadata_flag() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: false,
+        registration_kind: RegistrationKind::Factory,
     };
     
     let with_metadata = ServiceDescriptor {
@@ -288,8 +306,69 @@ fn test_service_descriptor_metadata_flag() {
         impl_type_id: None,
         impl_type_name: None,
         has_metadata: true,
+        registration_kind: RegistrationKind::Factory,
     };
     
     assert!(!without_metadata.has_metadata);
     assert!(with_metadata.has_metadata);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_service_descriptor_registration_kind() {
+    struct Config;
+    struct Client;
+    struct Cache;
+    struct PassThrough;
+
+    impl ferrous_di::ServiceDecorator<Cache> for PassThrough {
+        fn decorate(&self, original: std::sync::Arc<Cache>, _: &dyn ferrous_di::traits::ResolverCore) -> std::sync::Arc<Cache> {
+            original
+        }
+    }
+
+    let mut services = ServiceCollection::new();
+    services.add_singleton(Config);
+    services.add_singleton_factory::<Client, _>(|_| Client);
+    services.add_transient_factory::<Cache, _>(|_| Cache);
+    services.decorate_with::<Cache, _>(PassThrough);
+
+    let descriptors = services.get_service_descriptors();
+    let kind_of = |name: &str| {
+        descriptors
+            .iter()
+            .find(|d| d.type_name().ends_with(name))
+            .map(|d| d.registration_kind)
+    };
+
+    assert_eq!(kind_of("Config"), Some(RegistrationKind::Instance));
+    assert_eq!(kind_of("Client"), Some(RegistrationKind::Factory));
+    assert_eq!(kind_of("Cache"), Some(RegistrationKind::Decorated));
+}
+
+#[test]
+fn test_collection_diff_reports_added_and_lifetime_changes() {
+    struct Database;
+    struct Cache;
+    struct Metrics;
+
+    let mut before = ServiceCollection::new();
+    before.add_singleton_factory::<Database, _>(|_| Database);
+    before.add_transient_factory::<Cache, _>(|_| Cache);
+
+    let mut after = ServiceCollection::new();
+    after.add_singleton_factory::<Database, _>(|_| Database);
+    after.add_scoped_factory::<Cache, _>(|_| Cache);
+    after.add_singleton_factory::<Metrics, _>(|_| Metrics);
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.added, vec![ferrous_di::key_of_type::<Metrics>()]);
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        diff.lifetime_changed,
+        vec![(ferrous_di::key_of_type::<Cache>(), Lifetime::Transient, Lifetime::Scoped)]
+    );
+
+    let reverse = after.diff(&before);
+    assert_eq!(reverse.removed, diff.added);
+    assert!(before.diff(&before).is_empty());
+}
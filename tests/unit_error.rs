@@ -115,4 +115,27 @@ fn test_error_as_std_error() {
     
     // Should have a source (None in our case)
     assert!(error.source().is_none());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_error_propagates_with_question_mark() {
+    fn load_anyhow() -> anyhow::Result<()> {
+        Err(DiError::NotFound("Database"))?;
+        Ok(())
+    }
+    fn load_io() -> std::io::Result<()> {
+        Err(DiError::Circular(vec!["A", "B", "A"]))?;
+        Ok(())
+    }
+
+    let err = load_anyhow().unwrap_err();
+    assert_eq!(err.to_string(), "Service not found: Database");
+    assert!(matches!(err.downcast_ref::<DiError>(), Some(DiError::NotFound("Database"))));
+    assert_eq!(err.chain().count(), 1);
+
+    let err = load_io().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+    assert_eq!(err.to_string(), "Circular dependency: A -> B -> A");
+    let inner = err.get_ref().and_then(|e| e.downcast_ref::<DiError>());
+    assert!(matches!(inner, Some(DiError::Circular(path)) if path.len() == 3));
+}
@@ -1,7 +1,7 @@
 /// Unit tests for Key type methods
 /// These tests specifically target mutations found by cargo-mutants
 
-use ferrous_di::Key;
+use ferrous_di::{Discriminant, Key};
 use std::any::TypeId;
 
 #[test]
@@ -185,4 +185,99 @@ fn test_key_hash() {
     
     let lookup_key = Key::Type(TypeId::of::<String>(), "alloc::string::String");
     assert_eq!(map.get(&lookup_key), Some(&"test_value"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_key_ordering_is_by_kind_then_name_then_discriminator() {
+    use ferrous_di::KeyKind;
+
+    struct Zeta;
+    struct Alpha;
+
+    let alpha = ferrous_di::key_of_type::<Alpha>();
+    let zeta = ferrous_di::key_of_type::<Zeta>();
+    let mut keys = vec![
+        Key::TraitNamed("dyn app::Store", "redis"),
+        Key::MultiTrait("dyn app::Tool", 1),
+        zeta.clone(),
+        Key::Trait("dyn app::Store"),
+        Key::MultiTraitNamed("dyn app::Tool", "fs", 0),
+        Key::TraitNamed("dyn app::Store", "memory"),
+        Key::MultiTrait("dyn app::Tool", 0),
+        Key::TypeNamed(TypeId::of::<u32>(), "u32", "port"),
+        Key::Trait("dyn app::Logger"),
+        alpha.clone(),
+    ];
+    keys.sort();
+
+    let expected = vec![
+        alpha,
+        zeta,
+        Key::TypeNamed(TypeId::of::<u32>(), "u32", "port"),
+        Key::Trait("dyn app::Logger"),
+        Key::Trait("dyn app::Store"),
+        Key::TraitNamed("dyn app::Store", "memory"),
+        Key::TraitNamed("dyn app::Store", "redis"),
+        Key::MultiTrait("dyn app::Tool", 0),
+        Key::MultiTrait("dyn app::Tool", 1),
+        Key::MultiTraitNamed("dyn app::Tool", "fs", 0),
+    ];
+    assert_eq!(keys, expected);
+
+    let kinds: Vec<KeyKind> = keys.iter().map(Key::kind).collect();
+    assert!(kinds.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(kinds[0], KeyKind::Type);
+    assert_eq!(kinds[9], KeyKind::MultiTraitNamed);
+}
+
+#[test]
+fn test_key_ordering_agrees_with_equality() {
+    use std::cmp::Ordering;
+
+    #[derive(Hash, PartialEq, Eq)]
+    enum Region { Eu, Us }
+
+    // Every value hashes alike, so distinct ones collide
+    #[derive(PartialEq, Eq)]
+    struct Shard(u8);
+    impl std::hash::Hash for Shard {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            0u8.hash(state);
+        }
+    }
+
+    mod other {
+        pub struct Id;
+    }
+    struct Id;
+
+    let keys = vec![
+        Key::Type(TypeId::of::<String>(), "alloc::string::String"),
+        Key::Type(TypeId::of::<String>(), "alloc::string::String"),
+        Key::Type(TypeId::of::<u32>(), "u32"),
+        // Distinct types under one name are told apart by TypeId
+        Key::Type(TypeId::of::<Id>(), "Id"),
+        Key::Type(TypeId::of::<other::Id>(), "Id"),
+        Key::TypeNamed(TypeId::of::<u32>(), "u32", "port"),
+        Key::TypeNamed(TypeId::of::<u32>(), "u32", "port"),
+        Key::TypeNamed(TypeId::of::<u32>(), "u32", "timeout"),
+        Key::Trait("dyn app::Store"),
+        Key::TraitNamed("dyn app::Store", "redis"),
+        Key::MultiTrait("dyn app::Tool", 0),
+        Key::MultiTrait("dyn app::Tool", 1),
+        Key::MultiTraitNamed("dyn app::Tool", "fs", 0),
+        Key::TraitDiscriminated("dyn app::Store", Discriminant::new(Region::Eu)),
+        Key::TraitDiscriminated("dyn app::Store", Discriminant::new(Region::Eu)),
+        Key::TraitDiscriminated("dyn app::Store", Discriminant::new(Region::Us)),
+        Key::TraitDiscriminated("dyn app::Store", Discriminant::new(Shard(1))),
+        Key::TraitDiscriminated("dyn app::Store", Discriminant::new(Shard(2))),
+        Key::TraitDiscriminated("dyn app::Store", Discriminant::new(Shard(2))),
+    ];
+
+    for a in &keys {
+        for b in &keys {
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b, "{:?} vs {:?}", a, b);
+            assert_eq!(a.cmp(b), b.cmp(a).reverse());
+        }
+    }
+}